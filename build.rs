@@ -0,0 +1,25 @@
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    // Regenerate the rook and bishop magic tables, with the generator's default seed
+    #[cfg(feature = "generate-magics")]
+    {
+        println!("cargo:rerun-if-changed=tools/magics_finders/src/lib.rs");
+
+        let out_dir = std::path::PathBuf::from(std::env::var("OUT_DIR").unwrap());
+        magic_gen::generate(&out_dir, &magic_gen::Options::default()).expect("failed to generate the magic tables");
+    }
+
+    // PEXT lookups need BMI2 on the target (e.g. -C target-cpu=native), otherwise keep the magics
+    println!("cargo::rustc-check-cfg=cfg(use_pext)");
+    if std::env::var_os("CARGO_FEATURE_PEXT").is_some() {
+        let arch = std::env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default();
+        let features = std::env::var("CARGO_CFG_TARGET_FEATURE").unwrap_or_default();
+
+        if arch == "x86_64" && features.split(',').any(|feature| feature == "bmi2") {
+            println!("cargo::rustc-cfg=use_pext");
+        } else {
+            println!("cargo::warning=the pext feature needs an x86-64 target with BMI2, using magics");
+        }
+    }
+}
@@ -1,9 +1,12 @@
+//! The board, its pieces and moves, FEN and SAN
+
 use crate::movegen::MoveGen;
 
 mod types;
 mod zobrist;
-pub mod board;
+mod san;
+mod position;
 
 pub use types::*;
 pub use zobrist::Zobrist;
-pub use board::Board;
+pub use position::Board;
@@ -10,7 +10,7 @@ pub fn define_pieces(input: TokenStream) -> TokenStream {
 
     if chars.peek() == Some(&'#') {
         // Parse derives
-        while let Some(c) = chars.next() {
+        for c in chars.by_ref() {
             derives.push(c);
             if c == ']' {
                 break;
@@ -27,7 +27,7 @@ pub fn define_pieces(input: TokenStream) -> TokenStream {
         }
     }
 
-    let mut output = format!("{} pub enum PieceType {{", derives);
+    let mut output = format!("#[doc = \"Kind of piece, regardless of color\"] {} pub enum PieceType {{", derives);
 
     // Parse pieces
     let remaining = chars.collect::<String>();
@@ -38,11 +38,14 @@ pub fn define_pieces(input: TokenStream) -> TokenStream {
         .collect();
 
     for &piece in &pieces {
-        output.push_str(&format!("{piece},"));
+        output.push_str(&format!("#[doc = \"{piece}\"] {piece},"));
     }
 
     output.push('}');
-    output.push_str(&format!("pub const PIECETYPE_COUNT: usize = {};", pieces.len()));
+    output.push_str(&format!(
+        "#[doc = \"Number of piece types\"] pub const PIECETYPE_COUNT: usize = {};",
+        pieces.len()
+    ));
 
     output.parse().unwrap()
 }
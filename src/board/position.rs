@@ -0,0 +1,1142 @@
+use crossterm::style::{self, Stylize};
+
+use super::{
+    Bitboard,
+    CastlingRights,
+    Color,
+    FenError,
+    HistoryState,
+    Mailbox,
+    MoveType, Move,
+    Occupied,
+    Piece, PieceType, PIECETYPE_COUNT,
+    MoveGen,
+    Square, SquareExt,
+    UciMoveError,
+    Zobrist
+};
+use std::sync::Arc;
+
+use crate::eval::{Eval, EvalState, Network, NnueState, PawnTable};
+use crate::search::clamp_eval;
+
+/// A position with the history of the moves made on it, which can be taken
+/// back, and the incrementally updated evaluation terms
+#[derive(Clone)]
+pub struct Board {
+    bitboards: [Bitboard; PIECETYPE_COUNT * 2],
+    mailbox: Mailbox,
+    occupied: Occupied,
+    color_to_move: Color,
+    en_passant_square: Option<Square>,
+    castling_rights: CastlingRights,
+    halfmove_clock: u32,
+    fullmove_number: u32,
+    history: Vec<HistoryState>,
+    zobrist: Zobrist,
+    pawn_key: u64,
+    eval_state: EvalState,
+    nnue: Option<NnueState>,
+}
+
+impl Board {
+    /// The standard starting position
+    pub fn startpos() -> Self {
+        let mut bitboards = [0; 12];
+
+        bitboards[Piece::new(PieceType::Pawn,   Color::White).index()] = 0xff00;
+        bitboards[Piece::new(PieceType::Knight, Color::White).index()] = 0x42;
+        bitboards[Piece::new(PieceType::Bishop, Color::White).index()] = 0x24;
+        bitboards[Piece::new(PieceType::Rook,   Color::White).index()] = 0x81;
+        bitboards[Piece::new(PieceType::Queen,  Color::White).index()] = 0x8;
+        bitboards[Piece::new(PieceType::King,   Color::White).index()] = 0x10;
+
+        bitboards[Piece::new(PieceType::Pawn,   Color::Black).index()] = 0xff000000000000;
+        bitboards[Piece::new(PieceType::Knight, Color::Black).index()] = 0x4200000000000000;
+        bitboards[Piece::new(PieceType::Bishop, Color::Black).index()] = 0x2400000000000000;
+        bitboards[Piece::new(PieceType::Rook,   Color::Black).index()] = 0x8100000000000000;
+        bitboards[Piece::new(PieceType::Queen,  Color::Black).index()] = 0x800000000000000;
+        bitboards[Piece::new(PieceType::King,   Color::Black).index()] = 0x1000000000000000;
+
+        let mailbox = Mailbox::startpos();
+
+        let mut board = Self {
+            bitboards,
+            mailbox,
+            occupied: Occupied {
+                white: 0xffff,
+                black: 0xffff000000000000,
+                all: 0xffff00000000ffff
+            },
+            color_to_move: Color::White,
+            en_passant_square: None,
+            castling_rights: CastlingRights::default(),
+            halfmove_clock: 0,
+            fullmove_number: 1,
+            history: Vec::new(),
+            zobrist: Zobrist::new(),
+            eval_state: EvalState::from_mailbox(&mailbox),
+            pawn_key: 0,
+            nnue: None
+        };
+
+        board.pawn_key = board.compute_pawn_key();
+        board
+    }
+
+    /// Parse a FEN string, the halfmove clock and fullmove number may be omitted
+    pub fn from_fen(fen: &str) -> Result<Self, FenError> {
+        let fields: Vec<&str> = fen.split_whitespace().collect();
+        if !(4..=6).contains(&fields.len()) {
+            return Err(FenError::FieldCount(fields.len()));
+        }
+
+        let mut bitboards: [Bitboard; 12] = [0; 12];
+        let mut mailbox = Mailbox::new();
+        let mut occupied = Occupied::new();
+
+        let ranks: Vec<&str> = fields[0].split('/').collect();
+        if ranks.len() != 8 {
+            return Err(FenError::RankCount(ranks.len()));
+        }
+
+        // Ranks are listed from the 8th down to the 1st
+        for (rank, pieces) in (0..8u8).rev().zip(ranks) {
+            let mut file = 0u8;
+
+            for c in pieces.chars() {
+                if let Some(empty) = c.to_digit(10).filter(|n| (1..=8).contains(n)) {
+                    file += empty as u8;
+                    if file > 8 {
+                        return Err(FenError::RankLength(rank as usize + 1));
+                    }
+                    continue;
+                }
+
+                let piece = Piece::try_from_char(c).ok_or(FenError::InvalidPiece(c))?;
+                if file >= 8 {
+                    return Err(FenError::RankLength(rank as usize + 1));
+                }
+
+                let square = rank * 8 + file;
+                if piece.piece_type == PieceType::Pawn && (rank == 0 || rank == 7) {
+                    return Err(FenError::PawnOnBackRank(square));
+                }
+
+                bitboards[piece.index()] |= 1 << square;
+                mailbox.set_piece(square, Some(piece));
+
+                match piece.color {
+                    Color::White => occupied.white |= 1 << square,
+                    Color::Black => occupied.black |= 1 << square,
+                }
+
+                file += 1;
+            }
+
+            if file != 8 {
+                return Err(FenError::RankLength(rank as usize + 1));
+            }
+        }
+
+        occupied.all = occupied.white | occupied.black;
+
+        for color in [Color::White, Color::Black] {
+            let kings = bitboards[PieceType::King.index(&color)].count_ones();
+            if kings != 1 {
+                return Err(FenError::KingCount(color, kings));
+            }
+        }
+
+        let color_to_move = match fields[1] {
+            "w" => Color::White,
+            "b" => Color::Black,
+            other => return Err(FenError::InvalidColor(other.to_string())),
+        };
+
+        let mut castling_rights = CastlingRights::default_but_false();
+        if fields[2] != "-" {
+            for c in fields[2].chars() {
+                // The king and rook must still be on their starting squares
+                let (right, king, rook, color) = match c {
+                    'K' => (&mut castling_rights.white_king_side,  4,  7,  Color::White),
+                    'Q' => (&mut castling_rights.white_queen_side, 4,  0,  Color::White),
+                    'k' => (&mut castling_rights.black_king_side,  60, 63, Color::Black),
+                    'q' => (&mut castling_rights.black_queen_side, 60, 56, Color::Black),
+
+                    _ => return Err(FenError::InvalidCastling(fields[2].to_string())),
+                };
+
+                if *right {
+                    return Err(FenError::InvalidCastling(fields[2].to_string()));
+                }
+
+                if mailbox.get_piece(king) != Some(&Piece::new(PieceType::King, color))
+                    || mailbox.get_piece(rook) != Some(&Piece::new(PieceType::Rook, color))
+                {
+                    return Err(FenError::CastlingWithoutPieces(c));
+                }
+
+                *right = true;
+            }
+        }
+
+        let en_passant_square = match fields[3] {
+            "-" => None,
+            alg => {
+                let square = Square::try_from_algebraic(alg)
+                    .ok_or_else(|| FenError::InvalidEnPassant(alg.to_string()))?;
+
+                // The square was skipped by a double push of the side not to move
+                let (rank, pushed_to, pushed_from) = match color_to_move {
+                    Color::White => (5, square.wrapping_sub(8), square + 8),
+                    Color::Black => (2, square + 8, square.wrapping_sub(8)),
+                };
+                if square / 8 != rank {
+                    return Err(FenError::InvalidEnPassant(alg.to_string()));
+                }
+
+                let pawn = Piece::new(PieceType::Pawn, color_to_move.inverse());
+                if mailbox.get_piece(pushed_to) != Some(&pawn)
+                    || mailbox.get_piece(square).is_some()
+                    || mailbox.get_piece(pushed_from).is_some()
+                {
+                    return Err(FenError::EnPassantWithoutPawn(square));
+                }
+
+                Some(square)
+            }
+        };
+
+        let halfmove_clock = match fields.get(4) {
+            Some(s) => s.parse().map_err(|_| FenError::InvalidHalfmoveClock(s.to_string()))?,
+            None => 0,
+        };
+
+        let fullmove_number = match fields.get(5) {
+            Some(s) => s.parse().ok()
+                .filter(|&n| n >= 1)
+                .ok_or_else(|| FenError::InvalidFullmoveNumber(s.to_string()))?,
+            None => 1,
+        };
+
+        let mut board = Self {
+            bitboards,
+            mailbox,
+            occupied,
+            color_to_move,
+            en_passant_square,
+            castling_rights,
+            halfmove_clock,
+            fullmove_number,
+            history: Vec::new(),
+            zobrist: Zobrist::new(),
+            eval_state: EvalState::from_mailbox(&mailbox),
+            pawn_key: 0,
+            nnue: None
+        };
+        board.pawn_key = board.compute_pawn_key();
+
+        if board.was_illegal_move() {
+            return Err(FenError::OpponentInCheck);
+        }
+
+        Ok(board)
+    }
+
+    /// FEN of the position
+    pub fn to_fen(&self) -> String {
+        let mut result = String::new();
+
+        let mut square = 56;
+        while square < 64 {
+            let mut empty_count = 0;
+
+            for _ in 0..8 {
+                match self.mailbox.get_piece(square) {
+                    Some(piece) => {
+                        if empty_count != 0 {
+                            result.push_str(empty_count.to_string().as_str());
+                        }
+
+                        result.push(piece.to_char());
+                        empty_count = 0;
+                    }
+
+                    None => empty_count += 1,
+                }
+
+                square += 1;
+            }
+
+            if empty_count != 0 {
+                result.push_str(empty_count.to_string().as_str());
+            }
+
+            result.push('/');
+            square = square.wrapping_sub(16);
+        }
+
+        result.pop();
+
+        result.push(' ');
+        result.push(if self.color_to_move == Color::White { 'w' } else { 'b' });
+        result.push(' ');
+
+        if self.castling_rights.white_king_side {
+            result.push('K');
+        }
+        if self.castling_rights.white_queen_side {
+            result.push('Q');
+        }
+        if self.castling_rights.black_king_side {
+            result.push('k');
+        }
+        if self.castling_rights.black_queen_side {
+            result.push('q');
+        }
+        if result.ends_with(' ') {
+            result.push('-');
+        }
+
+        result.push(' ');
+
+        match self.en_passant_square {
+            Some(square) => result.push_str(square.to_algebraic()),
+            None => result.push('-'),
+        }
+
+        result.push(' ');
+        result.push_str(self.halfmove_clock.to_string().as_str());
+        result.push(' ');
+        result.push_str(self.fullmove_number.to_string().as_str());
+
+        result
+    }
+
+    /// Print the board to the terminal, white pieces in red, black in blue
+    pub fn print(&self) {
+        println!("  +-----------------+");
+
+        for rank in (0..8).rev() {
+            print!("{} |", rank + 1);
+            for file in 0..8 {
+                let idx = rank * 8 + file;
+                let square = self.mailbox.get_piece(idx);
+                let symbol = match square {
+                    Some(piece) => {
+                        let chr = piece.to_char();
+                        if chr.is_uppercase() {
+                            chr.with(style::Color::Red)
+                        } else {
+                            chr.with(style::Color::Blue)
+                        }
+                    },
+                    None => '.'.stylize(),
+                };
+                if file == 0 { print!(" "); }
+                print!("{} ", symbol);
+            }
+            println!("|");
+        }
+
+        println!("  +-----------------+");
+        println!("    a b c d e f g h");
+    }
+
+    /// Piece on a square
+    #[inline(always)]
+    pub const fn get_piece(&self, square: Square) -> Option<&Piece> {
+        self.mailbox.get_piece(square)
+    }
+
+    /// Squares of the pieces of a type and color
+    #[inline(always)]
+    pub const fn get_bitboard(&self, piece_type: &PieceType, color: &Color) -> Bitboard {
+        self.bitboards[piece_type.index(color)]
+    }
+
+    #[inline(always)]
+    pub(crate) const fn get_eval_state(&self) -> &EvalState {
+        &self.eval_state
+    }
+
+    /// Squares occupied by each side
+    #[inline(always)]
+    pub const fn get_occupied(&self) -> &Occupied {
+        &self.occupied
+    }
+
+    /// Castling still allowed, by side and wing
+    #[inline(always)]
+    pub const fn get_castling_rights(&self) -> &CastlingRights {
+        &self.castling_rights
+    }
+
+    /// Square a pawn just skipped with a double push, if any
+    #[inline(always)]
+    pub const fn get_en_passant_square(&self) -> Option<Square> {
+        self.en_passant_square
+    }
+
+    /// Side to move
+    #[inline(always)]
+    pub const fn color_to_move(&self) -> &Color {
+        &self.color_to_move
+    }
+
+    /// Plies since the last capture or pawn move, for the 50-move rule
+    #[inline(always)]
+    pub const fn get_halfmove_clock(&self) -> u32 {
+        self.halfmove_clock
+    }
+
+    /// Move number, starting at 1 and increasing after black's move
+    #[inline(always)]
+    pub const fn get_fullmove_number(&self) -> u32 {
+        self.fullmove_number
+    }
+
+    fn place_piece(&mut self, square: Square, piece: Piece) {
+        self.bitboards[piece.index()] |= 1u64 << square;
+        self.update_pawn_key(square, &piece);
+        self.eval_state.add_piece(square, &piece);
+        if let Some(nnue) = &mut self.nnue {
+            nnue.add_piece(square, &piece);
+        }
+        self.mailbox.set_piece(square, Some(piece))
+    }
+
+    fn remove_piece(&mut self, square: Square, piece: &Piece) {
+        self.bitboards[piece.index()] &= !(1u64 << square);
+        self.update_pawn_key(square, piece);
+        self.eval_state.remove_piece(square, piece);
+        if let Some(nnue) = &mut self.nnue {
+            nnue.remove_piece(square, piece);
+        }
+        self.mailbox.set_piece(square, None);
+    }
+
+    /// Fill in the type of a move read without one (e.g. from UCI or a book)
+    pub(crate) fn find_move_type(&self, mv: &mut Move) {
+        if mv.move_type != MoveType::Unknown { return; }
+
+        let piece = self.mailbox.get_piece(mv.from).copied().unwrap();
+        let target = self.mailbox.get_piece(mv.to).copied();
+
+        // Detect castling
+        if piece.piece_type == PieceType::King {
+            match (piece.color, mv.from, mv.to) {
+                (Color::White, 4,  6)  => {
+                    mv.move_type = MoveType::CastleKingside;
+                    return;
+                }
+                (Color::White, 4,  2)  => {
+                    mv.move_type = MoveType::CastleQueenside;
+                    return;
+                }
+                (Color::Black, 60, 62) => {
+                    mv.move_type = MoveType::CastleKingside;
+                    return;
+                }
+                (Color::Black, 60, 58) => {
+                    mv.move_type = MoveType::CastleQueenside;
+                    return;
+                }
+                _ => {}
+            }
+        }
+
+        // Detect en passant
+        if piece.piece_type == PieceType::Pawn && self.en_passant_square == Some(mv.to) {
+            mv.move_type = MoveType::EnPassant;
+            return;
+        }
+
+        // Capture vs quiet
+        mv.move_type = if target.is_some() {
+            MoveType::Capture
+        } else {
+            MoveType::Quiet
+        };
+    }
+
+    fn save_state(&mut self) {
+        self.history.push(HistoryState {
+            bitboards:         self.bitboards,
+            mailbox:           self.mailbox,
+            occupied:          self.occupied,
+            color_to_move:     self.color_to_move,
+            en_passant_square: self.en_passant_square,
+            castling_rights:   self.castling_rights,
+            halfmove_clock:    self.halfmove_clock,
+            fullmove_number:   self.fullmove_number,
+            pawn_key:          self.pawn_key,
+            eval_state:        self.eval_state
+        });
+
+        if let Some(nnue) = &mut self.nnue {
+            nnue.push();
+        }
+    }
+
+    fn update_occupied(&mut self) {
+        self.occupied.white = {
+            let mut occ = 0;
+            for i in 0..PIECETYPE_COUNT {
+                occ |= self.bitboards[i];
+            }
+            occ
+        };
+        self.occupied.black = {
+            let mut occ = 0;
+            for i in PIECETYPE_COUNT..self.bitboards.len() {
+                occ |= self.bitboards[i];
+            }
+            occ
+        };
+        self.occupied.all = self.occupied.white | self.occupied.black;
+    }
+
+    pub(crate) fn revert_state(&mut self) {
+        let state = self.history.pop().unwrap();
+
+        self.bitboards         = state.bitboards;
+        self.mailbox           = state.mailbox;
+        self.occupied          = state.occupied;
+        // TODO: Try to swap color manually instead of saving and reverting it
+        self.color_to_move     = state.color_to_move;
+        self.en_passant_square = state.en_passant_square;
+        self.castling_rights   = state.castling_rights;
+        self.halfmove_clock    = state.halfmove_clock;
+        self.fullmove_number   = state.fullmove_number;
+        self.pawn_key          = state.pawn_key;
+        self.eval_state        = state.eval_state;
+
+        // The network may have been switched on after this state was saved
+        if let Some(nnue) = &mut self.nnue
+            && !nnue.pop()
+        {
+            nnue.refresh(&self.mailbox);
+        }
+    }
+
+    /// Play a pseudolegal move, of a known type (see `MoveType::Unknown`)
+    pub fn make_move(&mut self, mv: &Move) {
+        // Save in the annals of time
+        self.save_state();
+
+        let moving_piece = *self.mailbox
+            .get_piece(mv.from)
+            .unwrap();
+
+        let is_white = moving_piece.color.is_white();
+
+        // Pawn moves and captures reset the fifty-move counter
+        if moving_piece.piece_type == PieceType::Pawn || self.mailbox.get_piece(mv.to).is_some() {
+            self.halfmove_clock = 0;
+        } else {
+            self.halfmove_clock += 1;
+        }
+
+        if !is_white {
+            self.fullmove_number += 1;
+        }
+
+        // Handle castling rights
+        match moving_piece.piece_type {
+            // If the king moves, remove his rights
+            PieceType::King => match moving_piece.color {
+                Color::White => {
+                    self.castling_rights.white_king_side  = false;
+                    self.castling_rights.white_queen_side = false;
+                }
+                Color::Black => {
+                    self.castling_rights.black_king_side  = false;
+                    self.castling_rights.black_queen_side = false;
+                }
+            },
+            // If a rook moves, remove the kings' rights too
+            PieceType::Rook => match mv.from {
+                0  => self.castling_rights.white_queen_side = false,
+                7  => self.castling_rights.white_king_side  = false,
+                56 => self.castling_rights.black_queen_side = false,
+                63 => self.castling_rights.black_king_side  = false,
+                _ => {}
+            }
+            _ => {}
+        }
+
+        // If a piece moves to one of the corners, the king is dethroned
+        match mv.to {
+            0  => self.castling_rights.white_queen_side = false,
+            7  => self.castling_rights.white_king_side  = false,
+            56 => self.castling_rights.black_queen_side = false,
+            63 => self.castling_rights.black_king_side  = false,
+            _ => {}
+        }
+
+        match mv.move_type {
+            MoveType::Quiet => {}
+
+            MoveType::Capture => {
+                let Some(&captured_piece) = self.mailbox.get_piece(mv.to) else { panic!() };
+                self.remove_piece(mv.to, &captured_piece);
+            }
+
+            MoveType::CastleKingside => match moving_piece.color {
+                Color::White => {
+                    self.castling_rights.white_king_side = false;
+                    let Some(&rook) = self.mailbox.get_piece(7) else { panic!() };
+                    self.remove_piece(7, &rook);
+                    self.place_piece(5, rook);
+                }
+                Color::Black => {
+                    self.castling_rights.black_king_side = false;
+                    let Some(&rook) = self.mailbox.get_piece(63) else { panic!() };
+                    self.remove_piece(63, &rook);
+                    self.place_piece(61, rook);
+                }
+            }
+
+            MoveType::CastleQueenside => match moving_piece.color {
+                Color::White => {
+                    self.castling_rights.white_queen_side = false;
+                    let Some(&rook) = self.mailbox.get_piece(0) else { panic!() };
+                    self.remove_piece(0, &rook);
+                    self.place_piece(3, rook);
+                }
+                Color::Black => {
+                    self.castling_rights.black_queen_side = false;
+                    let Some(&rook) = self.mailbox.get_piece(56) else { panic!() };
+                    self.remove_piece(56, &rook);
+                    self.place_piece(59, rook);
+                }
+            }
+
+            MoveType::EnPassant => {
+                let captured_pawn_square = if is_white { mv.to - 8 } else { mv.to + 8 };
+                let Some(&captured_pawn) = self.mailbox.get_piece(captured_pawn_square) else { panic!() };
+                self.remove_piece(captured_pawn_square, &captured_pawn);
+            }
+
+            MoveType::Promotion(promotion) => {
+                self.remove_piece(mv.from, &moving_piece);
+
+                // Remove any captured piece at the destination
+                if let Some(&captured_piece) = self.mailbox.get_piece(mv.to) {
+                    self.remove_piece(mv.to, &captured_piece);
+                }
+
+                self.place_piece(mv.to, Piece {
+                    piece_type: promotion,
+                    color: moving_piece.color,
+                });
+
+                // Return to avoid placing a pawn (because promotion)
+                self.color_to_move = self.color_to_move.inverse();
+                self.en_passant_square = None;
+                self.update_occupied();
+                return;
+            }
+
+            MoveType::Unknown => panic!(),
+        }
+
+        self.remove_piece(mv.from, &moving_piece);
+        self.place_piece(mv.to, moving_piece);
+
+        // Flip color
+        self.color_to_move = self.color_to_move.inverse();
+
+        // Clear en passant square
+        self.en_passant_square = None;
+
+        // Set the en passant square for the next turn
+        // TODO: Make a MoveType for DoublePawnPush
+        if moving_piece.piece_type == PieceType::Pawn {
+            if is_white && (mv.to == mv.from + 16) {
+                self.en_passant_square = Some(mv.from + 8);
+            }
+            else if !is_white && (mv.to == mv.from.wrapping_sub(16)) {
+                self.en_passant_square = Some(mv.from - 8);
+            }
+        }
+
+        self.update_occupied();
+    }
+
+    /// Take back the last move made with `make_move`, returns false if there is none
+    pub fn undo_move(&mut self) -> bool {
+        if self.history.is_empty() {
+            return false;
+        }
+
+        self.revert_state();
+        true
+    }
+
+    /// Whether the opponent attacks a square
+    pub fn is_attacked(&self, square: Square) -> bool {
+        let enemy_color   = self.color_to_move.inverse();
+        let enemy_pawns   = self.bitboards[PieceType::Pawn.index(&enemy_color)];
+        let enemy_knights = self.bitboards[PieceType::Knight.index(&enemy_color)];
+        let enemy_bishops = self.bitboards[PieceType::Bishop.index(&enemy_color)];
+        let enemy_rooks   = self.bitboards[PieceType::Rook.index(&enemy_color)];
+        let enemy_queens  = self.bitboards[PieceType::Queen.index(&enemy_color)];
+        let enemy_king    = self.bitboards[PieceType::King.index(&enemy_color)];
+
+        (
+            MoveGen::attacks(self, &PieceType::Pawn, &self.color_to_move, square) & enemy_pawns
+            | MoveGen::attacks(self, &PieceType::Knight, &self.color_to_move, square) & enemy_knights
+            | MoveGen::attacks(self, &PieceType::Bishop, &self.color_to_move, square) & enemy_bishops
+            | MoveGen::attacks(self, &PieceType::Rook, &self.color_to_move, square) & enemy_rooks
+            | MoveGen::attacks(self, &PieceType::Queen, &self.color_to_move, square) & enemy_queens
+            | MoveGen::attacks(self, &PieceType::King, &self.color_to_move, square) & enemy_king
+        ) != 0
+    }
+
+    /// Whether the side to move is in check
+    pub fn in_check(&self) -> bool {
+        self.is_attacked(self.bitboards[
+            PieceType::King.index(&self.color_to_move)
+        ].trailing_zeros() as u8)
+    }
+
+    /// Whether the side to move has the right to castle kingside, with the
+    /// squares between king and rook empty and not passing through check
+    pub fn can_castle_kingside(&self) -> bool {
+        if ((self.color_to_move == Color::White) && !self.castling_rights.white_king_side)
+            || ((self.color_to_move == Color::Black) && !self.castling_rights.black_king_side)
+        {
+            return false;
+        }
+
+        let squares =
+            if self.color_to_move == Color::White {
+                (4, 5, 6)
+            } else {
+                (60, 61, 62)
+            };
+
+        if self.mailbox.get_piece(squares.1).is_some()
+            || self.mailbox.get_piece(squares.2).is_some()
+        {
+            return false;
+        }
+
+        if self.is_attacked(squares.0)
+            || self.is_attacked(squares.1)
+            || self.is_attacked(squares.2)
+        {
+            return false;
+        }
+
+        true
+    }
+
+    /// Same as `can_castle_kingside`, on the queenside
+    pub fn can_castle_queenside(&self) -> bool {
+        if ((self.color_to_move == Color::White) && !self.castling_rights.white_queen_side)
+            || ((self.color_to_move == Color::Black) && !self.castling_rights.black_queen_side)
+        {
+            return false;
+        }
+
+        let squares =
+            if self.color_to_move == Color::White {
+                (4, 3, 2, 1)
+            } else {
+                (60, 59, 58, 57)
+            };
+
+        if self.mailbox.get_piece(squares.1).is_some()
+            || self.mailbox.get_piece(squares.2).is_some()
+            || self.mailbox.get_piece(squares.3).is_some()
+        {
+            return false;
+        }
+
+        if self.is_attacked(squares.0)
+            || self.is_attacked(squares.1)
+            || self.is_attacked(squares.2)
+        {
+            return false;
+        }
+
+        true
+    }
+
+    /// After `make_move`, whether the move left the mover's king in check, in
+    /// which case it must be taken back with `undo_move`
+    pub fn was_illegal_move(&mut self) -> bool {
+        self.color_to_move = self.color_to_move.inverse();
+
+        if self.is_attacked(self.bitboards[
+            PieceType::King.index(&self.color_to_move)
+        ].trailing_zeros() as u8) {
+            return true;
+        }
+
+        self.color_to_move = self.color_to_move.inverse();
+
+        false
+    }
+
+    /// Whether a pseudolegal move keeps the king out of check, without making it
+    pub fn is_legal(&self, mv: &Move) -> bool {
+        let us = self.color_to_move;
+        let them = us.inverse();
+
+        let to = 1u64 << mv.to;
+        let mut captured = to;
+        if mv.move_type == MoveType::EnPassant {
+            captured = 1 << if us == Color::White { mv.to - 8 } else { mv.to + 8 };
+        }
+
+        let occupied = (self.occupied.all & !(1u64 << mv.from) & !captured) | to;
+
+        let king_square = match self.mailbox.get_piece(mv.from) {
+            Some(piece) if piece.piece_type == PieceType::King => mv.to,
+            _ => self.bitboards[PieceType::King.index(&us)].trailing_zeros() as Square,
+        };
+
+        let enemy = |piece_type: PieceType| self.bitboards[piece_type.index(&them)] & !captured;
+        let diagonal = enemy(PieceType::Bishop) | enemy(PieceType::Queen);
+        let straight = enemy(PieceType::Rook) | enemy(PieceType::Queen);
+
+        (
+            MoveGen::pawn_attacks(&us, king_square) & enemy(PieceType::Pawn)
+            | MoveGen::knight_attacks(king_square) & enemy(PieceType::Knight)
+            | MoveGen::bishop_attacks(king_square, occupied) & diagonal
+            | MoveGen::rook_attacks(king_square, occupied) & straight
+            | MoveGen::king_attacks(king_square) & enemy(PieceType::King)
+        ) == 0
+    }
+
+    /// Pseudolegal moves that don't leave the king in check
+    pub fn legal_moves(&self) -> Vec<Move> {
+        MoveGen::get_pseudolegal_moves(self)
+            .into_iter()
+            .filter(|mv| self.is_legal(mv))
+            .collect()
+    }
+
+    /// Find the legal move written in UCI notation, such as "e2e4" or "e7e8q"
+    pub fn parse_uci(&self, uci: &str) -> Result<Move, UciMoveError> {
+        let invalid = || UciMoveError::InvalidSyntax(uci.to_string());
+
+        let from = uci.get(0..2).and_then(Square::try_from_algebraic).ok_or_else(invalid)?;
+        let to = uci.get(2..4).and_then(Square::try_from_algebraic).ok_or_else(invalid)?;
+        let promotion = match uci.get(4..).ok_or_else(invalid)?.to_ascii_lowercase().as_str() {
+            "" => None,
+            "n" => Some(PieceType::Knight),
+            "b" => Some(PieceType::Bishop),
+            "r" => Some(PieceType::Rook),
+            "q" => Some(PieceType::Queen),
+            _ => return Err(invalid()),
+        };
+
+        self.legal_moves()
+            .into_iter()
+            .find(|mv| {
+                let mv_promotion = match mv.move_type {
+                    MoveType::Promotion(piece_type) => Some(piece_type),
+                    _ => None,
+                };
+                mv.from == from && mv.to == to && mv_promotion == promotion
+            })
+            .ok_or_else(|| UciMoveError::IllegalMove(uci.to_string()))
+    }
+
+    /// Switch between the handcrafted evaluation (`None`) and a network
+    pub fn set_network(&mut self, network: Option<Arc<Network>>) {
+        self.nnue = network.map(|network| NnueState::new(network, &self.mailbox));
+    }
+
+    /// Score from the side to move's point of view, pawn structures are cached in `pawn_table`.
+    /// Always below the mate and tablebase scores.
+    pub fn evaluate(&self, pawn_table: &mut PawnTable) -> i16 {
+        if let Some(nnue) = &self.nnue {
+            return clamp_eval(nnue.evaluate(&self.color_to_move));
+        }
+
+        let pawns = pawn_table.probe(self.pawn_key, [
+            self.bitboards[PieceType::Pawn.index(&Color::White)],
+            self.bitboards[PieceType::Pawn.index(&Color::Black)],
+        ]);
+
+        let score = Eval::evaluate(self, &pawns).clamp(-(i16::MAX as i32), i16::MAX as i32) as i16;
+
+        clamp_eval(if self.color_to_move == Color::White { score } else { -score })
+    }
+
+    /// Zobrist key of the position, computed from scratch
+    pub fn get_zobrist_hash(&self) -> u64 {
+        let mut hash = 0;
+
+        // Hash pieces
+        for square in 0..64 {
+            if let Some(piece) = self.get_piece(square) {
+                hash ^= self.zobrist.pieces[Zobrist::index(
+                    piece.color.index(),
+                    piece.piece_type as usize,
+                    square
+                )];
+            }
+        }
+
+        // Hash side to move
+        if self.color_to_move == Color::White {
+            hash ^= self.zobrist.side_to_move;
+        }
+
+        // Hash castling rights
+        let castling_rights = &self.castling_rights;
+        if castling_rights.white_king_side  { hash ^= self.zobrist.castling_rights[0]; }
+        if castling_rights.white_queen_side { hash ^= self.zobrist.castling_rights[1]; }
+        if castling_rights.black_king_side  { hash ^= self.zobrist.castling_rights[2]; }
+        if castling_rights.black_queen_side { hash ^= self.zobrist.castling_rights[3]; }
+
+        // Hash en passant
+        if let Some(ep_square) = self.en_passant_square {
+            let file = ep_square % 8;
+            hash ^= self.zobrist.en_passant_file[file as usize];
+        }
+
+        hash
+    }
+    /// Material signature in Syzygy's naming, e.g. "KRPvKR" with `first`'s pieces on the left
+    pub fn material_signature(&self, first: Color) -> String {
+        let mut signature = String::new();
+
+        for color in [first, first.inverse()] {
+            if color != first {
+                signature.push('v');
+            }
+
+            for (piece_type, c) in [
+                (PieceType::King,   'K'),
+                (PieceType::Queen,  'Q'),
+                (PieceType::Rook,   'R'),
+                (PieceType::Bishop, 'B'),
+                (PieceType::Knight, 'N'),
+                (PieceType::Pawn,   'P'),
+            ] {
+                let count = self.bitboards[piece_type.index(&color)].count_ones();
+                signature.extend(std::iter::repeat_n(c, count as usize));
+            }
+        }
+
+        signature
+    }
+
+    /// Zobrist key of the pawns alone, kept up to date as pieces move
+    #[inline(always)]
+    pub const fn get_pawn_hash(&self) -> u64 {
+        self.pawn_key
+    }
+
+    #[inline(always)]
+    fn update_pawn_key(&mut self, square: Square, piece: &Piece) {
+        if piece.piece_type == PieceType::Pawn {
+            self.pawn_key ^= self.zobrist.pieces[Zobrist::index(piece.color.index(), PieceType::Pawn as usize, square)];
+        }
+    }
+
+    fn compute_pawn_key(&self) -> u64 {
+        let mut hash = 0;
+
+        for color in [Color::White, Color::Black] {
+            let mut pawns = self.bitboards[PieceType::Pawn.index(&color)];
+            while pawns != 0 {
+                let square = pawns.trailing_zeros() as u8;
+                pawns &= pawns - 1;
+
+                hash ^= self.zobrist.pieces[Zobrist::index(
+                    color.index(),
+                    PieceType::Pawn as usize,
+                    square
+                )];
+            }
+        }
+
+        hash
+    }
+}
+
+/// Boards are equal when they hold the same position, regardless of history
+impl PartialEq for Board {
+    fn eq(&self, other: &Self) -> bool {
+        self.bitboards == other.bitboards
+            && self.color_to_move == other.color_to_move
+            && self.en_passant_square == other.en_passant_square
+            && self.castling_rights == other.castling_rights
+            && self.halfmove_clock == other.halfmove_clock
+            && self.fullmove_number == other.fullmove_number
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STANDARD_EPD: &str = include_str!("../../tools/perft/standard.epd");
+
+    fn assert_round_trip(board: &Board) {
+        let fen = board.to_fen();
+        match Board::from_fen(&fen) {
+            Ok(parsed) => assert!(parsed == *board, "{fen} parsed back as {}", parsed.to_fen()),
+            Err(e) => panic!("{fen}: {e}"),
+        }
+    }
+
+    fn round_trip_tree(board: &mut Board, depth: i32) {
+        assert_round_trip(board);
+        assert_eq!(board.get_pawn_hash(), board.compute_pawn_key(), "{}", board.to_fen());
+        if depth == 0 {
+            return;
+        }
+
+        for mv in &MoveGen::get_pseudolegal_moves(board) {
+            board.make_move(mv);
+            if !board.was_illegal_move() {
+                round_trip_tree(board, depth - 1);
+            }
+            board.revert_state();
+        }
+    }
+
+    fn assert_incremental_terms(board: &Board, context: &str) {
+        let fresh = EvalState::from_mailbox(&board.mailbox);
+        assert_eq!(board.eval_state.psqt, fresh.psqt, "{context}: {}", board.to_fen());
+        assert_eq!(board.eval_state.phase, fresh.phase, "{context}: {}", board.to_fen());
+        assert_eq!(board.pawn_key, board.compute_pawn_key(), "{context}: {}", board.to_fen());
+    }
+
+    fn incremental_tree(board: &mut Board, depth: i32) {
+        if depth == 0 {
+            return;
+        }
+
+        for mv in board.legal_moves() {
+            board.make_move(&mv);
+            assert_incremental_terms(board, &format!("after {}", mv.to_uci()));
+            incremental_tree(board, depth - 1);
+            board.undo_move();
+            assert_incremental_terms(board, &format!("after undoing {}", mv.to_uci()));
+        }
+    }
+
+    fn assert_accumulator(board: &Board) {
+        let nnue = board.nnue.as_ref().unwrap();
+        assert!(nnue.matches_refresh(&board.mailbox), "{}", board.to_fen());
+    }
+
+    fn accumulator_tree(board: &mut Board, depth: i32) {
+        assert_accumulator(board);
+        if depth == 0 {
+            return;
+        }
+
+        for mv in board.legal_moves() {
+            board.make_move(&mv);
+            accumulator_tree(board, depth - 1);
+            board.revert_state();
+            assert_accumulator(board);
+        }
+    }
+
+    #[test]
+    fn accumulator_matches_refresh() {
+        // Castling both ways, en passant, and promotions with and without captures
+        for fen in [
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+            "n1n5/PPPk4/8/8/8/8/4Kppp/5N1N b - - 0 1",
+        ] {
+            let mut board = Board::from_fen(fen).unwrap();
+            board.set_network(Some(Network::embedded()));
+            accumulator_tree(&mut board, 2);
+        }
+    }
+
+    #[test]
+    fn incremental_eval_matches_recompute() {
+        // Castling both ways, en passant, and promotions with and without captures
+        for fen in [
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+            "n1n5/PPPk4/8/8/8/8/4Kppp/5N1N b - - 0 1",
+        ] {
+            let mut board = Board::from_fen(fen).unwrap();
+            assert_incremental_terms(&board, fen);
+            incremental_tree(&mut board, 3);
+        }
+    }
+
+    #[test]
+    fn nnue_output_saturates() {
+        // Every hidden neuron fully active, with the largest output weights
+        let embedded = Network::embedded();
+        for weight in [i16::MAX, i16::MIN] {
+            let network = Network {
+                feature_weights: vec![0; embedded.feature_weights.len()],
+                feature_bias: vec![i16::MAX; embedded.feature_bias.len()],
+                output_weights: vec![weight; embedded.output_weights.len()],
+                output_bias: weight as i32,
+            };
+
+            let mut board = Board::startpos();
+            board.set_network(Some(Arc::new(network)));
+            let eval = board.evaluate(&mut PawnTable::new(1));
+            assert_eq!(eval, clamp_eval(weight), "{weight}");
+        }
+    }
+
+    #[test]
+    fn fen_rank_overflow() {
+        let fen = format!("{}/8/8/8/8/8/8/8 w - - 0 1", "8".repeat(40));
+        assert!(matches!(Board::from_fen(&fen), Err(FenError::RankLength(8))));
+        assert!(matches!(Board::from_fen("4k4/8/8/8/8/8/8/4K3 w - - 0 1"), Err(FenError::RankLength(8))));
+    }
+
+    #[test]
+    fn fen_round_trip() {
+        for line in STANDARD_EPD.lines() {
+            let fen = line.split(';').next().unwrap().trim();
+            let mut board = Board::from_fen(fen).unwrap();
+
+            assert_eq!(board.to_fen(), fen);
+            round_trip_tree(&mut board, 2);
+        }
+    }
+
+    #[test]
+    fn undo_move() {
+        let mut board = Board::startpos();
+        assert!(!board.undo_move());
+
+        board.make_move(&board.parse_uci("e2e4").unwrap());
+        assert!(board.undo_move());
+        assert!(board == Board::startpos());
+        assert!(!board.undo_move());
+    }
+
+    #[test]
+    fn parse_uci() {
+        let board = Board::from_fen("r3k2r/1P6/8/3pP3/8/8/8/R3K2R w KQkq d6 0 1").unwrap();
+
+        assert_eq!(board.parse_uci("e1g1").unwrap().move_type, MoveType::CastleKingside);
+        assert_eq!(board.parse_uci("e5d6").unwrap().move_type, MoveType::EnPassant);
+        assert_eq!(board.parse_uci("b7a8N").unwrap().move_type, MoveType::Promotion(PieceType::Knight));
+        assert_eq!(board.parse_uci("a1a8").unwrap().move_type, MoveType::Capture);
+
+        for uci in ["b7b8", "e1e3", "e5e6q"] {
+            assert_eq!(board.parse_uci(uci), Err(UciMoveError::IllegalMove(uci.to_string())));
+        }
+        for uci in ["", "e2", "e9e4", "e2e4x", "e7e8qq", "é2e4"] {
+            assert_eq!(board.parse_uci(uci), Err(UciMoveError::InvalidSyntax(uci.to_string())));
+        }
+    }
+}
@@ -0,0 +1,257 @@
+use super::{
+    Board,
+    Color,
+    Move, MoveType,
+    Piece, PieceType,
+    SanError,
+    Square, SquareExt
+};
+
+impl Board {
+    /// Standard algebraic notation for a legal move, e.g. "Nbd2", "exd5", "e8=Q+", "O-O#"
+    pub fn move_to_san(&mut self, mv: &Move) -> String {
+        let mut san = match mv.move_type {
+            MoveType::CastleKingside => String::from("O-O"),
+            MoveType::CastleQueenside => String::from("O-O-O"),
+            _ => self.move_to_san_body(mv),
+        };
+
+        self.make_move(mv);
+        if self.in_check() {
+            san.push(if self.legal_moves().is_empty() { '#' } else { '+' });
+        }
+        self.revert_state();
+
+        san
+    }
+
+    fn move_to_san_body(&mut self, mv: &Move) -> String {
+        let piece_type = self.get_piece(mv.from).unwrap().piece_type;
+        let is_capture = mv.move_type == MoveType::EnPassant || self.get_piece(mv.to).is_some();
+
+        let mut san = String::new();
+
+        if piece_type == PieceType::Pawn {
+            if is_capture {
+                san.push(file_char(mv.from));
+            }
+        } else {
+            san.push(piece_char(piece_type));
+
+            // Other pieces of the same type that can reach the same square
+            let others: Vec<Square> = self.legal_moves()
+                .into_iter()
+                .filter(|other| {
+                    other.to == mv.to
+                        && other.from != mv.from
+                        && self.get_piece(other.from).unwrap().piece_type == piece_type
+                })
+                .map(|other| other.from)
+                .collect();
+
+            if !others.is_empty() {
+                if others.iter().all(|&from| from % 8 != mv.from % 8) {
+                    san.push(file_char(mv.from));
+                } else if others.iter().all(|&from| from / 8 != mv.from / 8) {
+                    san.push(rank_char(mv.from));
+                } else {
+                    san.push_str(mv.from.to_algebraic());
+                }
+            }
+        }
+
+        if is_capture {
+            san.push('x');
+        }
+
+        san.push_str(mv.to.to_algebraic());
+
+        if let MoveType::Promotion(promotion) = mv.move_type {
+            san.push('=');
+            san.push(piece_char(promotion));
+        }
+
+        san
+    }
+
+    /// Find the legal move written in SAN. Also accepts the usual sloppy
+    /// variants: missing or extra check marks and annotations ("e4!?"),
+    /// zeros for castling ("0-0"), promotions without '=' ("e8Q"), missing
+    /// 'x' ("ed5") and over-disambiguation ("Ng1f3", "e2-e4").
+    pub fn parse_san(&mut self, san: &str) -> Result<Move, SanError> {
+        let invalid = || SanError::InvalidSyntax(san.to_string());
+
+        let trimmed = san.trim().trim_end_matches(['+', '#', '!', '?']);
+
+        let castle = match trimmed {
+            "O-O" | "0-0" => Some(MoveType::CastleKingside),
+            "O-O-O" | "0-0-0" => Some(MoveType::CastleQueenside),
+            _ => None,
+        };
+
+        if let Some(castle) = castle {
+            return self.legal_moves()
+                .into_iter()
+                .find(|mv| mv.move_type == castle)
+                .ok_or_else(|| SanError::IllegalMove(san.to_string()));
+        }
+
+        let mut chars: Vec<char> = trimmed.chars().filter(|&c| c != 'x' && c != '-').collect();
+
+        // Piece letters are uppercase, so 'b' is always a file
+        let piece_type = match chars.first() {
+            Some(&c) if "NBRQK".contains(c) => {
+                chars.remove(0);
+                Piece::try_from_char(c).unwrap().piece_type
+            }
+            Some(_) => PieceType::Pawn,
+            None => return Err(invalid()),
+        };
+
+        // Promotion, with or without '='
+        let promotion = match chars.last() {
+            Some(&c) if piece_type == PieceType::Pawn && "NBRQnbrq".contains(c) => {
+                chars.pop();
+                if chars.last() == Some(&'=') {
+                    chars.pop();
+                }
+                Some(Piece::try_from_char(c.to_ascii_uppercase()).unwrap().piece_type)
+            }
+            _ => None,
+        };
+
+        if chars.len() < 2 {
+            return Err(invalid());
+        }
+
+        let to_str: String = chars.split_off(chars.len() - 2).into_iter().collect();
+        let to = Square::try_from_algebraic(&to_str).ok_or_else(invalid)?;
+
+        // Whatever is left narrows down the origin square
+        let mut from_file = None;
+        let mut from_rank = None;
+        for c in chars {
+            match c {
+                'a'..='h' if from_file.is_none() => from_file = Some(c as u8 - b'a'),
+                '1'..='8' if from_rank.is_none() => from_rank = Some(c as u8 - b'1'),
+                _ => return Err(invalid()),
+            }
+        }
+
+        let candidates: Vec<Move> = self.legal_moves()
+            .into_iter()
+            .filter(|mv| {
+                let move_promotion = match mv.move_type {
+                    MoveType::Promotion(promotion) => Some(promotion),
+                    _ => None,
+                };
+
+                mv.to == to
+                    && self.get_piece(mv.from).unwrap().piece_type == piece_type
+                    && from_file.is_none_or(|file| mv.from % 8 == file)
+                    && from_rank.is_none_or(|rank| mv.from / 8 == rank)
+                    && move_promotion == promotion
+                    && !matches!(mv.move_type, MoveType::CastleKingside | MoveType::CastleQueenside)
+            })
+            .collect();
+
+        match candidates[..] {
+            [mv] => Ok(mv),
+            [] => Err(SanError::IllegalMove(san.to_string())),
+            _ => Err(SanError::AmbiguousMove(san.to_string())),
+        }
+    }
+}
+
+fn piece_char(piece_type: PieceType) -> char {
+    Piece::new(piece_type, Color::White).to_char()
+}
+
+fn file_char(square: Square) -> char {
+    (b'a' + square % 8) as char
+}
+
+fn rank_char(square: Square) -> char {
+    (b'1' + square / 8) as char
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STANDARD_EPD: &str = include_str!("../../tools/perft/standard.epd");
+
+    fn board(fen: &str) -> Board {
+        Board::from_fen(fen).unwrap()
+    }
+
+    fn assert_san(fen: &str, mv: Move, san: &str) {
+        let mut board = board(fen);
+        assert_eq!(board.move_to_san(&mv), san, "{fen}");
+        assert_eq!(board.parse_san(san), Ok(mv), "{fen}");
+    }
+
+    #[test]
+    fn disambiguation() {
+        let knights = "4k3/8/8/8/8/5N2/8/1N2K3 w - - 0 1";
+        assert_san(knights, Move::new(1, 11, MoveType::Quiet), "Nbd2");
+        assert_san(knights, Move::new(21, 11, MoveType::Quiet), "Nfd2");
+        assert_eq!(board(knights).parse_san("Nd2"), Err(SanError::AmbiguousMove("Nd2".to_string())));
+
+        let rooks = "4k3/8/8/R7/8/8/8/R3K3 w - - 0 1";
+        assert_san(rooks, Move::new(0, 16, MoveType::Quiet), "R1a3");
+        assert_san(rooks, Move::new(32, 16, MoveType::Quiet), "R5a3");
+
+        let queens = "4k3/8/8/8/8/Q7/8/Q1Q1K3 w - - 0 1";
+        assert_san(queens, Move::new(0, 9, MoveType::Quiet), "Qa1b2");
+    }
+
+    #[test]
+    fn promotion() {
+        let fen = "7k/4P3/8/8/8/8/8/4K3 w - - 0 1";
+        assert_san(fen, Move::new(52, 60, MoveType::Promotion(PieceType::Queen)), "e8=Q+");
+        assert_san(fen, Move::new(52, 60, MoveType::Promotion(PieceType::Knight)), "e8=N");
+        assert_eq!(board(fen).parse_san("e8Q"), Ok(Move::new(52, 60, MoveType::Promotion(PieceType::Queen))));
+    }
+
+    #[test]
+    fn castling() {
+        let fen = "r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1";
+        assert_san(fen, Move::new(4, 6, MoveType::CastleKingside), "O-O");
+        assert_san(fen, Move::new(4, 2, MoveType::CastleQueenside), "O-O-O");
+        assert_eq!(board(fen).parse_san("0-0"), Ok(Move::new(4, 6, MoveType::CastleKingside)));
+        assert_eq!(board(fen).parse_san("0-0-0"), Ok(Move::new(4, 2, MoveType::CastleQueenside)));
+    }
+
+    #[test]
+    fn check_and_mate_suffixes() {
+        assert_san("4k3/8/8/8/8/8/8/R3K3 w - - 0 1", Move::new(0, 56, MoveType::Quiet), "Ra8+");
+
+        let scholar = "r1bqkbnr/pppp1ppp/2n5/4p3/2B1P3/5Q2/PPPP1PPP/RNB1K1NR w KQkq - 2 3";
+        assert_san(scholar, Move::new(21, 53, MoveType::Capture), "Qxf7#");
+        assert_eq!(board(scholar).parse_san("Qxf7"), Ok(Move::new(21, 53, MoveType::Capture)));
+        assert_eq!(board(scholar).parse_san("Qf7!?"), Ok(Move::new(21, 53, MoveType::Capture)));
+    }
+
+    #[test]
+    fn errors() {
+        let mut board = Board::startpos();
+        assert_eq!(board.parse_san("e5"), Err(SanError::IllegalMove("e5".to_string())));
+        assert_eq!(board.parse_san("O-O"), Err(SanError::IllegalMove("O-O".to_string())));
+        assert_eq!(board.parse_san("Nz3"), Err(SanError::InvalidSyntax("Nz3".to_string())));
+        assert_eq!(board.parse_san(""), Err(SanError::InvalidSyntax("".to_string())));
+    }
+
+    #[test]
+    fn round_trip() {
+        for line in STANDARD_EPD.lines() {
+            let fen = line.split(';').next().unwrap().trim();
+            let mut board = board(fen);
+
+            for mv in board.legal_moves() {
+                let san = board.move_to_san(&mv);
+                assert_eq!(board.parse_san(&san), Ok(mv), "{fen}: {san}");
+            }
+        }
+    }
+}
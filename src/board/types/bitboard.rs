@@ -1,6 +1,9 @@
+/// Set of squares, bit `n` being square `n`
 pub type Bitboard = u64;
 
+/// Debugging helpers for bitboards
 pub trait BitboardExt {
+    /// Print the set squares as a board, rank 8 at the top
     fn print(&self);
 }
 
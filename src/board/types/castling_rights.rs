@@ -1,12 +1,18 @@
-#[derive(Clone, Copy, Debug)]
+/// Castling still allowed for each side and wing
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct CastlingRights {
+    /// White may castle queenside (O-O-O)
     pub white_queen_side: bool,
+    /// White may castle kingside (O-O)
     pub white_king_side: bool,
+    /// Black may castle queenside (O-O-O)
     pub black_queen_side: bool,
+    /// Black may castle kingside (O-O)
     pub black_king_side: bool,
 }
 
 impl CastlingRights {
+    /// All rights, as in the starting position
     pub const fn default() -> Self {
         Self {
             white_queen_side: true,
@@ -16,6 +22,7 @@ impl CastlingRights {
         }
     }
 
+    /// No rights at all
     pub const fn default_but_false() -> Self {
         Self {
             white_queen_side: false,
@@ -1,10 +1,14 @@
+/// Side, of a piece or to move
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Color {
+    /// Moves first
     White,
+    /// Moves second
     Black,
 }
 
 impl Color {
+    /// The other side
     pub const fn inverse(&self) -> Self {
         match self {
             Self::White => Self::Black,
@@ -12,13 +16,7 @@ impl Color {
         }
     }
 
-    pub const fn from_bool(bool: bool) -> Self {
-        match bool {
-            true  => Self::White,
-            false => Self::Black,
-        }
-    }
-
+    /// Whether this is white
     pub const fn is_white(&self) -> bool {
         match self {
             Self::White => true,
@@ -26,6 +24,7 @@ impl Color {
         }
     }
 
+    /// 0 for white, 1 for black
     pub const fn index(&self) -> usize {
         match self {
             Self::White => 0,
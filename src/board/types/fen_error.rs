@@ -0,0 +1,57 @@
+use std::fmt;
+
+use crate::board::{Color, Square, SquareExt};
+
+/// Why a FEN string could not be parsed
+#[derive(Debug, Clone, PartialEq)]
+pub enum FenError {
+    /// Not 4 to 6 whitespace separated fields
+    FieldCount(usize),
+    /// Not 8 ranks in the placement
+    RankCount(usize),
+    /// A rank (1-8) with more or less than 8 squares
+    RankLength(usize),
+    /// Not a piece letter or a digit in the placement
+    InvalidPiece(char),
+    /// Side to move other than "w" or "b"
+    InvalidColor(String),
+    /// Castling field other than "-" or some of "KQkq"
+    InvalidCastling(String),
+    /// En passant field that isn't "-" or a square on the 6th rank (3rd with black to move)
+    InvalidEnPassant(String),
+    /// Halfmove clock that isn't a number
+    InvalidHalfmoveClock(String),
+    /// Fullmove number that isn't a positive number
+    InvalidFullmoveNumber(String),
+    /// A side without exactly one king
+    KingCount(Color, u32),
+    /// A pawn on the first or last rank
+    PawnOnBackRank(Square),
+    /// The side that just moved left its king in check
+    OpponentInCheck,
+    /// A castling right without the king and rook on their squares
+    CastlingWithoutPieces(char),
+    /// An en passant square no pawn can have just skipped
+    EnPassantWithoutPawn(Square),
+}
+
+impl fmt::Display for FenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FieldCount(count) => write!(f, "expected 4 to 6 fields, found {count}"),
+            Self::RankCount(count) => write!(f, "expected 8 ranks, found {count}"),
+            Self::RankLength(rank) => write!(f, "rank {rank} does not have 8 squares"),
+            Self::InvalidPiece(c) => write!(f, "invalid piece '{c}'"),
+            Self::InvalidColor(s) => write!(f, "invalid side to move '{s}'"),
+            Self::InvalidCastling(s) => write!(f, "invalid castling rights '{s}'"),
+            Self::InvalidEnPassant(s) => write!(f, "invalid en passant square '{s}'"),
+            Self::InvalidHalfmoveClock(s) => write!(f, "invalid halfmove clock '{s}'"),
+            Self::InvalidFullmoveNumber(s) => write!(f, "invalid fullmove number '{s}'"),
+            Self::KingCount(color, count) => write!(f, "{color:?} has {count} kings"),
+            Self::PawnOnBackRank(square) => write!(f, "pawn on {}", square.to_algebraic()),
+            Self::OpponentInCheck => write!(f, "the side not to move is in check"),
+            Self::CastlingWithoutPieces(c) => write!(f, "castling right '{c}' without the king and rook on their squares"),
+            Self::EnPassantWithoutPawn(square) => write!(f, "no pawn can have just moved past {}", square.to_algebraic()),
+        }
+    }
+}
@@ -6,7 +6,9 @@ use crate::board::{
     Occupied,
     Square
 };
+use crate::eval::EvalState;
 
+#[derive(Clone)]
 pub struct HistoryState {
     pub bitboards: [Bitboard; 12],
     pub mailbox: Mailbox,
@@ -14,4 +16,8 @@ pub struct HistoryState {
     pub color_to_move: Color,
     pub en_passant_square: Option<Square>,
     pub castling_rights: CastlingRights,
+    pub halfmove_clock: u32,
+    pub fullmove_number: u32,
+    pub pawn_key: u64,
+    pub eval_state: EvalState,
 }
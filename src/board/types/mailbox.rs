@@ -72,3 +72,9 @@ impl Mailbox {
         self.0[square as usize] = piece;
     }
 }
+
+impl Default for Mailbox {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -7,13 +7,19 @@ mod r#move;
 mod occupied;
 mod castling_rights;
 mod history_state;
+mod fen_error;
+mod san_error;
+mod uci_move_error;
 
 pub use square::*;
 pub use color::*;
-pub use mailbox::*;
+pub(crate) use mailbox::*;
 pub use pieces::*;
 pub use bitboard::*;
 pub use r#move::*;
 pub use occupied::*;
 pub use castling_rights::*;
-pub use history_state::*;
+pub(crate) use history_state::*;
+pub use fen_error::*;
+pub use san_error::*;
+pub use uci_move_error::*;
@@ -1,24 +1,37 @@
 use super::{PieceType, Square, SquareExt};
 
+/// What a move does, besides moving a piece
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum MoveType {
+    /// Not known yet, e.g. a move read from UCI, see `Move::from_uci`
     Unknown,
+    /// Neither a capture nor a special move
     Quiet,
+    /// Takes the piece on the target square
     Capture,
+    /// A pawn taking a pawn that just moved two squares past it
     EnPassant,
+    /// O-O, given as the king's move
     CastleKingside,
+    /// O-O-O, given as the king's move
     CastleQueenside,
+    /// A pawn reaching the last rank, capturing or not
     Promotion(PieceType),
 }
 
-#[derive(Clone, Copy, Debug)]
+/// A move from one square to another
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Move {
+    /// Square the piece leaves
     pub from: Square,
+    /// Square the piece lands on
     pub to: Square,
+    /// Kind of move
     pub move_type: MoveType,
 }
 
 impl Move {
+    /// A move of the given type
     pub const fn new(
         from: Square,
         to: Square,
@@ -27,6 +40,9 @@ impl Move {
         Self { from, to, move_type }
     }
 
+    /// Parse a UCI move such as "e7e8q", its type is only known for promotions.
+    /// Panics if it isn't a UCI move. `Board::make_move` needs the type, so
+    /// use `Board::parse_uci` to read a move to play.
     pub fn from_uci(uci: &str) -> Move {
         let from = Square::from_algebraic(&uci[0..2]);
         let to = Square::from_algebraic(&uci[2..4]);
@@ -36,13 +52,14 @@ impl Move {
             Some('b') | Some('B') => MoveType::Promotion(PieceType::Bishop),
             Some('r') | Some('R') => MoveType::Promotion(PieceType::Rook),
             Some('q') | Some('Q') => MoveType::Promotion(PieceType::Queen),
-            None => MoveType::Quiet,
+            None => MoveType::Unknown,
             _ => panic!(),
         };
 
         Self { from, to, move_type }
     }
 
+    /// UCI notation, such as "e7e8q"
     pub fn to_uci(&self) -> String {
         let mut uci = format!("{}{}", self.from.to_algebraic(), self.to.to_algebraic());
 
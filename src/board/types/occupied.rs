@@ -1,18 +1,24 @@
 use super::bitboard::Bitboard;
 use super::color::Color;
 
+/// Squares occupied by each side
 #[derive(Clone, Copy)]
 pub struct Occupied {
+    /// White pieces
     pub white: Bitboard,
+    /// Black pieces
     pub black: Bitboard,
+    /// Pieces of both sides
     pub all: Bitboard,
 }
 
 impl Occupied {
+    /// An empty board
     pub const fn new() -> Self {
         Self { white: 0, black: 0, all: 0 }
     }
 
+    /// Pieces of the opponent of `color`
     #[inline(always)]
     pub const fn enemy(&self, color: &Color) -> Bitboard {
         match color {
@@ -21,3 +27,9 @@ impl Occupied {
         }
     }
 }
+
+impl Default for Occupied {
+    fn default() -> Self {
+        Self::new()
+    }
+}
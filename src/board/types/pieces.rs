@@ -12,36 +12,31 @@ define_pieces! {
 }
 
 impl PieceType {
+    /// Index of this piece type and color in `[Bitboard; 12]` tables, white first
     #[inline(always)]
     pub const fn index(&self, color: &Color) -> usize {
         *self as usize + PIECETYPE_COUNT * color.index()
     }
-
-    pub const fn value(&self) -> i16 {
-        match self {
-            PieceType::Pawn   => 100,
-            PieceType::Knight => 300,
-            PieceType::Bishop => 300,
-            PieceType::Rook   => 500,
-            PieceType::Queen  => 900,
-            PieceType::King   => 10000,
-        }
-    }
 }
 
-#[derive(Clone, Copy, Debug)]
+/// A piece of some color
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Piece {
+    /// What piece it is
     pub piece_type: PieceType,
+    /// Whose piece it is
     pub color: Color,
 }
 
 impl Piece {
+    /// A piece of the given type and color
     pub const fn new(piece_type: PieceType, color: Color) -> Self {
         Self { piece_type, color }
     }
 
-    pub const fn from_char(c: char) -> Self {
-        match c {
+    /// Parse a FEN letter, uppercase for white
+    pub const fn try_from_char(c: char) -> Option<Self> {
+        Some(match c {
             'P' => Piece { piece_type: PieceType::Pawn,   color: Color::White },
             'p' => Piece { piece_type: PieceType::Pawn,   color: Color::Black },
 
@@ -60,10 +55,11 @@ impl Piece {
             'K' => Piece { piece_type: PieceType::King,   color: Color::White },
             'k' => Piece { piece_type: PieceType::King,   color: Color::Black },
 
-            _ => unreachable!()
-        }
+            _ => return None
+        })
     }
 
+    /// FEN letter, uppercase for white
     pub const fn to_char(&self) -> char {
         match (self.piece_type, &self.color) {
             (PieceType::Pawn,   Color::White) => 'P',
@@ -86,6 +82,7 @@ impl Piece {
         }
     }
 
+    /// Index in `[Bitboard; 12]` tables, white first
     #[inline(always)]
     pub const fn index(&self) -> usize {
         self.piece_type as usize + PIECETYPE_COUNT * self.color.index()
@@ -0,0 +1,22 @@
+use std::fmt;
+
+/// Why a SAN move could not be parsed
+#[derive(Debug, Clone, PartialEq)]
+pub enum SanError {
+    /// Not SAN at all
+    InvalidSyntax(String),
+    /// SAN, but no legal move matches it
+    IllegalMove(String),
+    /// SAN matching several legal moves, it needs a disambiguation
+    AmbiguousMove(String),
+}
+
+impl fmt::Display for SanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidSyntax(san) => write!(f, "'{san}' is not a SAN move"),
+            Self::IllegalMove(san) => write!(f, "'{san}' is not a legal move"),
+            Self::AmbiguousMove(san) => write!(f, "'{san}' matches several legal moves"),
+        }
+    }
+}
@@ -1,6 +1,9 @@
+/// Square index, a1 = 0, b1 = 1, ..., h8 = 63
 pub type Square = u8;
 
+/// Conversions between squares and algebraic notation
 pub trait SquareExt {
+    /// Names of the squares, by index
     const ALGEBRAIC_TABLE: [&'static str; 64] = [
         "a1", "b1", "c1", "d1", "e1", "f1", "g1", "h1",
         "a2", "b2", "c2", "d2", "e2", "f2", "g2", "h2",
@@ -12,7 +15,11 @@ pub trait SquareExt {
         "a8", "b8", "c8", "d8", "e8", "f8", "g8", "h8"
     ];
 
+    /// Parse a square such as "e4", panics if it isn't one
     fn from_algebraic(alg: &str) -> Self;
+    /// Parse a square such as "e4"
+    fn try_from_algebraic(alg: &str) -> Option<Self> where Self: Sized;
+    /// Name of the square, such as "e4"
     fn to_algebraic(&self) -> &'static str;
 }
 
@@ -32,8 +39,18 @@ impl SquareExt for Square {
         (rank as u8 - b'1') * 8 + file as u8 - b'a'
     }
 
+    fn try_from_algebraic(s: &str) -> Option<Self> {
+        let &[file, rank] = s.as_bytes() else { return None };
+
+        if !(b'a'..=b'h').contains(&file) || !(b'1'..=b'8').contains(&rank) {
+            return None;
+        }
+
+        Some((rank - b'1') * 8 + file - b'a')
+    }
+
     fn to_algebraic(&self) -> &'static str {
-        assert!((0u8..64u8).contains(&self));
+        assert!((0u8..64u8).contains(self));
         Self::ALGEBRAIC_TABLE[*self as usize]
     }
 }
@@ -0,0 +1,19 @@
+use std::fmt;
+
+/// Why a UCI move could not be parsed
+#[derive(Debug, Clone, PartialEq)]
+pub enum UciMoveError {
+    /// Not a UCI move such as "e2e4" or "e7e8q"
+    InvalidSyntax(String),
+    /// A UCI move, but not a legal one
+    IllegalMove(String),
+}
+
+impl fmt::Display for UciMoveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidSyntax(uci) => write!(f, "'{uci}' is not a UCI move"),
+            Self::IllegalMove(uci) => write!(f, "'{uci}' is not a legal move"),
+        }
+    }
+}
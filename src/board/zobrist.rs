@@ -1,37 +1,65 @@
-use rand::Rng;
 use super::PIECETYPE_COUNT;
 
+/// SplitMix64, used instead of `rand` so the keys are identical on every run and build
+struct KeyRng(u64);
+
+impl KeyRng {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+/// Random keys hashing positions, see `Board::get_zobrist_hash`
+#[derive(Clone)]
 pub struct Zobrist {
+    /// One per piece on each square, see `Zobrist::index`
     pub pieces: [u64; 64 * PIECETYPE_COUNT * 2],
+    /// Present when white is to move
     pub side_to_move: u64,
-    pub castling_rights: [u64; 4],   // [White K, White Q, Black K, Black Q]
-    pub en_passant_file: [u64; 8],   // en passant file a-h
+    /// One per castling right: white kingside, white queenside, black kingside, black queenside
+    pub castling_rights: [u64; 4],
+    /// One per file a-h of the en passant square
+    pub en_passant_file: [u64; 8],
 }
 
 impl Zobrist {
+    /// Changing this invalidates anything keyed by hashes (e.g. saved transposition tables)
+    pub const SEED: u64 = 0x4550_5349_4C4F_4E00;
+
+    /// The keys the engine uses, from `SEED`
     pub fn new() -> Self {
-        let mut rng = rand::rng();
+        Self::with_seed(Self::SEED)
+    }
+
+    /// Keys from another seed
+    pub fn with_seed(seed: u64) -> Self {
+        let mut rng = KeyRng(seed);
 
         let mut pieces = [0; 64 * PIECETYPE_COUNT * 2];
         for color in 0..2 {
             for piece_type in 0..PIECETYPE_COUNT {
                 for square in 0..64 {
-                    pieces[Self::index(color, piece_type, square)] = rng.random();
+                    pieces[Self::index(color, piece_type, square)] = rng.next();
                 }
             }
         }
 
-        let castling_rights = std::array::from_fn(|_| rng.random());
-        let en_passant_file = std::array::from_fn(|_| rng.random());
+        let castling_rights = std::array::from_fn(|_| rng.next());
+        let en_passant_file = std::array::from_fn(|_| rng.next());
 
         Self {
             pieces,
-            side_to_move: rng.random(),
+            side_to_move: rng.next(),
             castling_rights,
             en_passant_file,
         }
     }
 
+    /// Index of a (color, piece type, square) key in `pieces`
     #[inline(always)]
     pub const fn index(color: usize, piece_type: usize, square: u8) -> usize {
         debug_assert!(color < 2);
@@ -40,3 +68,9 @@ impl Zobrist {
         (color * PIECETYPE_COUNT + piece_type) * 64 + square as usize
     }
 }
+
+impl Default for Zobrist {
+    fn default() -> Self {
+        Self::new()
+    }
+}
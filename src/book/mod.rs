@@ -0,0 +1,9 @@
+//! Polyglot opening books
+
+use crate::board::{Board, Color, Move, MoveType, PieceType, Square};
+use crate::movegen::MoveGen;
+
+mod random;
+mod polyglot;
+
+pub use polyglot::{Book, BookError};
@@ -0,0 +1,291 @@
+use rand::Rng;
+
+use super::random::{RANDOM64, RANDOM_CASTLE, RANDOM_EN_PASSANT, RANDOM_PIECE, RANDOM_TURN};
+use super::{Board, Color, Move, MoveGen, MoveType, PieceType, Square};
+
+/*
+ * Polyglot book format
+ *
+ * A book is a list of 16 byte big-endian entries sorted by key:
+ *
+ *   offset  size  field
+ *   0       8     key: Polyglot hash of the position
+ *   8       2     move
+ *   10      2     weight
+ *   12      4     learn (unused)
+ *
+ * Move bits: 0..3 to file, 3..6 to rank, 6..9 from file, 9..12 from rank,
+ * 12..15 promotion (none, knight, bishop, rook, queen). Castling is stored
+ * as the king capturing its own rook (e1h1, e1a1, e8h8, e8a8).
+ */
+
+const ENTRY_SIZE: usize = 16;
+
+/// Why a book could not be read
+#[derive(Debug)]
+pub enum BookError {
+    /// The file could not be read
+    Io(std::io::Error),
+    /// A size that isn't a whole number of entries
+    WrongLength(usize),
+}
+
+impl std::fmt::Display for BookError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Io(err)         => write!(f, "{err}"),
+            Self::WrongLength(len) => {
+                write!(f, "book size {len} is not a multiple of {ENTRY_SIZE} bytes")
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct BookEntry {
+    key: u64,
+    mv: u16,
+    weight: u16,
+}
+
+/// A Polyglot opening book, loaded in memory
+pub struct Book {
+    entries: Vec<BookEntry>,
+}
+
+impl Book {
+    /// Read a book from the contents of a `.bin` file
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, BookError> {
+        if !bytes.len().is_multiple_of(ENTRY_SIZE) {
+            return Err(BookError::WrongLength(bytes.len()));
+        }
+
+        let entries = bytes
+            .chunks_exact(ENTRY_SIZE)
+            .map(|entry| BookEntry {
+                key: u64::from_be_bytes(entry[0..8].try_into().unwrap()),
+                mv: u16::from_be_bytes(entry[8..10].try_into().unwrap()),
+                weight: u16::from_be_bytes(entry[10..12].try_into().unwrap()),
+            })
+            .collect();
+
+        Ok(Self { entries })
+    }
+
+    /// Read a `.bin` book file
+    pub fn from_file(path: &str) -> Result<Self, BookError> {
+        Self::from_bytes(&std::fs::read(path).map_err(BookError::Io)?)
+    }
+
+    /// Number of entries
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the book has no entries
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Polyglot hash of a position, unrelated to `Board::get_zobrist_hash`
+    pub fn key(board: &Board) -> u64 {
+        let mut key = 0;
+
+        for square in 0..64 {
+            if let Some(piece) = board.get_piece(square) {
+                let kind = 2 * piece.piece_type as usize + (piece.color == Color::White) as usize;
+                key ^= RANDOM64[RANDOM_PIECE + 64 * kind + square as usize];
+            }
+        }
+
+        let castling_rights = board.get_castling_rights();
+        if castling_rights.white_king_side  { key ^= RANDOM64[RANDOM_CASTLE]; }
+        if castling_rights.white_queen_side { key ^= RANDOM64[RANDOM_CASTLE + 1]; }
+        if castling_rights.black_king_side  { key ^= RANDOM64[RANDOM_CASTLE + 2]; }
+        if castling_rights.black_queen_side { key ^= RANDOM64[RANDOM_CASTLE + 3]; }
+
+        // Polyglot only hashes the en passant file if a pawn can actually capture there
+        if let Some(ep_square) = board.get_en_passant_square()
+            && Self::can_capture_en_passant(board, ep_square)
+        {
+            key ^= RANDOM64[RANDOM_EN_PASSANT + (ep_square % 8) as usize];
+        }
+
+        if *board.color_to_move() == Color::White {
+            key ^= RANDOM64[RANDOM_TURN];
+        }
+
+        key
+    }
+
+    fn can_capture_en_passant(board: &Board, ep_square: Square) -> bool {
+        let color = *board.color_to_move();
+
+        // The pawns that could capture sit next to the pawn that just moved
+        let pawn_square = match color {
+            Color::White => ep_square - 8,
+            Color::Black => ep_square + 8,
+        };
+        let file = pawn_square % 8;
+
+        [(file > 0, pawn_square.wrapping_sub(1)), (file < 7, pawn_square + 1)]
+            .into_iter()
+            .filter(|&(on_board, _)| on_board)
+            .filter_map(|(_, square)| board.get_piece(square))
+            .any(|piece| piece.piece_type == PieceType::Pawn && piece.color == color)
+    }
+
+    /// Turn a Polyglot move into a legal `Move` for this position
+    fn decode_move(board: &mut Board, mv: u16) -> Option<Move> {
+        let to = (mv & 0o77) as Square;
+        let from = ((mv >> 6) & 0o77) as Square;
+        let promotion = match (mv >> 12) & 0b111 {
+            0 => None,
+            1 => Some(PieceType::Knight),
+            2 => Some(PieceType::Bishop),
+            3 => Some(PieceType::Rook),
+            4 => Some(PieceType::Queen),
+            _ => return None,
+        };
+
+        let piece = *board.get_piece(from)?;
+
+        // King takes own rook is castling
+        let to = match (piece.piece_type, from, to) {
+            (PieceType::King, 4, 7)   => 6,
+            (PieceType::King, 4, 0)   => 2,
+            (PieceType::King, 60, 63) => 62,
+            (PieceType::King, 60, 56) => 58,
+            _ => to,
+        };
+
+        let mut mv = Move::new(from, to, match promotion {
+            Some(piece_type) => MoveType::Promotion(piece_type),
+            None => MoveType::Unknown,
+        });
+        board.find_move_type(&mut mv);
+
+        // Entries can be corrupt or hash collisions, only play moves we'd generate ourselves
+        let legal = MoveGen::get_pseudolegal_moves(board)
+            .into_iter()
+            .filter(|m| m.from == mv.from && m.to == mv.to)
+            .any(|m| match (m.move_type, mv.move_type) {
+                (MoveType::Promotion(a), MoveType::Promotion(b)) => a == b,
+                (MoveType::Promotion(_), _) | (_, MoveType::Promotion(_)) => false,
+                _ => true,
+            });
+        if !legal {
+            return None;
+        }
+
+        board.make_move(&mv);
+        let illegal = board.was_illegal_move();
+        board.revert_state();
+
+        (!illegal).then_some(mv)
+    }
+
+    /// Legal book moves for this position with their weights
+    pub fn moves(&self, board: &mut Board) -> Vec<(Move, u16)> {
+        let key = Self::key(board);
+        let start = self.entries.partition_point(|entry| entry.key < key);
+
+        self.entries[start..]
+            .iter()
+            .take_while(|entry| entry.key == key)
+            .filter_map(|entry| Self::decode_move(board, entry.mv).map(|mv| (mv, entry.weight)))
+            .collect()
+    }
+
+    /// Pick a book move, either the highest weighted one or randomly in proportion to the weights
+    pub fn probe(&self, board: &mut Board, best: bool) -> Option<Move> {
+        let moves = self.moves(board);
+
+        if best {
+            return moves.iter().max_by_key(|(_, weight)| *weight).map(|(mv, _)| *mv);
+        }
+
+        let total: u32 = moves.iter().map(|(_, weight)| *weight as u32).sum();
+        if total == 0 {
+            return moves.first().map(|(mv, _)| *mv);
+        }
+
+        let mut pick = rand::rng().random_range(0..total);
+        for (mv, weight) in moves {
+            if pick < weight as u32 {
+                return Some(mv);
+            }
+            pick -= weight as u32;
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Keys from the Polyglot format description
+    const KEYS: [(&str, u64); 9] = [
+        ("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1", 0x463b96181691fc9c),
+        ("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1", 0x823c9b50fd114196),
+        ("rnbqkbnr/ppp1pppp/8/3p4/4P3/8/PPPP1PPP/RNBQKBNR w KQkq d6 0 2", 0x0756b94461c50fb0),
+        ("rnbqkbnr/ppp1pppp/8/3pP3/8/8/PPPP1PPP/RNBQKBNR b KQkq - 0 2", 0x662fafb965db29d4),
+        ("rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3", 0x22a48b5a8e47ff78),
+        ("rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPPKPPP/RNBQ1BNR b kq - 0 3", 0x652a607ca3f242c1),
+        ("rnbq1bnr/ppp1pkpp/8/3pPp2/8/8/PPPPKPPP/RNBQ1BNR w - - 0 4", 0x00fdd303c946bdd9),
+        ("rnbqkbnr/p1pppppp/8/8/PpP4P/8/1P1PPPP1/RNBQKBNR b KQkq c3 0 3", 0x3c8123ea7b067637),
+        ("rnbqkbnr/p1pppppp/8/8/P6P/R1p5/1P1PPPP1/1NBQKBNR b Kkq - 0 4", 0x5c3f9b829b279560),
+    ];
+
+    fn entry(key: u64, mv: u16, weight: u16) -> [u8; ENTRY_SIZE] {
+        let mut bytes = [0; ENTRY_SIZE];
+        bytes[0..8].copy_from_slice(&key.to_be_bytes());
+        bytes[8..10].copy_from_slice(&mv.to_be_bytes());
+        bytes[10..12].copy_from_slice(&weight.to_be_bytes());
+        bytes
+    }
+
+    fn encode(from: Square, to: Square) -> u16 {
+        ((from as u16) << 6) | to as u16
+    }
+
+    #[test]
+    fn keys() {
+        for (fen, key) in KEYS {
+            assert_eq!(Book::key(&Board::from_fen(fen).unwrap()), key, "{fen}");
+        }
+    }
+
+    #[test]
+    fn castling_is_decoded_as_king_takes_rook() {
+        let mut board = Board::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1").unwrap();
+        let key = Book::key(&board);
+
+        let bytes = [entry(key, encode(4, 7), 2), entry(key, encode(4, 0), 1)].concat();
+        let book = Book::from_bytes(&bytes).unwrap();
+        assert_eq!(book.len(), 2);
+
+        let moves = book.moves(&mut board);
+        assert_eq!(moves, [
+            (Move::new(4, 6, MoveType::CastleKingside), 2),
+            (Move::new(4, 2, MoveType::CastleQueenside), 1),
+        ]);
+        assert_eq!(book.probe(&mut board, true), Some(Move::new(4, 6, MoveType::CastleKingside)));
+    }
+
+    #[test]
+    fn illegal_entries_are_skipped() {
+        let mut board = Board::startpos();
+        let key = Book::key(&board);
+
+        // e2e5 and a move from an empty square
+        let bytes = [entry(key, encode(12, 36), 1), entry(key, encode(20, 28), 1)].concat();
+        let book = Book::from_bytes(&bytes).unwrap();
+
+        assert!(book.moves(&mut board).is_empty());
+        assert!(Book::from_bytes(&[]).unwrap().is_empty());
+        assert!(matches!(Book::from_bytes(&[0; 17]), Err(BookError::WrongLength(17))));
+    }
+}
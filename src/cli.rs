@@ -0,0 +1,155 @@
+use std::process::ExitCode;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use epsilon::board::Board;
+use epsilon::epd::Epd;
+use epsilon::eval::Trace;
+use epsilon::search::{Search, SearchLimits, SearchOptions, TranspositionTable};
+
+use crate::bench::{self, Bench};
+use crate::debug::Debug;
+
+const USAGE: &str = "\
+Usage: Epsilon [command]
+
+Without a command, speaks UCI on stdin/stdout.
+
+Commands:
+  bench [depth]                         Search a fixed position set, print the node count
+  perft <depth> [fen] [--threads <n>]   Count the leaf nodes of the move tree
+  perft-suite <file> [maxdepth]         Check the Dn counts of an EPD file
+  eval <fen>                            Print the evaluation breakdown
+  search <fen> [options]                Search a position and print the best move
+      --depth <n>                       Search to a fixed depth (default 5)
+      --movetime <ms>                   Search for a fixed time instead
+      --threads <n>                     Search threads (default 1)
+      --hash <mb>                       Transposition table size (default 16)
+  help                                  Print this message";
+
+pub struct Cli;
+
+impl Cli {
+    /// Run a command given on the command line, `None` when there is none (UCI mode)
+    pub fn run(args: &[String]) -> Option<ExitCode> {
+        let (command, args) = args.split_first()?;
+
+        let result = match command.as_str() {
+            "bench" => Self::bench(args),
+            "perft" => Self::perft(args),
+            "perft-suite" => Self::perft_suite(args),
+            "eval" => Self::eval(args),
+            "search" => Self::search(args),
+            "help" | "--help" | "-h" => {
+                println!("{USAGE}");
+                Ok(ExitCode::SUCCESS)
+            }
+            _ => Err(format!("unknown command '{command}'")),
+        };
+
+        Some(result.unwrap_or_else(|err| {
+            eprintln!("Error: {err}\n\n{USAGE}");
+            ExitCode::from(2)
+        }))
+    }
+
+    fn parse<T: std::str::FromStr>(name: &str, value: Option<&String>) -> Result<T, String> {
+        let value = value.ok_or(format!("missing {name}"))?;
+        value.parse().map_err(|_| format!("invalid {name} '{value}'"))
+    }
+
+    /// A search depth of at least 1, deeper ones are capped at `SearchLimits::MAX_DEPTH`
+    fn depth(value: Option<&String>) -> Result<u8, String> {
+        match Self::parse::<u64>("depth", value)? {
+            0 => Err("depth must be at least 1".to_string()),
+            depth => Ok(depth.min(SearchLimits::MAX_DEPTH as u64) as u8),
+        }
+    }
+
+    /// A FEN given as one argument or spread over several, or `startpos`
+    fn board(fen: &[String]) -> Result<Board, String> {
+        if fen.is_empty() || fen == ["startpos"] {
+            return Ok(Board::startpos());
+        }
+
+        Board::from_fen(&fen.join(" ")).map_err(|err| format!("invalid FEN: {err}"))
+    }
+
+    fn bench(args: &[String]) -> Result<ExitCode, String> {
+        let depth = match args.first() {
+            Some(_) => Self::depth(args.first())?,
+            None => bench::DEFAULT_DEPTH,
+        };
+
+        Bench::run(depth);
+        Ok(ExitCode::SUCCESS)
+    }
+
+    fn perft(args: &[String]) -> Result<ExitCode, String> {
+        let depth = Self::parse("depth", args.first())?;
+        let split = args.iter().position(|arg| arg == "--threads").unwrap_or(args.len());
+        let board = Self::board(&args[1..split.max(1)])?;
+        let threads = if split < args.len() { Self::parse("threads", args.get(split + 1))? } else { 1 };
+
+        let start = Instant::now();
+        let nodes = Debug::perft_parallel(&board, depth, threads, None);
+        let elapsed = start.elapsed().as_secs_f64();
+
+        println!("Time: {elapsed}s\nNodes: {nodes}\nNodes/s: {}", (nodes as f64 / elapsed) as u64);
+        Ok(ExitCode::SUCCESS)
+    }
+
+    fn perft_suite(args: &[String]) -> Result<ExitCode, String> {
+        let path = args.first().ok_or("missing file")?;
+        let max_depth = match args.get(1) {
+            Some(_) => Self::parse("maxdepth", args.get(1))?,
+            None => 6,
+        };
+
+        let positions = Epd::from_file(path).map_err(|err| format!("failed to read {path}: {err}"))?;
+
+        Ok(if Debug::perft_suite(&positions, max_depth) { ExitCode::SUCCESS } else { ExitCode::FAILURE })
+    }
+
+    fn eval(args: &[String]) -> Result<ExitCode, String> {
+        let board = Self::board(args)?;
+        println!("{}", Trace::new(&board));
+        Ok(ExitCode::SUCCESS)
+    }
+
+    fn search(args: &[String]) -> Result<ExitCode, String> {
+        // Everything before the first option is the FEN
+        let split = args.iter().position(|arg| arg.starts_with("--")).unwrap_or(args.len());
+        let board = Self::board(&args[..split])?;
+
+        let mut limits = SearchLimits::depth(5);
+        let mut search_options = SearchOptions {
+            info: Some(Arc::new(|info| println!("{info}"))),
+            ..SearchOptions::default()
+        };
+        let mut hash_mb = 16;
+
+        let mut options = args[split..].iter();
+        while let Some(option) = options.next() {
+            match option.as_str() {
+                "--depth" => limits = SearchLimits::depth(Self::depth(options.next())?),
+                "--movetime" => {
+                    let ms = Self::parse("movetime", options.next())?;
+                    limits = SearchLimits::movetime(Duration::from_millis(ms));
+                }
+                "--threads" => search_options.threads = Self::parse("threads", options.next())?,
+                "--hash" => hash_mb = Self::parse("hash", options.next())?,
+                _ => return Err(format!("unknown option '{option}'")),
+            }
+        }
+
+        let tt = TranspositionTable::new(hash_mb);
+        let result = Search::lazy_smp(&board, &tt, limits, &search_options);
+
+        match result.best_move {
+            Some(mv) => println!("bestmove {}", mv.to_uci()),
+            None => println!("bestmove 0000"),
+        }
+        Ok(ExitCode::SUCCESS)
+    }
+}
@@ -1,5 +1,48 @@
+//! Perft, plain, hashed and parallel, and perft suites
+
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::thread;
 use std::time::Instant;
-use crate::{movegen::MoveGen, board::Board};
+use epsilon::{movegen::MoveGen, board::{Board, Move}, epd::Epd};
+
+/*
+ * Perft results by (Zobrist key, depth), shared between threads without
+ * locks. A slot holds the key xored with the data, and the data:
+ * node count << 8 | depth. Torn writes fail the key check.
+ */
+pub struct PerftTable {
+    slots: Vec<[AtomicU64; 2]>,
+}
+
+impl PerftTable {
+    pub fn new(size_mb: usize) -> Self {
+        let size = ((size_mb * 1_000_000) / std::mem::size_of::<[AtomicU64; 2]>()).max(1);
+        Self { slots: (0..size).map(|_| [AtomicU64::new(0), AtomicU64::new(0)]).collect() }
+    }
+
+    #[inline(always)]
+    fn slot(&self, key: u64, depth: i32) -> &[AtomicU64; 2] {
+        // Positions are revisited at different depths, keep them apart
+        let key = key ^ (depth as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        &self.slots[((key as u128 * self.slots.len() as u128) >> 64) as usize]
+    }
+
+    fn get(&self, key: u64, depth: i32) -> Option<u64> {
+        let [stored_key, stored_data] = self.slot(key, depth);
+        let data = stored_data.load(Ordering::Relaxed);
+
+        (stored_key.load(Ordering::Relaxed) ^ data == key && data & 0xff == depth as u64)
+            .then_some(data >> 8)
+    }
+
+    fn set(&self, key: u64, depth: i32, nodes: u64) {
+        let [stored_key, stored_data] = self.slot(key, depth);
+        let data = nodes << 8 | depth as u64;
+
+        stored_key.store(key ^ data, Ordering::Relaxed);
+        stored_data.store(data, Ordering::Relaxed);
+    }
+}
 
 pub struct Debug;
 
@@ -16,16 +59,16 @@ impl Debug {
             board.make_move(mv);
 
             if board.was_illegal_move() {
-                board.revert_state();
+                board.undo_move();
                 continue;
             }
 
             total_nodes += Self::perft(board, depth - 1);
 
-            board.revert_state();
+            board.undo_move();
         }
 
-        return total_nodes;
+        total_nodes
     }
 
     pub fn divide(board: &mut Board, depth: i32) -> u64 {
@@ -36,7 +79,7 @@ impl Debug {
             board.make_move(mv);
 
             if board.was_illegal_move() {
-                board.revert_state();
+                board.undo_move();
                 continue;
             }
 
@@ -44,10 +87,10 @@ impl Debug {
             println!("{}: {move_nodes}", mv.to_uci());
             total_nodes += move_nodes;
 
-            board.revert_state();
+            board.undo_move();
         }
 
-        return total_nodes;
+        total_nodes
     }
 
     pub fn timed_perft(board: &mut Board, depth: i32) -> (f64, u64) {
@@ -56,4 +99,189 @@ impl Debug {
         let elapsed = start.elapsed().as_secs_f64();
         (elapsed, nodes)
     }
+
+    /// Check every `Dn` count of the positions up to `max_depth`, drilling down into
+    /// the first failure of each position. Returns whether every count matched.
+    pub fn perft_suite(positions: &[Epd], max_depth: u32) -> bool {
+        let mut total_time = 0.0;
+        let mut total_nodes = 0;
+        let mut failures = 0;
+
+        for epd in positions {
+            let mut board = epd.board();
+            println!("Fen: {}", board.to_fen());
+
+            for (depth, expected) in epd.perft_counts() {
+                if depth > max_depth {
+                    continue;
+                }
+
+                let start = Instant::now();
+                let nodes = Self::perft_fast(&mut board, depth as i32, None);
+                let elapsed = start.elapsed().as_secs_f64();
+                total_time += elapsed;
+                total_nodes += nodes;
+
+                let passed = nodes == expected;
+                println!(
+                    "Depth: {depth} | Nodes: {nodes:>12} | Expected: {expected:>12} | Time: {elapsed:>10.6}s | Nodes/s: {:>10} | {}",
+                    (nodes as f64 / elapsed) as u64,
+                    if passed { "PASS" } else { "FAIL" }
+                );
+
+                if !passed {
+                    failures += 1;
+
+                    println!("Divide at depth {depth}, to compare with a reference engine:");
+                    Self::divide(&mut board, depth as i32);
+                    Self::drill_down(&mut board, depth as i32, &mut Vec::new());
+                    break;
+                }
+            }
+
+            println!();
+        }
+
+        println!("Total Time: {total_time:.3}s");
+        println!("Total Nodes: {total_nodes}");
+        println!("Average Nodes/s: {}", (total_nodes as f64 / total_time) as u64);
+        println!("Failures: {failures}");
+
+        failures == 0
+    }
+
+    /*
+     * Without reference counts for every move, the best automatic checks are
+     * the board's own. At every step, the legal moves must be the pseudolegal
+     * moves that don't leave the king in check once made, and the counts after
+     * make/unmake must match the ones from a fresh board set up from the FEN,
+     * making every move. Follow the first move where they don't. A move the pseudolegal generator misses or
+     * invents fools both checks, so that still needs a divide against a
+     * reference engine.
+     */
+    fn drill_down(board: &mut Board, depth: i32, path: &mut Vec<String>) {
+        let (missing, extra) = Self::legality_errors(board);
+        if !missing.is_empty() || !extra.is_empty() {
+            let after = if path.is_empty() { String::from("the root") } else { path.join(" ") };
+            println!(
+                "Legal moves after {after} ({}) differ from making every pseudolegal move: missing {}, extra {}",
+                board.to_fen(),
+                missing.join(" "),
+                extra.join(" ")
+            );
+            return;
+        }
+
+        for mv in board.legal_moves() {
+            board.make_move(&mv);
+
+            let fen = board.to_fen();
+            let fast = Self::perft_fast(board, depth - 1, None);
+            let reference = Self::perft(&mut Board::from_fen(&fen).unwrap(), depth - 1);
+
+            if fast != reference {
+                path.push(mv.to_uci());
+                println!(
+                    "Diverges after {}: {fast} nodes after make/unmake, {reference} making every move from {fen}",
+                    path.join(" ")
+                );
+                Self::drill_down(board, depth - 1, path);
+                board.undo_move();
+                return;
+            }
+
+            board.undo_move();
+        }
+
+        if path.is_empty() {
+            println!("The legality check and make/unmake agree with their references, so the difference is in pseudolegal move generation");
+        } else {
+            println!("The board after {} is out of sync with its FEN", path.join(" "));
+        }
+    }
+
+    /// Legal moves `legal_moves` leaves out and illegal ones it keeps, found
+    /// by making every pseudolegal move and checking the king
+    fn legality_errors(board: &mut Board) -> (Vec<String>, Vec<String>) {
+        let fast = board.legal_moves();
+
+        let mut slow = Vec::new();
+        for mv in MoveGen::get_pseudolegal_moves(board) {
+            board.make_move(&mv);
+            if !board.was_illegal_move() {
+                slow.push(mv);
+            }
+            board.undo_move();
+        }
+
+        let missing = slow.iter().filter(|mv| !fast.contains(mv)).map(Move::to_uci).collect();
+        let extra = fast.iter().filter(|mv| !slow.contains(mv)).map(Move::to_uci).collect();
+        (missing, extra)
+    }
+
+    /// Perft that counts the legal moves at the last ply instead of making them,
+    /// and reuses the counts of transposed positions if given a table
+    pub fn perft_fast(board: &mut Board, depth: i32, table: Option<&PerftTable>) -> u64 {
+        if depth <= 0 {
+            return 1;
+        }
+
+        let moves = MoveGen::get_pseudolegal_moves(board);
+        if depth == 1 {
+            return moves.iter().filter(|mv| board.is_legal(mv)).count() as u64;
+        }
+
+        let key = board.get_zobrist_hash();
+        if let Some(nodes) = table.and_then(|table| table.get(key, depth)) {
+            return nodes;
+        }
+
+        let mut total_nodes = 0;
+        for mv in &moves {
+            if !board.is_legal(mv) {
+                continue;
+            }
+
+            board.make_move(mv);
+            total_nodes += Self::perft_fast(board, depth - 1, table);
+            board.undo_move();
+        }
+
+        if let Some(table) = table {
+            table.set(key, depth, total_nodes);
+        }
+
+        total_nodes
+    }
+
+    /// Fast perft with the root moves shared out between threads
+    pub fn perft_parallel(board: &Board, depth: i32, threads: usize, table: Option<&PerftTable>) -> u64 {
+        if depth <= 1 {
+            return Self::perft_fast(&mut board.clone(), depth, table);
+        }
+
+        let root_moves = board.clone().legal_moves();
+        let next = AtomicUsize::new(0);
+
+        thread::scope(|scope| {
+            let workers: Vec<_> = (0..threads.max(1))
+                .map(|_| {
+                    let mut board = board.clone();
+                    let (root_moves, next) = (&root_moves, &next);
+
+                    scope.spawn(move || {
+                        let mut nodes = 0;
+                        while let Some(mv) = root_moves.get(next.fetch_add(1, Ordering::Relaxed)) {
+                            board.make_move(mv);
+                            nodes += Self::perft_fast(&mut board, depth - 1, table);
+                            board.undo_move();
+                        }
+                        nodes
+                    })
+                })
+                .collect();
+
+            workers.into_iter().map(|worker| worker.join().unwrap()).sum()
+        })
+    }
 }
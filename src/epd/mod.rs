@@ -0,0 +1,10 @@
+//! EPD records and test suites
+
+use crate::board::{Board, FenError, Move, SanError};
+use crate::search::{Search, SearchLimits, SearchOptions, TranspositionTable};
+
+mod record;
+mod suite;
+
+pub use record::{Epd, EpdError};
+pub use suite::Suite;
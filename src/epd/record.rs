@@ -0,0 +1,265 @@
+use super::{Board, FenError, Move, SanError};
+
+/*
+ * EPD records
+ *
+ * The first four FEN fields, then operations terminated by ';':
+ *
+ *   r1b1k2r/ppppnppp/2n2q2/2b5/3NP3/2P1B3/PP3PPP/RN1QKB1R w KQkq - bm Nxc6; id "WAC.003";
+ *
+ * Operands are separated by spaces and may be quoted. The halfmove clock and
+ * fullmove number come from the `hmvc` and `fmvn` operations, or from two
+ * plain numbers after the FEN fields as in perft suites:
+ *
+ *   4k3/8/8/8/8/8/8/4K2R w K - 0 1 ;D1 15 ;D2 66
+ */
+
+/// Why an EPD record could not be read
+#[derive(Debug)]
+pub enum EpdError {
+    /// The file could not be read
+    Io(std::io::Error),
+    /// Fewer than the four FEN fields
+    MissingFields,
+    /// A string operand without its closing quote
+    UnterminatedString,
+    /// An invalid position
+    Fen(FenError),
+    /// An error on a line of a file
+    Line(usize, Box<EpdError>),
+}
+
+impl std::fmt::Display for EpdError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Io(err)            => write!(f, "{err}"),
+            Self::MissingFields      => write!(f, "expected the first four FEN fields"),
+            Self::UnterminatedString => write!(f, "unterminated string operand"),
+            Self::Fen(err)           => write!(f, "invalid position: {err}"),
+            Self::Line(line, err)    => write!(f, "line {line}: {err}"),
+        }
+    }
+}
+
+impl From<std::io::Error> for EpdError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// One EPD record: a position and its operations
+#[derive(Clone, Debug)]
+pub struct Epd {
+    fen: String, // All six fields
+    operations: Vec<(String, Vec<String>)>,
+}
+
+impl Epd {
+    /// Every record of a file, skipping blank lines
+    pub fn from_file(path: &str) -> Result<Vec<Self>, EpdError> {
+        std::fs::read_to_string(path)?
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| Self::parse(line).map_err(|err| EpdError::Line(i + 1, Box::new(err))))
+            .collect()
+    }
+
+    /// Parse one line. The move counters come from the `hmvc` and `fmvn`
+    /// operations, or a nameless first one as in perft suites.
+    pub fn parse(line: &str) -> Result<Self, EpdError> {
+        let mut rest = line.trim_start();
+        let mut fields = Vec::new();
+        for _ in 0..4 {
+            let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            if end == 0 {
+                return Err(EpdError::MissingFields);
+            }
+            fields.push(&rest[..end]);
+            rest = rest[end..].trim_start();
+        }
+
+        let mut operations = Self::parse_operations(rest)?;
+
+        // Perft suites write the counters as a nameless first operation
+        let mut counters = ["0", "1"].map(String::from);
+        if let Some((opcode, operands)) = operations.first()
+            && operands.len() <= 1
+            && std::iter::once(opcode).chain(operands).all(|s| s.parse::<u32>().is_ok())
+        {
+            counters[0] = opcode.clone();
+            if let Some(fullmove) = operands.first() {
+                counters[1] = fullmove.clone();
+            }
+            operations.remove(0);
+        }
+
+        for (opcode, operands) in &operations {
+            match (opcode.as_str(), operands.first()) {
+                ("hmvc", Some(value)) => counters[0] = value.clone(),
+                ("fmvn", Some(value)) => counters[1] = value.clone(),
+                _ => {}
+            }
+        }
+
+        let fen = format!("{} {} {}", fields.join(" "), counters[0], counters[1]);
+        Board::from_fen(&fen).map_err(EpdError::Fen)?;
+
+        Ok(Self { fen, operations })
+    }
+
+    fn parse_operations(text: &str) -> Result<Vec<(String, Vec<String>)>, EpdError> {
+        let mut operations = Vec::new();
+        let mut words: Vec<String> = Vec::new();
+        let mut chars = text.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                ';' => {
+                    if !words.is_empty() {
+                        let opcode = words.remove(0);
+                        operations.push((opcode, std::mem::take(&mut words)));
+                    }
+                }
+
+                '"' => {
+                    let mut word = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('"') => break,
+                            Some(c) => word.push(c),
+                            None => return Err(EpdError::UnterminatedString),
+                        }
+                    }
+                    words.push(word);
+                }
+
+                c if c.is_whitespace() => {}
+
+                c => {
+                    let mut word = c.to_string();
+                    while let Some(&c) = chars.peek() {
+                        if c.is_whitespace() || c == ';' || c == '"' {
+                            break;
+                        }
+                        word.push(c);
+                        chars.next();
+                    }
+                    words.push(word);
+                }
+            }
+        }
+
+        // The last ';' is often missing
+        if !words.is_empty() {
+            let opcode = words.remove(0);
+            operations.push((opcode, words));
+        }
+
+        Ok(operations)
+    }
+
+    /// The position
+    pub fn board(&self) -> Board {
+        Board::from_fen(&self.fen).expect("checked when parsed")
+    }
+
+    /// Operands of an operation, by opcode
+    pub fn operation(&self, opcode: &str) -> Option<&[String]> {
+        self.operations
+            .iter()
+            .find(|(op, _)| op == opcode)
+            .map(|(_, operands)| operands.as_slice())
+    }
+
+    /// The `id` operation
+    pub fn id(&self) -> Option<&str> {
+        self.operation("id")?.first().map(String::as_str)
+    }
+
+    /// The `c0` comment
+    pub fn comment(&self) -> Option<&str> {
+        self.operation("c0")?.first().map(String::as_str)
+    }
+
+    /// The `bm` moves, in SAN
+    pub fn best_moves(&self, board: &mut Board) -> Result<Vec<Move>, SanError> {
+        self.moves("bm", board)
+    }
+
+    /// The `am` moves, in SAN
+    pub fn avoid_moves(&self, board: &mut Board) -> Result<Vec<Move>, SanError> {
+        self.moves("am", board)
+    }
+
+    fn moves(&self, opcode: &str, board: &mut Board) -> Result<Vec<Move>, SanError> {
+        self.operation(opcode)
+            .unwrap_or_default()
+            .iter()
+            .map(|san| board.parse_san(san))
+            .collect()
+    }
+
+    /// (depth, node count) of the `D1`..`Dn` operations
+    pub fn perft_counts(&self) -> Vec<(u32, u64)> {
+        self.operations
+            .iter()
+            .filter_map(|(opcode, operands)| {
+                let depth = opcode.strip_prefix('D')?.parse().ok()?;
+                let count = operands.first()?.parse().ok()?;
+                Some((depth, count))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::{MoveType, PieceType};
+
+    #[test]
+    fn quoted_operands() {
+        let epd = Epd::parse(
+            r#"r1b1k2r/ppppnppp/2n2q2/2b5/3NP3/2P1B3/PP3PPP/RN1QKB1R w KQkq - bm Nxc6; id "WAC.003"; c0 "Wins a piece; or does it?";"#,
+        ).unwrap();
+
+        assert_eq!(epd.id(), Some("WAC.003"));
+        assert_eq!(epd.comment(), Some("Wins a piece; or does it?"));
+        assert_eq!(epd.operation("bm"), Some(&["Nxc6".to_string()][..]));
+        assert!(matches!(Epd::parse(r#"8/8/8/8/8/8/8/K1k5 w - - c0 "open"#), Err(EpdError::UnterminatedString)));
+    }
+
+    #[test]
+    fn several_best_moves() {
+        let epd = Epd::parse("7k/4P3/8/8/8/8/8/R3K3 w Q - bm e8=Q+ O-O-O Ra8+; am Kd1").unwrap();
+        let mut board = epd.board();
+
+        assert_eq!(epd.best_moves(&mut board).unwrap(), [
+            Move::new(52, 60, MoveType::Promotion(PieceType::Queen)),
+            Move::new(4, 2, MoveType::CastleQueenside),
+            Move::new(0, 56, MoveType::Quiet),
+        ]);
+        assert_eq!(epd.avoid_moves(&mut board).unwrap(), [Move::new(4, 3, MoveType::Quiet)]);
+        assert!(epd.operation("id").is_none());
+    }
+
+    #[test]
+    fn perft_counts() {
+        let epd = Epd::parse("4k3/8/8/8/8/8/8/4K2R w K - 0 1 ;D1 15 ;D2 66 ;D3 1197").unwrap();
+
+        assert_eq!(epd.perft_counts(), [(1, 15), (2, 66), (3, 1197)]);
+        assert_eq!(epd.board().to_fen(), "4k3/8/8/8/8/8/8/4K2R w K - 0 1");
+    }
+
+    #[test]
+    fn move_counters() {
+        let fen = |line: &str| Epd::parse(line).unwrap().board().to_fen();
+
+        assert_eq!(fen("4k3/8/8/8/8/8/8/4K3 b - -"), "4k3/8/8/8/8/8/8/4K3 b - - 0 1");
+        assert_eq!(fen("4k3/8/8/8/8/8/8/4K3 b - - hmvc 12; fmvn 40;"), "4k3/8/8/8/8/8/8/4K3 b - - 12 40");
+        assert_eq!(fen("4k3/8/8/8/8/8/8/4K3 b - - 7 33 ;D1 5"), "4k3/8/8/8/8/8/8/4K3 b - - 7 33");
+        assert!(matches!(Epd::parse("4k3/8/8/8/8/8/8/4K3 b -"), Err(EpdError::MissingFields)));
+        assert!(matches!(Epd::parse("4k3/8/8/8/8/8/8/4K3 x - -"), Err(EpdError::Fen(_))));
+    }
+}
@@ -0,0 +1,73 @@
+use super::{Board, Epd, Move, Search, SearchLimits, SearchOptions, TranspositionTable};
+
+/// Test suites: EPD positions with the moves to find or to avoid
+pub struct Suite;
+
+impl Suite {
+    /// Search every position with a `bm` or `am` operation and report a line for each:
+    /// the move played must be one of the best moves and none of the moves to avoid.
+    /// Returns the number of positions solved and tried.
+    pub fn run(
+        positions: &[Epd],
+        tt: &TranspositionTable,
+        limits: SearchLimits,
+        options: &SearchOptions,
+        mut report: impl FnMut(&str)
+    ) -> (usize, usize) {
+        let mut solved = 0;
+        let mut tried = 0;
+
+        for (i, epd) in positions.iter().enumerate() {
+            let name = epd.id().or(epd.comment()).map_or_else(|| format!("#{}", i + 1), String::from);
+            let mut board = epd.board();
+
+            let expected = epd.best_moves(&mut board).and_then(|best| {
+                Ok((best, epd.avoid_moves(&mut board)?))
+            });
+            let (best, avoid) = match expected {
+                Ok(moves) => moves,
+                Err(err) => {
+                    report(&format!("{name}: {err}"));
+                    continue;
+                }
+            };
+
+            if best.is_empty() && avoid.is_empty() {
+                continue;
+            }
+            tried += 1;
+
+            tt.new_search();
+            let result = Search::lazy_smp(&board, tt, limits, options);
+
+            let Some(mv) = result.best_move else {
+                report(&format!("{name}: no move found"));
+                continue;
+            };
+
+            let ok = (best.is_empty() || best.contains(&mv)) && !avoid.contains(&mv);
+            if ok {
+                solved += 1;
+            }
+
+            let mut line = format!("{name}: {} {}", if ok { "solved" } else { "failed" }, board.move_to_san(&mv));
+            if !best.is_empty() {
+                line.push_str(&format!(", bm {}", san_list(&mut board, &best)));
+            }
+            if !avoid.is_empty() {
+                line.push_str(&format!(", am {}", san_list(&mut board, &avoid)));
+            }
+            report(&line);
+        }
+
+        (solved, tried)
+    }
+}
+
+fn san_list(board: &mut Board, moves: &[Move]) -> String {
+    moves
+        .iter()
+        .map(|mv| board.move_to_san(mv))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
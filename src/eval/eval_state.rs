@@ -0,0 +1,65 @@
+use super::{Color, Mailbox, Piece, Score, Square, PIECETYPE_COUNT, PSQT};
+
+/// How much each piece type contributes to the game phase
+pub const PHASE_WEIGHTS: [i32; PIECETYPE_COUNT] = [0, 1, 1, 2, 4, 0];
+
+/// Phase of the starting position (everything but pawns and kings on the board)
+pub const MAX_PHASE: i32 = 24;
+
+/// Evaluation terms that are updated incrementally as pieces are placed and removed
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EvalState {
+    pub psqt: [Score; 2], // Material + piece-square score per color
+    pub phase: i32,       // MAX_PHASE in the opening, 0 with only pawns and kings left
+}
+
+impl EvalState {
+    pub const fn new() -> Self {
+        Self {
+            psqt: [Score::ZERO; 2],
+            phase: 0,
+        }
+    }
+
+    pub fn from_mailbox(mailbox: &Mailbox) -> Self {
+        let mut state = Self::new();
+
+        for square in 0..64 {
+            if let Some(piece) = mailbox.get_piece(square) {
+                state.add_piece(square, piece);
+            }
+        }
+
+        state
+    }
+
+    #[inline(always)]
+    pub fn add_piece(&mut self, square: Square, piece: &Piece) {
+        self.psqt[piece.color.index()] += PSQT[piece.index()][square as usize];
+        self.phase += PHASE_WEIGHTS[piece.piece_type as usize];
+    }
+
+    #[inline(always)]
+    pub fn remove_piece(&mut self, square: Square, piece: &Piece) {
+        self.psqt[piece.color.index()] -= PSQT[piece.index()][square as usize];
+        self.phase -= PHASE_WEIGHTS[piece.piece_type as usize];
+    }
+
+    /// Material + piece-square score from white's point of view
+    #[inline(always)]
+    pub fn psqt(&self) -> Score {
+        self.psqt[Color::White.index()] - self.psqt[Color::Black.index()]
+    }
+
+    /// Game phase clamped to `0..=MAX_PHASE` (early promotions can push it above the max)
+    #[inline(always)]
+    pub const fn phase(&self) -> i32 {
+        if self.phase > MAX_PHASE { MAX_PHASE } else { self.phase }
+    }
+}
+
+/// Blend a midgame / endgame pair into a single score using the game phase
+#[inline(always)]
+pub const fn taper(score: Score, phase: i32) -> i32 {
+    (score.mg * phase + score.eg * (MAX_PHASE - phase)) / MAX_PHASE
+}
@@ -0,0 +1,257 @@
+use super::{
+    Bitboard,
+    Board,
+    Color,
+    MoveGen,
+    PieceType,
+    PawnEntry,
+    Score, Square,
+    masks::{
+        FILES,
+        KING_SHIELD,
+        KING_ZONE,
+        PASSED_SPAN,
+        ADJACENT_FILES,
+        pawn_attacks,
+        relative_rank
+    },
+    weights::*,
+    taper
+};
+
+/// Per-color information shared between the evaluation terms
+pub(super) struct EvalInfo {
+    pub pawns: [Bitboard; 2],
+    pub pawn_attacks: [Bitboard; 2],
+    pub kings: [Square; 2],
+}
+
+impl EvalInfo {
+    pub fn new(board: &Board) -> Self {
+        let pawns = [
+            board.get_bitboard(&PieceType::Pawn, &Color::White),
+            board.get_bitboard(&PieceType::Pawn, &Color::Black),
+        ];
+
+        Self {
+            pawns,
+            pawn_attacks: [
+                pawn_attacks(pawns[0], &Color::White),
+                pawn_attacks(pawns[1], &Color::Black),
+            ],
+            kings: [
+                board.get_bitboard(&PieceType::King, &Color::White).trailing_zeros() as Square,
+                board.get_bitboard(&PieceType::King, &Color::Black).trailing_zeros() as Square,
+            ],
+        }
+    }
+}
+
+/// The handcrafted evaluation
+pub struct Eval;
+
+impl Eval {
+    /// Tapered evaluation from white's point of view
+    pub fn evaluate(board: &Board, pawns: &PawnEntry) -> i32 {
+        let eval_state = board.get_eval_state();
+        let info = EvalInfo::new(board);
+
+        let mut score = eval_state.psqt() + pawns.score;
+
+        score += pawns.king_proximity(&Color::White, info.kings) - pawns.king_proximity(&Color::Black, info.kings);
+        score += Self::mobility(board, &Color::White, &info) - Self::mobility(board, &Color::Black, &info);
+        score += Self::king_safety(board, &Color::White, &info) - Self::king_safety(board, &Color::Black, &info);
+        score += Self::pieces(board, &Color::White, &info) - Self::pieces(board, &Color::Black, &info);
+
+        taper(score, eval_state.phase())
+    }
+
+    /// Squares a piece can reach (empty or capturable)
+    #[inline(always)]
+    fn reach(board: &Board, piece_type: &PieceType, color: &Color, square: Square) -> Bitboard {
+        MoveGen::moves(board, piece_type, color, square) | MoveGen::attacks(board, piece_type, color, square)
+    }
+
+    /// Reachable squares per piece, not counting squares guarded by enemy pawns
+    pub(super) fn mobility(board: &Board, color: &Color, info: &EvalInfo) -> Score {
+        let mobility_area = !info.pawn_attacks[color.inverse().index()];
+        let mut score = Score::ZERO;
+
+        for piece_type in [PieceType::Knight, PieceType::Bishop, PieceType::Rook, PieceType::Queen] {
+            let mut pieces = board.get_bitboard(&piece_type, color);
+            while pieces != 0 {
+                let square = pieces.trailing_zeros() as Square;
+                pieces &= pieces - 1;
+
+                let count = (Self::reach(board, &piece_type, color, square) & mobility_area).count_ones() as i32;
+                score += MOBILITY[piece_type as usize] * (count - MOBILITY_BASELINE[piece_type as usize]);
+            }
+        }
+
+        score
+    }
+
+    /// Enemy pressure on the king zone, pawn shield and open files around the king
+    pub(super) fn king_safety(board: &Board, color: &Color, info: &EvalInfo) -> Score {
+        let us = color.index();
+        let enemy = color.inverse();
+
+        let king = info.kings[us];
+        let zone = KING_ZONE[king as usize];
+        let mut score = Score::ZERO;
+
+        // Attacks on the king zone
+        let mut attackers = 0;
+        let mut units = 0;
+        for piece_type in [PieceType::Knight, PieceType::Bishop, PieceType::Rook, PieceType::Queen] {
+            let mut pieces = board.get_bitboard(&piece_type, &enemy);
+            while pieces != 0 {
+                let square = pieces.trailing_zeros() as Square;
+                pieces &= pieces - 1;
+
+                let hits = (Self::reach(board, &piece_type, &enemy, square) & zone).count_ones() as i32;
+                if hits > 0 {
+                    attackers += 1;
+                    units += hits * KING_ATTACK_WEIGHT[piece_type as usize];
+                }
+            }
+        }
+
+        // A lone attacker is rarely dangerous
+        if attackers >= 2 {
+            let units = units.min(KING_ATTACK_MAX_UNITS);
+            score += KING_ATTACK * (units * units / 4);
+        }
+
+        // Pawn shield
+        score += PAWN_SHIELD * (KING_SHIELD[us][king as usize] & info.pawns[us]).count_ones() as i32;
+
+        // Open files around the king
+        let king_file = king as usize % 8;
+        let mut files = FILES[king_file] | ADJACENT_FILES[king_file];
+        while files != 0 {
+            let file = FILES[files.trailing_zeros() as usize % 8];
+            files &= !file;
+
+            if file & (info.pawns[0] | info.pawns[1]) == 0 {
+                score += KING_OPEN_FILE;
+            } else if file & info.pawns[us] == 0 {
+                score += KING_SEMI_OPEN_FILE;
+            }
+        }
+
+        score
+    }
+
+    /// Bishop pair, rooks on (semi-)open files and the seventh rank, knight outposts
+    pub(super) fn pieces(board: &Board, color: &Color, info: &EvalInfo) -> Score {
+        let us = color.index();
+        let them = color.inverse().index();
+        let mut score = Score::ZERO;
+
+        if board.get_bitboard(&PieceType::Bishop, color).count_ones() >= 2 {
+            score += BISHOP_PAIR;
+        }
+
+        let mut rooks = board.get_bitboard(&PieceType::Rook, color);
+        while rooks != 0 {
+            let square = rooks.trailing_zeros() as Square;
+            rooks &= rooks - 1;
+
+            let file = FILES[square as usize % 8];
+            if file & info.pawns[us] == 0 {
+                score += if file & info.pawns[them] == 0 { ROOK_OPEN_FILE } else { ROOK_SEMI_OPEN_FILE };
+            }
+
+            // Only worth it while it traps the king or eats pawns
+            if relative_rank(color, square) == 6 {
+                let enemy_back_rank = relative_rank(color, info.kings[them]) == 7;
+                let enemy_pawns_on_seventh = info.pawns[them] & (0xffu64 << (square & !7)) != 0;
+                if enemy_back_rank || enemy_pawns_on_seventh {
+                    score += ROOK_ON_SEVENTH;
+                }
+            }
+        }
+
+        let mut knights = board.get_bitboard(&PieceType::Knight, color);
+        while knights != 0 {
+            let square = knights.trailing_zeros() as Square;
+            knights &= knights - 1;
+
+            let rank = relative_rank(color, square);
+            let supported = info.pawn_attacks[us] & (1u64 << square) != 0;
+            let attackable = PASSED_SPAN[us][square as usize]
+                & ADJACENT_FILES[square as usize % 8]
+                & info.pawns[them] != 0;
+
+            if (3..=5).contains(&rank) && supported && !attackable {
+                score += KNIGHT_OUTPOST;
+            }
+        }
+
+        score
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eval::PawnTable;
+
+    const STANDARD_EPD: &str = include_str!("../../tools/perft/standard.epd");
+
+    /// The same position with colors swapped and the board flipped
+    fn mirror(board: &Board) -> Board {
+        let fen = board.to_fen();
+        let fields: Vec<&str> = fen.split_whitespace().collect();
+        let swap_case = |s: &str| {
+            s.chars()
+                .map(|c| if c.is_ascii_uppercase() { c.to_ascii_lowercase() } else { c.to_ascii_uppercase() })
+                .collect::<String>()
+        };
+
+        let placement = fields[0].split('/').rev().map(swap_case).collect::<Vec<_>>().join("/");
+        let color = if fields[1] == "w" { "b" } else { "w" };
+        let en_passant = match fields[3].as_bytes() {
+            [file, b'3'] => format!("{}6", *file as char),
+            [file, b'6'] => format!("{}3", *file as char),
+            _ => "-".to_string(),
+        };
+
+        let fen = format!("{placement} {color} {} {en_passant} {} {}", swap_case(fields[2]), fields[4], fields[5]);
+        Board::from_fen(&fen).unwrap()
+    }
+
+    #[test]
+    fn color_symmetry() {
+        let mut pawn_table = PawnTable::new(1);
+        let mut side_table = PawnTable::new(1);
+        let mut white_eval = |board: &Board| {
+            let pawns = pawn_table.probe(board.get_pawn_hash(), [
+                board.get_bitboard(&PieceType::Pawn, &Color::White),
+                board.get_bitboard(&PieceType::Pawn, &Color::Black),
+            ]);
+            Eval::evaluate(board, &pawns)
+        };
+
+        // The standard perft positions and every position one move from them
+        for line in STANDARD_EPD.lines() {
+            let mut board = Board::from_fen(line.split(';').next().unwrap()).unwrap();
+            let mut positions = vec![board.clone()];
+            for mv in board.legal_moves() {
+                board.make_move(&mv);
+                positions.push(board.clone());
+                board.undo_move();
+            }
+
+            for board in positions {
+                let mirrored = mirror(&board);
+                let fen = board.to_fen();
+
+                // Eval::evaluate is from white's point of view, Board::evaluate from the side to move's
+                assert_eq!(white_eval(&mirrored), -white_eval(&board), "{fen}");
+                assert_eq!(mirrored.evaluate(&mut side_table), board.evaluate(&mut side_table), "{fen}");
+            }
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use super::{Bitboard, Color, Square};
+
+pub const FILE_A: Bitboard = 0x0101010101010101;
+
+pub const FILES: [Bitboard; 8] = {
+    let mut files = [0u64; 8];
+
+    let mut file = 0usize;
+    while file < 8 {
+        files[file] = FILE_A << file;
+        file += 1;
+    }
+
+    files
+};
+
+pub const ADJACENT_FILES: [Bitboard; 8] = {
+    let mut adjacent = [0u64; 8];
+
+    let mut file = 0usize;
+    while file < 8 {
+        if file > 0 { adjacent[file] |= FILES[file - 1]; }
+        if file < 7 { adjacent[file] |= FILES[file + 1]; }
+        file += 1;
+    }
+
+    adjacent
+};
+
+/// Every square strictly in front of a square (from the given color's point of view)
+const FORWARD_RANKS: [[Bitboard; 64]; 2] = {
+    let mut forward = [[0u64; 64]; 2];
+
+    let mut square = 0usize;
+    while square < 64 {
+        let rank = square / 8;
+
+        forward[Color::White.index()][square] = if rank < 7 { !0u64 << ((rank + 1) * 8) } else { 0 };
+        forward[Color::Black.index()][square] = if rank > 0 { !0u64 >> ((8 - rank) * 8) } else { 0 };
+
+        square += 1;
+    }
+
+    forward
+};
+
+/// Squares in front of a pawn on its own file
+pub const FORWARD_FILE: [[Bitboard; 64]; 2] = {
+    let mut forward = [[0u64; 64]; 2];
+
+    let mut square = 0usize;
+    while square < 64 {
+        let file = FILES[square % 8];
+        forward[0][square] = FORWARD_RANKS[0][square] & file;
+        forward[1][square] = FORWARD_RANKS[1][square] & file;
+        square += 1;
+    }
+
+    forward
+};
+
+/// Squares an enemy pawn must be on to stop a pawn from being passed
+pub const PASSED_SPAN: [[Bitboard; 64]; 2] = {
+    let mut span = [[0u64; 64]; 2];
+
+    let mut square = 0usize;
+    while square < 64 {
+        let files = FILES[square % 8] | ADJACENT_FILES[square % 8];
+        span[0][square] = FORWARD_RANKS[0][square] & files;
+        span[1][square] = FORWARD_RANKS[1][square] & files;
+        square += 1;
+    }
+
+    span
+};
+
+/// Squares on adjacent files, level with or behind a pawn, where friendly pawns could support it
+pub const SUPPORT_SPAN: [[Bitboard; 64]; 2] = {
+    let mut span = [[0u64; 64]; 2];
+
+    let mut square = 0usize;
+    while square < 64 {
+        let adjacent = ADJACENT_FILES[square % 8];
+        span[0][square] = !FORWARD_RANKS[0][square] & adjacent;
+        span[1][square] = !FORWARD_RANKS[1][square] & adjacent;
+        square += 1;
+    }
+
+    span
+};
+
+#[inline(always)]
+pub const fn relative_rank(color: &Color, square: Square) -> usize {
+    match color {
+        Color::White => square as usize / 8,
+        Color::Black => 7 - square as usize / 8,
+    }
+}
+
+/// Number of king moves between two squares
+#[inline(always)]
+pub const fn distance(a: Square, b: Square) -> i32 {
+    let rank_dist = (a as i32 / 8 - b as i32 / 8).abs();
+    let file_dist = (a as i32 % 8 - b as i32 % 8).abs();
+    if rank_dist > file_dist { rank_dist } else { file_dist }
+}
+
+/// Squares attacked by a set of pawns
+#[inline(always)]
+pub const fn pawn_attacks(pawns: Bitboard, color: &Color) -> Bitboard {
+    let not_a = !FILES[0];
+    let not_h = !FILES[7];
+    match color {
+        Color::White => ((pawns & not_a) << 7) | ((pawns & not_h) << 9),
+        Color::Black => ((pawns & not_a) >> 9) | ((pawns & not_h) >> 7),
+    }
+}
+
+/// The king's square and every square around it
+pub const KING_ZONE: [Bitboard; 64] = {
+    let mut zones = [0u64; 64];
+
+    let mut square = 0usize;
+    while square < 64 {
+        let mut target = 0usize;
+        while target < 64 {
+            if distance(square as Square, target as Square) <= 1 {
+                zones[square] |= 1u64 << target;
+            }
+            target += 1;
+        }
+        square += 1;
+    }
+
+    zones
+};
+
+/// Up to two ranks in front of the king on its own and adjacent files, where a pawn shield stands
+pub const KING_SHIELD: [[Bitboard; 64]; 2] = {
+    let mut shields = [[0u64; 64]; 2];
+
+    let mut square = 0usize;
+    while square < 64 {
+        let files = FILES[square % 8] | ADJACENT_FILES[square % 8];
+        let rank = square / 8;
+
+        let white_ranks = if rank < 7 { 0xffffu64 << ((rank + 1) * 8) } else { 0 };
+        let black_ranks = match rank {
+            0 => 0,
+            1 => 0xff,
+            _ => 0xffffu64 << ((rank - 2) * 8),
+        };
+
+        shields[Color::White.index()][square] = files & white_ranks;
+        shields[Color::Black.index()][square] = files & black_ranks;
+        square += 1;
+    }
+
+    shields
+};
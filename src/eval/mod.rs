@@ -0,0 +1,27 @@
+//! Tapered handcrafted evaluation and the NNUE network
+
+use crate::board::{Bitboard, Board, Color, Mailbox, Piece, PieceType, Square, PIECETYPE_COUNT};
+use crate::movegen::MoveGen;
+
+mod score;
+mod pst;
+mod masks;
+mod weights;
+mod eval_state;
+mod pawns;
+mod evaluate;
+mod trace;
+mod nnue;
+
+use score::Score;
+use pst::{MATERIAL, PSQT};
+use evaluate::EvalInfo;
+use eval_state::MAX_PHASE;
+
+pub use eval_state::taper;
+pub(crate) use eval_state::EvalState;
+pub use pawns::{PawnEntry, PawnTable};
+pub use evaluate::Eval;
+pub use trace::Trace;
+pub use nnue::{Network, NetworkError};
+pub(crate) use nnue::NnueState;
@@ -0,0 +1,118 @@
+use std::sync::Arc;
+
+use super::{
+    Color,
+    Mailbox,
+    Network,
+    Piece,
+    Square,
+    HIDDEN_SIZE, PIECETYPE_COUNT,
+    QA, QB, SCALE,
+    simd
+};
+
+/// Hidden layer pre-activations for both perspectives
+#[derive(Clone, Copy, PartialEq)]
+#[repr(C, align(32))]
+pub struct Accumulator {
+    pub values: [[i16; HIDDEN_SIZE]; 2], // Indexed by perspective color
+}
+
+/// Feature index of a piece seen from one side
+#[inline(always)]
+fn feature_index(perspective: &Color, piece: &Piece, square: Square) -> usize {
+    let (relative_color, relative_square) = match perspective {
+        Color::White => (piece.color.index(), square),
+        Color::Black => (piece.color.inverse().index(), square ^ 56),
+    };
+
+    (relative_color * PIECETYPE_COUNT + piece.piece_type as usize) * 64 + relative_square as usize
+}
+
+/// Incrementally updated network state owned by a `Board`
+#[derive(Clone)]
+pub struct NnueState {
+    network: Arc<Network>,
+    accumulator: Accumulator,
+    history: Vec<Accumulator>,
+}
+
+impl NnueState {
+    pub fn new(network: Arc<Network>, mailbox: &Mailbox) -> Self {
+        let mut state = Self {
+            network,
+            accumulator: Accumulator { values: [[0; HIDDEN_SIZE]; 2] },
+            history: Vec::new(),
+        };
+        state.refresh(mailbox);
+        state
+    }
+
+    /// Rebuild the accumulator from scratch
+    pub fn refresh(&mut self, mailbox: &Mailbox) {
+        for perspective in 0..2 {
+            self.accumulator.values[perspective].copy_from_slice(&self.network.feature_bias);
+        }
+
+        for square in 0..64 {
+            if let Some(piece) = mailbox.get_piece(square) {
+                self.add_piece(square, piece);
+            }
+        }
+    }
+
+    #[inline(always)]
+    pub fn add_piece(&mut self, square: Square, piece: &Piece) {
+        for perspective in [Color::White, Color::Black] {
+            let weights = self.network.feature(feature_index(&perspective, piece, square));
+            simd::add_assign(&mut self.accumulator.values[perspective.index()], weights);
+        }
+    }
+
+    #[inline(always)]
+    pub fn remove_piece(&mut self, square: Square, piece: &Piece) {
+        for perspective in [Color::White, Color::Black] {
+            let weights = self.network.feature(feature_index(&perspective, piece, square));
+            simd::sub_assign(&mut self.accumulator.values[perspective.index()], weights);
+        }
+    }
+
+    #[inline(always)]
+    pub fn push(&mut self) {
+        self.history.push(self.accumulator);
+    }
+
+    /// Restore the previous accumulator, returns false if there is none to restore
+    #[inline(always)]
+    pub fn pop(&mut self) -> bool {
+        match self.history.pop() {
+            Some(accumulator) => {
+                self.accumulator = accumulator;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Whether the incrementally updated accumulator is the one `refresh` would build
+    #[cfg(test)]
+    pub fn matches_refresh(&self, mailbox: &Mailbox) -> bool {
+        let mut fresh = self.clone();
+        fresh.refresh(mailbox);
+        fresh.accumulator == self.accumulator
+    }
+
+    /// Network output in centipawns from the side to move's point of view,
+    /// saturated to the `i16` range
+    pub fn evaluate(&self, color_to_move: &Color) -> i16 {
+        let us = &self.accumulator.values[color_to_move.index()];
+        let them = &self.accumulator.values[color_to_move.inverse().index()];
+
+        // Each half fits in an i32, their sum scaled by SCALE doesn't
+        let output = simd::crelu_dot(us, &self.network.output_weights[..HIDDEN_SIZE]) as i64
+            + simd::crelu_dot(them, &self.network.output_weights[HIDDEN_SIZE..]) as i64;
+
+        let eval = (output + self.network.output_bias as i64) * SCALE as i64 / (QA * QB) as i64;
+        eval.clamp(i16::MIN as i64, i16::MAX as i64) as i16
+    }
+}
@@ -0,0 +1,16 @@
+use super::{Color, Mailbox, Piece, Square, PIECETYPE_COUNT};
+
+mod network;
+mod simd;
+mod accumulator;
+
+use network::{QA, QB, SCALE};
+
+pub use network::{Network, NetworkError};
+pub use accumulator::NnueState;
+
+/// (color, piece type, square) inputs per perspective
+pub const INPUT_SIZE: usize = 2 * PIECETYPE_COUNT * 64;
+
+/// Neurons per perspective in the hidden layer
+pub const HIDDEN_SIZE: usize = 128;
@@ -0,0 +1,150 @@
+use std::sync::{Arc, OnceLock};
+
+use super::{HIDDEN_SIZE, INPUT_SIZE};
+
+/*
+ * Network file format (all values little-endian)
+ *
+ *   offset  size                   field
+ *   0       8                      magic: b"EPSNNUE\0"
+ *   8       4                      version: u32 (currently 1)
+ *   12      4                      hidden size: u32 (must match HIDDEN_SIZE)
+ *   16      2 * 768 * HIDDEN_SIZE  feature weights: i16, feature-major ([feature][neuron])
+ *   ...     2 * HIDDEN_SIZE        feature biases: i16
+ *   ...     2 * 2 * HIDDEN_SIZE    output weights: i16, side-to-move half first
+ *   ...     4                      output bias: i32
+ *
+ * Feature index for a piece, seen from one side (the "perspective"):
+ *   (relative_color * 6 + piece_type) * 64 + relative_square
+ * where relative_color is 0 for the perspective's own pieces and the square
+ * is mirrored vertically (square ^ 56) for the black perspective.
+ *
+ * Quantization: accumulators are scaled by QA, output weights by QB, and the
+ * final output is `(sum + output_bias) * SCALE / (QA * QB)` centipawns.
+ */
+
+pub const MAGIC: &[u8; 8] = b"EPSNNUE\0";
+pub const VERSION: u32 = 1;
+
+pub const QA: i32 = 255;
+pub const QB: i32 = 64;
+pub const SCALE: i32 = 400;
+
+/// Bootstrap network distilled from the handcrafted piece-square tables (see tools/nnue/psqt_net.py)
+static EMBEDDED: &[u8] = include_bytes!("default.nnue");
+
+/// Why a network file could not be read
+#[derive(Debug)]
+pub enum NetworkError {
+    /// The file could not be read
+    Io(std::io::Error),
+    /// Not a network file
+    BadMagic,
+    /// A version of the format this build can't read
+    UnsupportedVersion(u32),
+    /// A hidden layer size other than `HIDDEN_SIZE`
+    HiddenSizeMismatch(u32),
+    /// A size that doesn't match the header
+    WrongLength {
+        /// Size the header implies
+        expected: usize,
+        /// Size of the file
+        found: usize,
+    },
+}
+
+impl std::fmt::Display for NetworkError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Io(err)                  => write!(f, "{err}"),
+            Self::BadMagic                 => write!(f, "not an Epsilon network file"),
+            Self::UnsupportedVersion(v)    => write!(f, "unsupported network version {v}"),
+            Self::HiddenSizeMismatch(size) => write!(f, "hidden size {size} does not match {HIDDEN_SIZE}"),
+            Self::WrongLength { expected, found } => {
+                write!(f, "expected {expected} bytes, found {found}")
+            }
+        }
+    }
+}
+
+/// Weights of a (768 -> 128)x2 -> 1 network, quantized
+pub struct Network {
+    pub(crate) feature_weights: Vec<i16>, // [INPUT_SIZE][HIDDEN_SIZE]
+    pub(crate) feature_bias: Vec<i16>,    // [HIDDEN_SIZE]
+    pub(crate) output_weights: Vec<i16>,  // [2][HIDDEN_SIZE]
+    pub(crate) output_bias: i32,
+}
+
+impl Network {
+    const HEADER_SIZE: usize = 16;
+    const FILE_SIZE: usize = Self::HEADER_SIZE
+        + 2 * INPUT_SIZE * HIDDEN_SIZE
+        + 2 * HIDDEN_SIZE
+        + 2 * 2 * HIDDEN_SIZE
+        + 4;
+
+    /// Read a network from the contents of a `.nnue` file, see the top of this file
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, NetworkError> {
+        if bytes.len() < Self::HEADER_SIZE || &bytes[0..8] != MAGIC {
+            return Err(NetworkError::BadMagic);
+        }
+
+        let read_u32 = |offset: usize| u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap());
+
+        let version = read_u32(8);
+        if version != VERSION {
+            return Err(NetworkError::UnsupportedVersion(version));
+        }
+
+        let hidden_size = read_u32(12);
+        if hidden_size as usize != HIDDEN_SIZE {
+            return Err(NetworkError::HiddenSizeMismatch(hidden_size));
+        }
+
+        if bytes.len() != Self::FILE_SIZE {
+            return Err(NetworkError::WrongLength { expected: Self::FILE_SIZE, found: bytes.len() });
+        }
+
+        let mut offset = Self::HEADER_SIZE;
+        let mut read_i16s = |count: usize| {
+            let values = bytes[offset..offset + 2 * count]
+                .chunks_exact(2)
+                .map(|b| i16::from_le_bytes([b[0], b[1]]))
+                .collect::<Vec<i16>>();
+            offset += 2 * count;
+            values
+        };
+
+        let feature_weights = read_i16s(INPUT_SIZE * HIDDEN_SIZE);
+        let feature_bias = read_i16s(HIDDEN_SIZE);
+        let output_weights = read_i16s(2 * HIDDEN_SIZE);
+        let output_bias = i32::from_le_bytes(bytes[Self::FILE_SIZE - 4..].try_into().unwrap());
+
+        Ok(Self {
+            feature_weights,
+            feature_bias,
+            output_weights,
+            output_bias,
+        })
+    }
+
+    /// Read a `.nnue` network file
+    pub fn from_file(path: &str) -> Result<Self, NetworkError> {
+        let bytes = std::fs::read(path).map_err(NetworkError::Io)?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// The network compiled into the binary
+    pub fn embedded() -> Arc<Network> {
+        static NETWORK: OnceLock<Arc<Network>> = OnceLock::new();
+        NETWORK
+            .get_or_init(|| Arc::new(Self::from_bytes(EMBEDDED).expect("Embedded network is invalid")))
+            .clone()
+    }
+
+    /// Hidden layer weights of one input
+    #[inline(always)]
+    pub(crate) fn feature(&self, index: usize) -> &[i16] {
+        &self.feature_weights[index * HIDDEN_SIZE..(index + 1) * HIDDEN_SIZE]
+    }
+}
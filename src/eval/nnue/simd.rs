@@ -0,0 +1,66 @@
+/*
+ * Vectorized accumulator kernels
+ *
+ * The AVX2 paths are picked at compile time (build with
+ * `RUSTFLAGS="-C target-cpu=native"` or `-C target-feature=+avx2`),
+ * everything else falls back to scalar loops the compiler can auto-vectorize.
+ */
+
+use super::{HIDDEN_SIZE, QA};
+
+#[inline(always)]
+pub fn add_assign(acc: &mut [i16; HIDDEN_SIZE], weights: &[i16]) {
+    for (a, w) in acc.iter_mut().zip(&weights[..HIDDEN_SIZE]) {
+        *a = a.wrapping_add(*w);
+    }
+}
+
+#[inline(always)]
+pub fn sub_assign(acc: &mut [i16; HIDDEN_SIZE], weights: &[i16]) {
+    for (a, w) in acc.iter_mut().zip(&weights[..HIDDEN_SIZE]) {
+        *a = a.wrapping_sub(*w);
+    }
+}
+
+/// Sum of `clamp(acc, 0, QA) * weight` over the hidden layer
+#[cfg(not(all(target_arch = "x86_64", target_feature = "avx2")))]
+#[inline(always)]
+pub fn crelu_dot(acc: &[i16; HIDDEN_SIZE], weights: &[i16]) -> i32 {
+    acc.iter()
+        .zip(&weights[..HIDDEN_SIZE])
+        .map(|(&a, &w)| (a as i32).clamp(0, QA) * w as i32)
+        .sum()
+}
+
+/// Sum of `clamp(acc, 0, QA) * weight` over the hidden layer
+#[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+#[inline(always)]
+pub fn crelu_dot(acc: &[i16; HIDDEN_SIZE], weights: &[i16]) -> i32 {
+    use std::arch::x86_64::*;
+
+    const LANES: usize = 16;
+    const _: () = assert!(HIDDEN_SIZE % LANES == 0);
+
+    let weights = &weights[..HIDDEN_SIZE];
+
+    // SAFETY: AVX2 is enabled at compile time and every load reads LANES i16s within bounds
+    unsafe {
+        let zero = _mm256_setzero_si256();
+        let qa = _mm256_set1_epi16(QA as i16);
+        let mut sum = _mm256_setzero_si256();
+
+        for i in (0..HIDDEN_SIZE).step_by(LANES) {
+            let a = _mm256_loadu_si256(acc.as_ptr().add(i) as *const __m256i);
+            let w = _mm256_loadu_si256(weights.as_ptr().add(i) as *const __m256i);
+            let clipped = _mm256_min_epi16(_mm256_max_epi16(a, zero), qa);
+            sum = _mm256_add_epi32(sum, _mm256_madd_epi16(clipped, w));
+        }
+
+        let high = _mm256_extracti128_si256(sum, 1);
+        let low = _mm256_castsi256_si128(sum);
+        let sum128 = _mm_add_epi32(high, low);
+        let sum64 = _mm_add_epi32(sum128, _mm_unpackhi_epi64(sum128, sum128));
+        let sum32 = _mm_add_epi32(sum64, _mm_shuffle_epi32(sum64, 1));
+        _mm_cvtsi128_si32(sum32)
+    }
+}
@@ -0,0 +1,154 @@
+use super::{
+    Bitboard,
+    Color,
+    Score, Square,
+    masks::{
+        ADJACENT_FILES,
+        FORWARD_FILE,
+        PASSED_SPAN,
+        SUPPORT_SPAN,
+        distance,
+        pawn_attacks,
+        relative_rank
+    },
+    weights::*
+};
+
+/// Pawn structure terms of one pawn configuration
+#[derive(Clone, Copy)]
+pub struct PawnEntry {
+    pub(crate) key: u64,              // Pawn-only Zobrist key
+    pub(crate) score: Score,          // Pawn structure score from white's point of view
+    pub(crate) passed: [Bitboard; 2], // Passed pawns per color
+}
+
+impl PawnEntry {
+    const EMPTY: Self = Self { key: 0, score: Score::ZERO, passed: [0; 2] };
+
+    /// Evaluate doubled, isolated, backward and passed pawns from scratch
+    pub fn new(key: u64, pawns: [Bitboard; 2]) -> Self {
+        let mut entry = Self { key, ..Self::EMPTY };
+
+        for color in [Color::White, Color::Black] {
+            let (structure, passed_score, passed) = Self::evaluate_color(&color, pawns);
+            entry.passed[color.index()] = passed;
+
+            match color {
+                Color::White => entry.score += structure + passed_score,
+                Color::Black => entry.score -= structure + passed_score,
+            }
+        }
+
+        entry
+    }
+
+    /// Returns the structure score, the passed pawn score and the passed pawns for one side
+    pub(super) fn evaluate_color(color: &Color, pawns: [Bitboard; 2]) -> (Score, Score, Bitboard) {
+        let us = color.index();
+        let them = color.inverse().index();
+
+        let own_pawns = pawns[us];
+        let enemy_pawns = pawns[them];
+        let enemy_attacks = pawn_attacks(enemy_pawns, &color.inverse());
+
+        let mut score = Score::ZERO;
+        let mut passed_score = Score::ZERO;
+        let mut passed = 0;
+
+        let mut remaining = own_pawns;
+        while remaining != 0 {
+            let square = remaining.trailing_zeros() as Square;
+            remaining &= remaining - 1;
+
+            let file = square as usize % 8;
+
+            // Another friendly pawn further up the same file
+            if FORWARD_FILE[us][square as usize] & own_pawns != 0 {
+                score += DOUBLED_PAWN;
+            }
+
+            if ADJACENT_FILES[file] & own_pawns == 0 {
+                score += ISOLATED_PAWN;
+            }
+            // No neighbour can come up to defend it and the square in front is controlled
+            else if SUPPORT_SPAN[us][square as usize] & own_pawns == 0 {
+                let stop = Self::stop_square(color, square);
+                if (1u64 << stop) & enemy_attacks != 0 {
+                    score += BACKWARD_PAWN;
+                }
+            }
+
+            if PASSED_SPAN[us][square as usize] & enemy_pawns == 0
+                && FORWARD_FILE[us][square as usize] & own_pawns == 0
+            {
+                passed_score += PASSED_PAWN[relative_rank(color, square)];
+                passed |= 1u64 << square;
+            }
+        }
+
+        (score, passed_score, passed)
+    }
+
+    /// Passed pawn bonus that depends on where both kings are (not covered by the pawn key)
+    pub fn king_proximity(&self, color: &Color, kings: [Square; 2]) -> Score {
+        let us = color.index();
+        let them = color.inverse().index();
+
+        let mut score = Score::ZERO;
+        let mut passed = self.passed[us];
+        while passed != 0 {
+            let square = passed.trailing_zeros() as Square;
+            passed &= passed - 1;
+
+            let rank = relative_rank(color, square) as i32;
+            if rank < 3 { continue; }
+
+            let stop = Self::stop_square(color, square);
+            let weight = rank - 2;
+
+            score += PASSED_OWN_KING_DISTANCE * (distance(kings[us], stop) * weight);
+            score += PASSED_ENEMY_KING_DISTANCE * (distance(kings[them], stop) * weight);
+        }
+
+        score
+    }
+
+    #[inline(always)]
+    const fn stop_square(color: &Color, square: Square) -> Square {
+        match color {
+            Color::White => square + 8,
+            Color::Black => square - 8,
+        }
+    }
+}
+
+/// Cache of pawn structure evaluations keyed by the pawn-only Zobrist key
+#[derive(Clone)]
+pub struct PawnTable {
+    table: Vec<PawnEntry>,
+    size: usize,
+}
+
+impl PawnTable {
+    /// A table taking about `size_mb` megabytes
+    pub fn new(size_mb: usize) -> Self {
+        let entry_size = std::mem::size_of::<PawnEntry>();
+        let size = (size_mb * 1_000_000) / entry_size;
+        Self {
+            table: vec![PawnEntry::EMPTY; size],
+            size
+        }
+    }
+
+    /// Look up the pawn structure, evaluating and storing it on a miss
+    pub fn probe(&mut self, key: u64, pawns: [Bitboard; 2]) -> PawnEntry {
+        let index = (key as usize) % self.size;
+
+        let entry = &mut self.table[index];
+        if entry.key != key || key == 0 {
+            *entry = PawnEntry::new(key, pawns);
+        }
+
+        *entry
+    }
+}
@@ -0,0 +1,133 @@
+use std::fmt;
+
+use super::{
+    Board,
+    Color,
+    Eval, EvalInfo,
+    PawnEntry,
+    Score,
+    MATERIAL, PSQT, MAX_PHASE,
+    taper
+};
+
+#[derive(Clone, Copy)]
+enum Term {
+    Material,
+    PieceSquare,
+    Pawns,
+    Passed,
+    Mobility,
+    KingSafety,
+    Pieces,
+}
+
+impl Term {
+    const ALL: [Term; 7] = [
+        Term::Material,
+        Term::PieceSquare,
+        Term::Pawns,
+        Term::Passed,
+        Term::Mobility,
+        Term::KingSafety,
+        Term::Pieces,
+    ];
+
+    const fn name(&self) -> &'static str {
+        match self {
+            Term::Material    => "Material",
+            Term::PieceSquare => "PST",
+            Term::Pawns       => "Pawns",
+            Term::Passed      => "Passed",
+            Term::Mobility    => "Mobility",
+            Term::KingSafety  => "King safety",
+            Term::Pieces      => "Pieces",
+        }
+    }
+}
+
+/// Per-term breakdown of the handcrafted evaluation, used by `debug eval`
+pub struct Trace {
+    terms: [[Score; 2]; Term::ALL.len()],
+    phase: i32,
+    eval: i32,
+}
+
+impl Trace {
+    /// Evaluate a position term by term
+    pub fn new(board: &Board) -> Self {
+        let info = EvalInfo::new(board);
+        let mut terms = [[Score::ZERO; 2]; Term::ALL.len()];
+
+        for square in 0..64 {
+            let Some(piece) = board.get_piece(square) else { continue; };
+
+            let material = MATERIAL[piece.piece_type as usize];
+            terms[Term::Material as usize][piece.color.index()] += material;
+            terms[Term::PieceSquare as usize][piece.color.index()] += PSQT[piece.index()][square as usize] - material;
+        }
+
+        let pawn_entry = PawnEntry::new(board.get_pawn_hash(), info.pawns);
+
+        for color in [Color::White, Color::Black] {
+            let us = color.index();
+            let (structure, passed, _) = PawnEntry::evaluate_color(&color, info.pawns);
+
+            terms[Term::Pawns as usize][us]      = structure;
+            terms[Term::Passed as usize][us]     = passed + pawn_entry.king_proximity(&color, info.kings);
+            terms[Term::Mobility as usize][us]   = Eval::mobility(board, &color, &info);
+            terms[Term::KingSafety as usize][us] = Eval::king_safety(board, &color, &info);
+            terms[Term::Pieces as usize][us]     = Eval::pieces(board, &color, &info);
+        }
+
+        let phase = board.get_eval_state().phase();
+        let eval = Eval::evaluate(board, &pawn_entry);
+
+        let trace = Self { terms, phase, eval };
+        debug_assert_eq!(taper(trace.total(), phase), eval);
+        trace
+    }
+
+    fn total(&self) -> Score {
+        self.terms
+            .iter()
+            .fold(Score::ZERO, |acc, term| acc + term[0] - term[1])
+    }
+}
+
+/// Centipawns to pawns
+fn pawns(value: i32) -> f64 {
+    value as f64 / 100.0
+}
+
+fn write_score(f: &mut fmt::Formatter, score: Score) -> fmt::Result {
+    write!(f, " {:>5.2} {:>5.2} ", pawns(score.mg), pawns(score.eg))
+}
+
+impl fmt::Display for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "     Term    |    White    |    Black    |    Total")?;
+        writeln!(f, "             |   MG    EG  |   MG    EG  |   MG    EG")?;
+        writeln!(f, " ------------+-------------+-------------+-------------")?;
+
+        for term in Term::ALL {
+            let [white, black] = self.terms[term as usize];
+
+            write!(f, " {:>11} |", term.name())?;
+            write_score(f, white)?;
+            write!(f, "|")?;
+            write_score(f, black)?;
+            write!(f, "|")?;
+            write_score(f, white - black)?;
+            writeln!(f)?;
+        }
+
+        writeln!(f, " ------------+-------------+-------------+-------------")?;
+        write!(f, "       Total |             |             |")?;
+        write_score(f, self.total())?;
+        writeln!(f)?;
+        writeln!(f)?;
+
+        writeln!(f, "Phase: {} / {MAX_PHASE}", self.phase)?;
+        write!(f, "Final evaluation: {:+.2} (white side)", pawns(self.eval))
+    }
+}
@@ -0,0 +1,56 @@
+use super::Score;
+
+/*
+ * Evaluation weights
+ *
+ * Every handcrafted term outside the piece-square tables reads its weight from here,
+ * so a tuner only has to touch this file.
+ */
+
+// Pawn structure (per pawn)
+pub const DOUBLED_PAWN: Score  = Score::new(-10, -25);
+pub const ISOLATED_PAWN: Score = Score::new(-8, -14);
+pub const BACKWARD_PAWN: Score = Score::new(-7, -12);
+
+// Passed pawns (indexed by relative rank)
+pub const PASSED_PAWN: [Score; 8] = [
+    Score::new(0, 0),
+    Score::new(2, 6),
+    Score::new(4, 10),
+    Score::new(8, 18),
+    Score::new(22, 36),
+    Score::new(45, 70),
+    Score::new(75, 110),
+    Score::new(0, 0),
+];
+
+// Passed pawns: king distance to the square in front of the pawn,
+// scaled by how far the pawn has advanced past its third rank
+pub const PASSED_OWN_KING_DISTANCE: Score   = Score::new(0, -2);
+pub const PASSED_ENEMY_KING_DISTANCE: Score = Score::new(0, 5);
+
+// Mobility: (reachable squares - baseline) * weight, indexed by piece type
+pub const MOBILITY: [Score; 6] = [
+    Score::new(0, 0), // Pawn
+    Score::new(4, 4), // Knight
+    Score::new(5, 5), // Bishop
+    Score::new(2, 4), // Rook
+    Score::new(1, 2), // Queen
+    Score::new(0, 0), // King
+];
+pub const MOBILITY_BASELINE: [i32; 6] = [0, 4, 6, 6, 12, 0];
+
+// King safety
+pub const KING_ATTACK_WEIGHT: [i32; 6] = [0, 2, 2, 3, 5, 0]; // Per attacked king zone square, by piece type
+pub const KING_ATTACK: Score          = Score::new(-1, 0);   // Scaled by units * units / 4
+pub const KING_ATTACK_MAX_UNITS: i32  = 60;
+pub const PAWN_SHIELD: Score          = Score::new(12, 0);   // Per pawn in front of the king
+pub const KING_SEMI_OPEN_FILE: Score  = Score::new(-15, 0);  // No friendly pawn on a file next to the king
+pub const KING_OPEN_FILE: Score       = Score::new(-25, 0);  // No pawn at all on a file next to the king
+
+// Pieces
+pub const BISHOP_PAIR: Score         = Score::new(25, 50);
+pub const ROOK_OPEN_FILE: Score      = Score::new(25, 10);
+pub const ROOK_SEMI_OPEN_FILE: Score = Score::new(12, 6);
+pub const ROOK_ON_SEVENTH: Score     = Score::new(10, 20);
+pub const KNIGHT_OUTPOST: Score      = Score::new(20, 12); // Pawn-supported, out of reach of enemy pawns
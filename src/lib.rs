@@ -0,0 +1,31 @@
+//! Epsilon, a UCI chess engine, as a library.
+//!
+//! The board and move generator, search, evaluation and the notation formats
+//! (FEN, SAN, PGN, EPD) are usable on their own. The `Epsilon` binary is a UCI
+//! front-end on top of them.
+//!
+//! ```
+//! use epsilon::board::Board;
+//! use epsilon::search::{Search, SearchLimits, SearchOptions, TranspositionTable};
+//!
+//! let mut board = Board::from_fen("6k1/5ppp/8/8/8/8/5PPP/R5K1 w - - 0 1").unwrap();
+//! assert_eq!(board.legal_moves().len(), 20);
+//!
+//! let mv = board.parse_san("Ra8#").unwrap();
+//! assert_eq!(board.move_to_san(&mv), "Ra8#");
+//!
+//! let tt = TranspositionTable::new(1);
+//! let result = Search::lazy_smp(&board, &tt, SearchLimits::depth(3), &SearchOptions::default());
+//! assert_eq!(result.best_move, Some(mv));
+//! ```
+
+#![warn(missing_docs)]
+
+pub mod board;
+pub mod movegen;
+pub mod eval;
+pub mod search;
+pub mod book;
+pub mod syzygy;
+pub mod pgn;
+pub mod epd;
@@ -1,9 +1,13 @@
-mod board;
-mod movegen;
-mod debug;
-mod search;
 mod uci;
+mod cli;
+mod debug;
+mod bench;
+
+fn main() -> std::process::ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
 
-fn main() {
-    uci::UciParser::run_loop();
+    cli::Cli::run(&args).unwrap_or_else(|| {
+        uci::UciParser::run_loop();
+        std::process::ExitCode::SUCCESS
+    })
 }
@@ -0,0 +1,333 @@
+use super::{
+    PieceType,
+    Square,
+    Bitboard,
+    Color,
+    MoveType, Move,
+    Board
+};
+
+use super::precomputed::{
+    pawn,
+    knight,
+    king
+};
+
+#[cfg(not(use_pext))]
+use super::precomputed::{bishop, rook};
+
+#[cfg(use_pext)]
+use super::precomputed::pext;
+
+#[cfg(not(use_pext))]
+macro_rules! get_magic_moves {
+    ($piece:ident, $square:expr, $occupied:expr) => {{
+        let magic = &$piece::MAGICS[$square];
+        magic.attacks[
+            ((($occupied & magic.mask).wrapping_mul(magic.magic)) >> magic.shift) as usize
+        ]
+    }};
+}
+
+#[cfg(use_pext)]
+macro_rules! get_magic_moves {
+    (bishop, $square:expr, $occupied:expr) => { pext::BISHOP.attacks($square, $occupied) };
+    (rook, $square:expr, $occupied:expr) => { pext::ROOK.attacks($square, $occupied) };
+}
+
+macro_rules! add_promotions {
+    ($moves:ident, $from_square:ident, $to_square:ident) => {{
+        $moves.push(Move::new($from_square, $to_square, MoveType::Promotion(PieceType::Queen)));
+        $moves.push(Move::new($from_square, $to_square, MoveType::Promotion(PieceType::Rook)));
+        $moves.push(Move::new($from_square, $to_square, MoveType::Promotion(PieceType::Bishop)));
+        $moves.push(Move::new($from_square, $to_square, MoveType::Promotion(PieceType::Knight)));
+    }}
+}
+
+/// Move generation and attack lookups
+pub struct MoveGen;
+
+impl MoveGen {
+    /// Captures of a piece standing on `square`: enemy pieces it attacks, and
+    /// the en passant square for pawns
+    pub fn attacks(board: &Board, piece_type: &PieceType, piece_color: &Color, square: Square) -> Bitboard {
+        let square_idx = square as usize;
+        let occupied = board.get_occupied();
+        let enemy_occupied = occupied.enemy(piece_color);
+        let en_passant_mask = match board.get_en_passant_square() {
+            Some(sq) => 1u64 << sq,
+            None     => 0,
+        };
+
+        match piece_type {
+            PieceType::Pawn => pawn::ATTACKS[piece_color.index()][square_idx] & (enemy_occupied | en_passant_mask),
+
+            PieceType::Knight => knight::ATTACKS_AND_MOVES[square_idx] & enemy_occupied,
+
+            PieceType::Bishop => get_magic_moves!(bishop, square_idx, occupied.all) & enemy_occupied,
+
+            PieceType::Rook => get_magic_moves!(rook, square_idx, occupied.all) & enemy_occupied,
+
+            PieceType::Queen => (get_magic_moves!(rook, square_idx, occupied.all)
+                                | get_magic_moves!(bishop, square_idx, occupied.all)) & enemy_occupied,
+
+            PieceType::King => king::ATTACKS_AND_MOVES[square_idx] & enemy_occupied,
+        }
+    }
+
+    /// Non-capturing moves of a piece standing on `square`, pawn pushes
+    /// included but not castling
+    pub fn moves(board: &Board, piece_type: &PieceType, piece_color: &Color, square: Square) -> Bitboard {
+        let square_idx = square as usize;
+        let occupied = board.get_occupied();
+        let unoccupied = !occupied.all;
+
+        match piece_type {
+            PieceType::Pawn => {
+                let single = pawn::SINGLE_PUSH[piece_color.index()][square_idx] & unoccupied;
+                let double = if single != 0 {
+                    pawn::DOUBLE_PUSH[piece_color.index()][square_idx] & unoccupied
+                } else {
+                    0
+                };
+                single | double
+            },
+
+            PieceType::Knight => knight::ATTACKS_AND_MOVES[square_idx] & unoccupied,
+
+            PieceType::Bishop => get_magic_moves!(bishop, square_idx, occupied.all) & unoccupied,
+
+            PieceType::Rook => get_magic_moves!(rook, square_idx, occupied.all) & unoccupied,
+
+            PieceType::Queen => (get_magic_moves!(rook, square_idx, occupied.all)
+                                | get_magic_moves!(bishop, square_idx, occupied.all)) & unoccupied,
+
+            PieceType::King => king::ATTACKS_AND_MOVES[square_idx] & unoccupied,
+        }
+    }
+
+    /*
+     * Attacks from a square regardless of what stands on the target squares,
+     * with sliders blocked by any occupancy (e.g. the one after a move)
+     */
+
+    /// Squares a pawn of `color` attacks
+    #[inline(always)]
+    pub const fn pawn_attacks(color: &Color, square: Square) -> Bitboard {
+        pawn::ATTACKS[color.index()][square as usize]
+    }
+
+    /// Squares a knight attacks
+    #[inline(always)]
+    pub const fn knight_attacks(square: Square) -> Bitboard {
+        knight::ATTACKS_AND_MOVES[square as usize]
+    }
+
+    /// Squares a bishop attacks, up to and including the first occupied ones
+    #[inline(always)]
+    pub fn bishop_attacks(square: Square, occupied: Bitboard) -> Bitboard {
+        get_magic_moves!(bishop, square as usize, occupied)
+    }
+
+    /// Squares a rook attacks, up to and including the first occupied ones
+    #[inline(always)]
+    pub fn rook_attacks(square: Square, occupied: Bitboard) -> Bitboard {
+        get_magic_moves!(rook, square as usize, occupied)
+    }
+
+    /// Squares a king attacks
+    #[inline(always)]
+    pub const fn king_attacks(square: Square) -> Bitboard {
+        king::ATTACKS_AND_MOVES[square as usize]
+    }
+
+    /// Moves of the side to move, some of which may leave its king in check
+    /// (see `Board::legal_moves`)
+    pub fn get_pseudolegal_moves(board: &Board) -> Vec<Move> {
+        let mut moves = Vec::new();
+        let en_passant_mask = match board.get_en_passant_square() {
+            Some(sq) => 1u64 << sq,
+            None     => 0,
+        };
+
+        for from_square in 0..64 {
+            let Some(piece) = board.get_piece(from_square) else { continue; };
+            if &piece.color != board.color_to_move() { continue; }
+
+            if piece.piece_type == PieceType::King {
+                if board.can_castle_kingside() {
+                    moves.push(Move::new(from_square, from_square + 2, MoveType::CastleKingside));
+                }
+                if board.can_castle_queenside() {
+                    moves.push(Move::new(from_square, from_square - 2, MoveType::CastleQueenside));
+                }
+            }
+
+            // Quiet (non-capture) moves
+            let mut quiet_moves = Self::moves(board, &piece.piece_type, &piece.color, from_square);
+            while quiet_moves != 0 {
+                let to_square = quiet_moves.trailing_zeros() as u8;
+                quiet_moves &= quiet_moves - 1;
+
+                if (piece.piece_type == PieceType::Pawn) && !(8..56).contains(&to_square) {
+                    add_promotions!(moves, from_square, to_square);
+                    continue;
+                }
+
+                moves.push(Move::new(from_square, to_square, MoveType::Quiet));
+            }
+
+            // Capture moves
+            let mut capture_moves = Self::attacks(board, &piece.piece_type, &piece.color, from_square);
+            while capture_moves != 0 {
+                let to_square = capture_moves.trailing_zeros() as u8;
+                capture_moves &= capture_moves - 1;
+
+                if piece.piece_type == PieceType::Pawn {
+                    if !(8..56).contains(&to_square) {
+                        add_promotions!(moves, from_square, to_square);
+                        continue;
+                    }
+
+                    // En passant square is cleared after every move
+                    // so no need to care about color / movement direction
+                    if en_passant_mask & (1u64 << to_square) != 0 {
+                        let from_to_dist = (to_square as i8 - from_square as i8).unsigned_abs();
+                        if from_to_dist == 7 || from_to_dist == 9 {
+                            moves.push(Move::new(from_square, to_square, MoveType::EnPassant));
+                            continue;
+                        }
+                    }
+                }
+                moves.push(Move::new(from_square, to_square, MoveType::Capture));
+            }
+        }
+
+        moves
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::precomputed::{bishop, rook, pext, Magic};
+    use crate::epd::Epd;
+
+    const STANDARD_EPD: &str = include_str!("../../tools/perft/standard.epd");
+
+    const BISHOP_DIRECTIONS: [(i8, i8); 4] = [(1, 1), (1, -1), (-1, 1), (-1, -1)];
+    const ROOK_DIRECTIONS: [(i8, i8); 4] = [(0, 1), (0, -1), (1, 0), (-1, 0)];
+
+    fn naive_attacks(square: usize, occupied: Bitboard, directions: &[(i8, i8)]) -> Bitboard {
+        let mut attacks = 0;
+
+        for &(dx, dy) in directions {
+            let (mut rank, mut file) = (square as i8 / 8 + dy, square as i8 % 8 + dx);
+
+            while (0..8).contains(&rank) && (0..8).contains(&file) {
+                let bit = 1u64 << (rank * 8 + file);
+                attacks |= bit;
+                if occupied & bit != 0 {
+                    break;
+                }
+                rank += dy;
+                file += dx;
+            }
+        }
+
+        attacks
+    }
+
+    /// Perft counting the moves `is_legal` accepts, checking at every node
+    /// that it agrees with making the move
+    fn perft(board: &mut Board, depth: u32) -> u64 {
+        let fen = board.to_fen();
+        let mut nodes = 0;
+
+        for mv in &MoveGen::get_pseudolegal_moves(board) {
+            let legal = board.is_legal(mv);
+            board.make_move(mv);
+            assert_eq!(legal, !board.was_illegal_move(), "{fen}: {}", mv.to_uci());
+            if legal {
+                nodes += if depth == 1 { 1 } else { perft(board, depth - 1) };
+            }
+            board.undo_move();
+        }
+
+        nodes
+    }
+
+    // Every occupancy of the relevant squares, with random pieces elsewhere
+    fn check_against_naive(
+        magics: &[Magic; 64],
+        directions: &[(i8, i8)],
+        lookup: impl Fn(usize, Bitboard) -> Bitboard,
+    ) {
+        let mut seed = 0x9E37_79B9_7F4A_7C15u64;
+        let mut random = || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed
+        };
+
+        for (square, magic) in magics.iter().enumerate() {
+            let mut subset: Bitboard = 0;
+            loop {
+                let occupied = subset | (random() & random() & !magic.mask);
+                assert_eq!(
+                    lookup(square, occupied),
+                    naive_attacks(square, occupied, directions),
+                    "square {square}, occupancy {occupied:#018x}"
+                );
+
+                subset = subset.wrapping_sub(magic.mask) & magic.mask;
+                if subset == 0 {
+                    break;
+                }
+            }
+        }
+    }
+
+    fn magic_lookup(magic: &Magic, occupied: Bitboard) -> Bitboard {
+        magic.attacks[((occupied & magic.mask).wrapping_mul(magic.magic) >> magic.shift) as usize]
+    }
+
+    #[test]
+    fn magic_attacks_match_naive() {
+        check_against_naive(&bishop::MAGICS, &BISHOP_DIRECTIONS, |sq, occ| magic_lookup(&bishop::MAGICS[sq], occ));
+        check_against_naive(&rook::MAGICS, &ROOK_DIRECTIONS, |sq, occ| magic_lookup(&rook::MAGICS[sq], occ));
+    }
+
+    #[test]
+    fn pext_attacks_match_naive() {
+        check_against_naive(&bishop::MAGICS, &BISHOP_DIRECTIONS, |sq, occ| pext::BISHOP.attacks(sq, occ));
+        check_against_naive(&rook::MAGICS, &ROOK_DIRECTIONS, |sq, occ| pext::ROOK.attacks(sq, occ));
+    }
+
+    // Whichever backend the build selected
+    #[test]
+    fn movegen_attacks_match_naive() {
+        check_against_naive(&bishop::MAGICS, &BISHOP_DIRECTIONS, |sq, occ| MoveGen::bishop_attacks(sq as Square, occ));
+        check_against_naive(&rook::MAGICS, &ROOK_DIRECTIONS, |sq, occ| MoveGen::rook_attacks(sq as Square, occ));
+    }
+
+    #[test]
+    fn perft_standard_positions() {
+        // Kiwipete, an en passant capture exposing the king, castling with
+        // attacked squares, and promotions with and without captures
+        for (position, depth) in [
+            ("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w", 3),
+            ("8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w", 4),
+            ("r3k2r/8/8/8/8/8/8/R3K2R b", 3),
+            ("8/8/8/8/8/8/6k1/4K2R w", 4),
+            ("n1n5/PPPk4/8/8/8/8/4Kppp/5N1N b", 3),
+        ] {
+            let line = STANDARD_EPD.lines().find(|line| line.starts_with(position)).unwrap();
+            let epd = Epd::parse(line).unwrap();
+            let (_, expected) = epd.perft_counts().into_iter().find(|&(d, _)| d == depth).unwrap();
+
+            assert_eq!(perft(&mut epd.board(), depth), expected, "{line}");
+        }
+    }
+}
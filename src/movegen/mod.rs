@@ -1,3 +1,5 @@
+//! Pseudolegal move generation with magic bitboards
+
 use crate::board::{
     Color,
     PieceType,
@@ -9,6 +11,6 @@ use crate::board::{
 
 mod utils;
 mod precomputed;
-mod movegen;
+mod generator;
 
-pub use movegen::MoveGen;
+pub use generator::MoveGen;
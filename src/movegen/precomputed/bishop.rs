@@ -1,133 +1,133 @@
 use super::{Bitboard, Magic};
 
 pub const MAGICS: [Magic; 64] = [
-    Magic { mask: 0x8040201008040200, magic: 0x1940080104208392, shift: 58, attacks: &ATTACK_TABLE_0 },
-    Magic { mask: 0x0080402010080500, magic: 0x2802028206020000, shift: 59, attacks: &ATTACK_TABLE_1 },
-    Magic { mask: 0x0000804020110A00, magic: 0x2010028981040410, shift: 59, attacks: &ATTACK_TABLE_2 },
-    Magic { mask: 0x0000008041221400, magic: 0x9604040380001002, shift: 59, attacks: &ATTACK_TABLE_3 },
-    Magic { mask: 0x0000000182442800, magic: 0x1204042001048440, shift: 59, attacks: &ATTACK_TABLE_4 },
-    Magic { mask: 0x0000010204885000, magic: 0x9A011002100100C0, shift: 59, attacks: &ATTACK_TABLE_5 },
-    Magic { mask: 0x000102040810A000, magic: 0x880A080C1A080038, shift: 59, attacks: &ATTACK_TABLE_6 },
-    Magic { mask: 0x0102040810204000, magic: 0x4011340205100801, shift: 58, attacks: &ATTACK_TABLE_7 },
-    Magic { mask: 0x4020100804020002, magic: 0x002340C2381D0100, shift: 59, attacks: &ATTACK_TABLE_8 },
-    Magic { mask: 0x8040201008050005, magic: 0x0010040404340020, shift: 59, attacks: &ATTACK_TABLE_9 },
-    Magic { mask: 0x00804020110A000A, magic: 0x8022040102020803, shift: 59, attacks: &ATTACK_TABLE_10 },
-    Magic { mask: 0x0000804122140014, magic: 0x00404C1400800040, shift: 59, attacks: &ATTACK_TABLE_11 },
-    Magic { mask: 0x0000018244280028, magic: 0x60126C0420020484, shift: 59, attacks: &ATTACK_TABLE_12 },
-    Magic { mask: 0x0001020488500050, magic: 0x0004960602210088, shift: 59, attacks: &ATTACK_TABLE_13 },
-    Magic { mask: 0x0102040810A000A0, magic: 0x20002A0301084004, shift: 59, attacks: &ATTACK_TABLE_14 },
-    Magic { mask: 0x0204081020400040, magic: 0x00041301080A2200, shift: 59, attacks: &ATTACK_TABLE_15 },
-    Magic { mask: 0x2010080402000204, magic: 0x004064300C211400, shift: 59, attacks: &ATTACK_TABLE_16 },
-    Magic { mask: 0x4020100805000508, magic: 0x20020A6021840280, shift: 58, attacks: &ATTACK_TABLE_17 },
-    Magic { mask: 0x804020110A000A11, magic: 0x0001021001020032, shift: 57, attacks: &ATTACK_TABLE_18 },
-    Magic { mask: 0x0080412214001422, magic: 0x0002100042000800, shift: 56, attacks: &ATTACK_TABLE_19 },
-    Magic { mask: 0x0001824428002844, magic: 0x4002802C00A00004, shift: 57, attacks: &ATTACK_TABLE_20 },
-    Magic { mask: 0x0102048850005088, magic: 0x000100402C004000, shift: 56, attacks: &ATTACK_TABLE_21 },
-    Magic { mask: 0x02040810A000A010, magic: 0x8002021405140280, shift: 59, attacks: &ATTACK_TABLE_22 },
-    Magic { mask: 0x0408102040004020, magic: 0x880500009400A200, shift: 59, attacks: &ATTACK_TABLE_23 },
-    Magic { mask: 0x1008040200020408, magic: 0x0020212D3D045C00, shift: 59, attacks: &ATTACK_TABLE_24 },
-    Magic { mask: 0x2010080500050810, magic: 0x80100208100C8600, shift: 58, attacks: &ATTACK_TABLE_25 },
-    Magic { mask: 0x4020110A000A1120, magic: 0x0000500208008010, shift: 57, attacks: &ATTACK_TABLE_26 },
-    Magic { mask: 0x8041221400142241, magic: 0x6400840006000A00, shift: 52, attacks: &ATTACK_TABLE_27 },
-    Magic { mask: 0x0182442800284482, magic: 0x0000840020802000, shift: 55, attacks: &ATTACK_TABLE_28 },
-    Magic { mask: 0x0204885000508804, magic: 0xC408008064300080, shift: 56, attacks: &ATTACK_TABLE_29 },
-    Magic { mask: 0x040810A000A01008, magic: 0xA000A60444021200, shift: 59, attacks: &ATTACK_TABLE_30 },
-    Magic { mask: 0x0810204000402010, magic: 0x001B024011045800, shift: 59, attacks: &ATTACK_TABLE_31 },
-    Magic { mask: 0x0804020002040810, magic: 0x6050022020101460, shift: 59, attacks: &ATTACK_TABLE_32 },
-    Magic { mask: 0x1008050005081020, magic: 0x10080F0107101400, shift: 58, attacks: &ATTACK_TABLE_33 },
-    Magic { mask: 0x20110A000A112040, magic: 0xBC01004100A80050, shift: 56, attacks: &ATTACK_TABLE_34 },
-    Magic { mask: 0x4122140014224180, magic: 0x1000032401280400, shift: 54, attacks: &ATTACK_TABLE_35 },
-    Magic { mask: 0x8244280028448201, magic: 0xB010000822080200, shift: 53, attacks: &ATTACK_TABLE_36 },
-    Magic { mask: 0x0488500050880402, magic: 0x8021100080040A00, shift: 57, attacks: &ATTACK_TABLE_37 },
-    Magic { mask: 0x0810A000A0100804, magic: 0x80420656012C0080, shift: 59, attacks: &ATTACK_TABLE_38 },
-    Magic { mask: 0x1020400040201008, magic: 0x20480180302B0100, shift: 59, attacks: &ATTACK_TABLE_39 },
-    Magic { mask: 0x0402000204081020, magic: 0x1028013008087080, shift: 59, attacks: &ATTACK_TABLE_40 },
-    Magic { mask: 0x0805000508102040, magic: 0x0801080110080120, shift: 58, attacks: &ATTACK_TABLE_41 },
-    Magic { mask: 0x110A000A11204080, magic: 0x1000340028010400, shift: 57, attacks: &ATTACK_TABLE_42 },
-    Magic { mask: 0x2214001422418000, magic: 0x8150002019004800, shift: 57, attacks: &ATTACK_TABLE_43 },
-    Magic { mask: 0x4428002844820100, magic: 0x100008010400AF40, shift: 57, attacks: &ATTACK_TABLE_44 },
-    Magic { mask: 0x8850005088040201, magic: 0x00901510010084A0, shift: 57, attacks: &ATTACK_TABLE_45 },
-    Magic { mask: 0x10A000A010080402, magic: 0x00140C0482048400, shift: 59, attacks: &ATTACK_TABLE_46 },
-    Magic { mask: 0x2040004020100804, magic: 0x800104088207AC80, shift: 59, attacks: &ATTACK_TABLE_47 },
-    Magic { mask: 0x0200020408102040, magic: 0x0002082444040380, shift: 59, attacks: &ATTACK_TABLE_48 },
-    Magic { mask: 0x0500050810204080, magic: 0x1400804410040900, shift: 59, attacks: &ATTACK_TABLE_49 },
-    Magic { mask: 0x0A000A1120408000, magic: 0x0040010400920000, shift: 59, attacks: &ATTACK_TABLE_50 },
-    Magic { mask: 0x1400142241800000, magic: 0x2A006E0042020840, shift: 59, attacks: &ATTACK_TABLE_51 },
-    Magic { mask: 0x2800284482010000, magic: 0x01C4011006020000, shift: 59, attacks: &ATTACK_TABLE_52 },
-    Magic { mask: 0x5000508804020100, magic: 0x8000400284010060, shift: 59, attacks: &ATTACK_TABLE_53 },
-    Magic { mask: 0xA000A01008040201, magic: 0x0208A00162020140, shift: 59, attacks: &ATTACK_TABLE_54 },
-    Magic { mask: 0x4000402010080402, magic: 0x0010076800848030, shift: 59, attacks: &ATTACK_TABLE_55 },
-    Magic { mask: 0x0002040810204080, magic: 0x08010080D0025050, shift: 58, attacks: &ATTACK_TABLE_56 },
-    Magic { mask: 0x0005081020408000, magic: 0x0AD000C40A180220, shift: 59, attacks: &ATTACK_TABLE_57 },
-    Magic { mask: 0x000A112040800000, magic: 0x2008260084008880, shift: 59, attacks: &ATTACK_TABLE_58 },
-    Magic { mask: 0x0014224180000000, magic: 0x331878100020880A, shift: 59, attacks: &ATTACK_TABLE_59 },
-    Magic { mask: 0x0028448201000000, magic: 0xE404400060046400, shift: 59, attacks: &ATTACK_TABLE_60 },
-    Magic { mask: 0x0050880402010000, magic: 0x14CB002510020600, shift: 59, attacks: &ATTACK_TABLE_61 },
-    Magic { mask: 0x00A0100804020100, magic: 0xC2001C8C98020C02, shift: 59, attacks: &ATTACK_TABLE_62 },
-    Magic { mask: 0x0040201008040201, magic: 0x0020022491011200, shift: 58, attacks: &ATTACK_TABLE_63 },
+    Magic { mask: 0x0040201008040200, magic: 0x0002200204004080, shift: 58, attacks: &ATTACK_TABLE_0 },
+    Magic { mask: 0x0000402010080400, magic: 0x8020015202204400, shift: 59, attacks: &ATTACK_TABLE_1 },
+    Magic { mask: 0x0000004020100A00, magic: 0x0104110202000002, shift: 59, attacks: &ATTACK_TABLE_2 },
+    Magic { mask: 0x0000000040221400, magic: 0x0558048904801041, shift: 59, attacks: &ATTACK_TABLE_3 },
+    Magic { mask: 0x0000000002442800, magic: 0x00045040008C0600, shift: 59, attacks: &ATTACK_TABLE_4 },
+    Magic { mask: 0x0000000204085000, magic: 0x0004902420340404, shift: 59, attacks: &ATTACK_TABLE_5 },
+    Magic { mask: 0x0000020408102000, magic: 0x100A022202400004, shift: 59, attacks: &ATTACK_TABLE_6 },
+    Magic { mask: 0x0002040810204000, magic: 0x0800802410020800, shift: 58, attacks: &ATTACK_TABLE_7 },
+    Magic { mask: 0x0020100804020000, magic: 0x0410042004210200, shift: 59, attacks: &ATTACK_TABLE_8 },
+    Magic { mask: 0x0040201008040000, magic: 0x1082080204041020, shift: 59, attacks: &ATTACK_TABLE_9 },
+    Magic { mask: 0x00004020100A0000, magic: 0x0008080200460100, shift: 59, attacks: &ATTACK_TABLE_10 },
+    Magic { mask: 0x0000004022140000, magic: 0x140004050A081000, shift: 59, attacks: &ATTACK_TABLE_11 },
+    Magic { mask: 0x0000000244280000, magic: 0x020010882018148C, shift: 59, attacks: &ATTACK_TABLE_12 },
+    Magic { mask: 0x0000020408500000, magic: 0x8184008820484400, shift: 59, attacks: &ATTACK_TABLE_13 },
+    Magic { mask: 0x0002040810200000, magic: 0x0400038808025200, shift: 59, attacks: &ATTACK_TABLE_14 },
+    Magic { mask: 0x0004081020400000, magic: 0x1010C08201012000, shift: 59, attacks: &ATTACK_TABLE_15 },
+    Magic { mask: 0x0010080402000200, magic: 0x80400038092811A0, shift: 59, attacks: &ATTACK_TABLE_16 },
+    Magic { mask: 0x0020100804000400, magic: 0x003004C40C00A420, shift: 59, attacks: &ATTACK_TABLE_17 },
+    Magic { mask: 0x004020100A000A00, magic: 0x8002001000284300, shift: 57, attacks: &ATTACK_TABLE_18 },
+    Magic { mask: 0x0000402214001400, magic: 0x0058001082094011, shift: 57, attacks: &ATTACK_TABLE_19 },
+    Magic { mask: 0x0000024428002800, magic: 0xE0060004010C0000, shift: 57, attacks: &ATTACK_TABLE_20 },
+    Magic { mask: 0x0002040850005000, magic: 0x4214800108014000, shift: 57, attacks: &ATTACK_TABLE_21 },
+    Magic { mask: 0x0004081020002000, magic: 0x020C0A0080880800, shift: 59, attacks: &ATTACK_TABLE_22 },
+    Magic { mask: 0x0008102040004000, magic: 0x448080020A010102, shift: 59, attacks: &ATTACK_TABLE_23 },
+    Magic { mask: 0x0008040200020400, magic: 0x1045042120209C00, shift: 59, attacks: &ATTACK_TABLE_24 },
+    Magic { mask: 0x0010080400040800, magic: 0x1881044820080202, shift: 59, attacks: &ATTACK_TABLE_25 },
+    Magic { mask: 0x0020100A000A1000, magic: 0x0000208024080880, shift: 57, attacks: &ATTACK_TABLE_26 },
+    Magic { mask: 0x0040221400142200, magic: 0x0044004044010002, shift: 55, attacks: &ATTACK_TABLE_27 },
+    Magic { mask: 0x0002442800284400, magic: 0x01208400C0802000, shift: 55, attacks: &ATTACK_TABLE_28 },
+    Magic { mask: 0x0004085000500800, magic: 0x0008002042008402, shift: 57, attacks: &ATTACK_TABLE_29 },
+    Magic { mask: 0x0008102000201000, magic: 0x020260401C050810, shift: 59, attacks: &ATTACK_TABLE_30 },
+    Magic { mask: 0x0010204000402000, magic: 0x1104082004820104, shift: 59, attacks: &ATTACK_TABLE_31 },
+    Magic { mask: 0x0004020002040800, magic: 0x0344602000060401, shift: 59, attacks: &ATTACK_TABLE_32 },
+    Magic { mask: 0x0008040004081000, magic: 0x40084104C0080800, shift: 59, attacks: &ATTACK_TABLE_33 },
+    Magic { mask: 0x00100A000A102000, magic: 0x8082048200100420, shift: 57, attacks: &ATTACK_TABLE_34 },
+    Magic { mask: 0x0022140014224000, magic: 0x0404A40108140100, shift: 55, attacks: &ATTACK_TABLE_35 },
+    Magic { mask: 0x0044280028440200, magic: 0x0040024100411100, shift: 55, attacks: &ATTACK_TABLE_36 },
+    Magic { mask: 0x0008500050080400, magic: 0x00040082020C0902, shift: 57, attacks: &ATTACK_TABLE_37 },
+    Magic { mask: 0x0010200020100800, magic: 0x0401020410020100, shift: 59, attacks: &ATTACK_TABLE_38 },
+    Magic { mask: 0x0020400040201000, magic: 0x0019040288010040, shift: 59, attacks: &ATTACK_TABLE_39 },
+    Magic { mask: 0x0002000204081000, magic: 0x5008080804100900, shift: 59, attacks: &ATTACK_TABLE_40 },
+    Magic { mask: 0x0004000408102000, magic: 0x8022415010010880, shift: 59, attacks: &ATTACK_TABLE_41 },
+    Magic { mask: 0x000A000A10204000, magic: 0x0801008040440400, shift: 57, attacks: &ATTACK_TABLE_42 },
+    Magic { mask: 0x0014001422400000, magic: 0x6009202018000100, shift: 57, attacks: &ATTACK_TABLE_43 },
+    Magic { mask: 0x0028002844020000, magic: 0x0001010122000401, shift: 57, attacks: &ATTACK_TABLE_44 },
+    Magic { mask: 0x0050005008040200, magic: 0x8020201400200A44, shift: 57, attacks: &ATTACK_TABLE_45 },
+    Magic { mask: 0x0020002010080400, magic: 0x0442502200A00A00, shift: 59, attacks: &ATTACK_TABLE_46 },
+    Magic { mask: 0x0040004020100800, magic: 0x650A040C01300280, shift: 59, attacks: &ATTACK_TABLE_47 },
+    Magic { mask: 0x0000020408102000, magic: 0x0041043002080040, shift: 59, attacks: &ATTACK_TABLE_48 },
+    Magic { mask: 0x0000040810204000, magic: 0x8002021084040900, shift: 59, attacks: &ATTACK_TABLE_49 },
+    Magic { mask: 0x00000A1020400000, magic: 0x0C04002884500028, shift: 59, attacks: &ATTACK_TABLE_50 },
+    Magic { mask: 0x0000142240000000, magic: 0x8080002222881068, shift: 59, attacks: &ATTACK_TABLE_51 },
+    Magic { mask: 0x0000284402000000, magic: 0x0040003102022002, shift: 59, attacks: &ATTACK_TABLE_52 },
+    Magic { mask: 0x0000500804020000, magic: 0x0211C20848012000, shift: 59, attacks: &ATTACK_TABLE_53 },
+    Magic { mask: 0x0000201008040200, magic: 0x0008100148050800, shift: 59, attacks: &ATTACK_TABLE_54 },
+    Magic { mask: 0x0000402010080400, magic: 0x0020412200810101, shift: 59, attacks: &ATTACK_TABLE_55 },
+    Magic { mask: 0x0002040810204000, magic: 0x0002008409380200, shift: 58, attacks: &ATTACK_TABLE_56 },
+    Magic { mask: 0x0004081020400000, magic: 0x8000010082100225, shift: 59, attacks: &ATTACK_TABLE_57 },
+    Magic { mask: 0x000A102040000000, magic: 0x24004008242A0800, shift: 59, attacks: &ATTACK_TABLE_58 },
+    Magic { mask: 0x0014224000000000, magic: 0x400050920A841420, shift: 59, attacks: &ATTACK_TABLE_59 },
+    Magic { mask: 0x0028440200000000, magic: 0x22A20002201A0490, shift: 59, attacks: &ATTACK_TABLE_60 },
+    Magic { mask: 0x0050080402000000, magic: 0x0088521002100104, shift: 59, attacks: &ATTACK_TABLE_61 },
+    Magic { mask: 0x0020100804020000, magic: 0x0020049022120402, shift: 59, attacks: &ATTACK_TABLE_62 },
+    Magic { mask: 0x0040201008040200, magic: 0x0530200200604300, shift: 58, attacks: &ATTACK_TABLE_63 },
 ];
 
-const ATTACK_TABLE_0: [Bitboard; 64] = [0x8040201008040200,0x0000000000040200,0x0000000008040200,0x0000000000040200,0x0000201008040200,0x0000000000040200,0x0000000008040200,0x0000000000040200,0x0000000000040200,0x0000001008040200,0x0000000000040200,0x0000000008040200,0x0000000000040200,0x0000001008040200,0x0000000000040200,0x0000000008040200,0x0000001008040200,0x0000000000040200,0x0000000008040200,0x0000000000040200,0x0000001008040200,0x0000000000040200,0x0000000008040200,0x0000000000040200,0x0000000000040200,0x0000000000000200,0x0000000000040200,0x0000000000000200,0x0000000000040200,0x0000000000000200,0x0000000000040200,0x0000000000000200,0x0000000000000200,0x0000000000000200,0x0000000000000200,0x0000000000000200,0x0000000000000200,0x0000000000000200,0x0000000000000200,0x0000000000000200,0x0000000000000200,0x0000000000000200,0x0000000000000200,0x0000000000000200,0x0000000000000200,0x0000000000000200,0x0000000000000200,0x0000000000000200,0x0000000000000200,0x0000000000000200,0x0000000000000200,0x0000000000000200,0x0000000000000200,0x0000000000000200,0x0000000000000200,0x0000000000000200,0x0000000000000200,0x0040201008040200,0x0000000000000200,0x0000000008040200,0x0000000000000200,0x0000201008040200,0x0000000000000200,0x0000000008040200,];
-const ATTACK_TABLE_1: [Bitboard; 32] = [0x0080402010080500,0x0000000000000500,0x0000000000080500,0x0000000000000500,0x0000000010080500,0x0000000000000500,0x0000000000080500,0x0000000000000500,0x0000002010080500,0x0000000000000500,0x0000000000080500,0x0000000000000500,0x0000000010080500,0x0000000000000500,0x0000000000080500,0x0000000000000500,0x0000402010080500,0x0000000000000500,0x0000000000080500,0x0000000000000500,0x0000000010080500,0x0000000000000500,0x0000000000080500,0x0000000000000500,0x0000002010080500,0x0000000000000500,0x0000000000080500,0x0000000000000500,0x0000000010080500,0x0000000000000500,0x0000000000080500,0x0000000000000500,];
-const ATTACK_TABLE_2: [Bitboard; 32] = [0x0000804020110A00,0x0000000000000A00,0x0000000000000A00,0x0000000000010A00,0x0000804020100A00,0x0000000000110A00,0x0000000020110A00,0x0000000000000A00,0x0000004020110A00,0x0000000000100A00,0x0000000020100A00,0x0000000000110A00,0x0000004020100A00,0x0000000000110A00,0x0000000020110A00,0x0000000000100A00,0x0000000000010A00,0x0000000000100A00,0x0000000020100A00,0x0000000000110A00,0x0000000000000A00,0x0000000000010A00,0x0000000000010A00,0x0000000000100A00,0x0000000000010A00,0x0000000000000A00,0x0000000000000A00,0x0000000000010A00,0x0000000000000A00,0x0000000000010A00,0x0000000000010A00,0x0000000000000A00,];
-const ATTACK_TABLE_3: [Bitboard; 32] = [0x0000008041221400,0x0000008040221400,0x0000008040201400,0x0000008040201400,0x0000000001021400,0x0000000000021400,0x0000000000001400,0x0000000000001400,0x0000000001021400,0x0000000000021400,0x0000000000001400,0x0000000000001400,0x0000000001221400,0x0000000000221400,0x0000000000201400,0x0000000000201400,0x0000000001221400,0x0000000000221400,0x0000000000201400,0x0000000000201400,0x0000000001021400,0x0000000000021400,0x0000000000001400,0x0000000000001400,0x0000000001021400,0x0000000000021400,0x0000000000001400,0x0000000000001400,0x0000000041221400,0x0000000040221400,0x0000000040201400,0x0000000040201400,];
-const ATTACK_TABLE_4: [Bitboard; 32] = [0x0000000182442800,0x0000000102442800,0x0000000080442800,0x0000000000442800,0x0000000080402800,0x0000000000402800,0x0000000080402800,0x0000000000402800,0x0000000082442800,0x0000000002442800,0x0000000080442800,0x0000000000442800,0x0000000080402800,0x0000000000402800,0x0000000080402800,0x0000000000402800,0x0000000102042800,0x0000000102042800,0x0000000000042800,0x0000000000042800,0x0000000000002800,0x0000000000002800,0x0000000000002800,0x0000000000002800,0x0000000002042800,0x0000000002042800,0x0000000000042800,0x0000000000042800,0x0000000000002800,0x0000000000002800,0x0000000000002800,0x0000000000002800,];
-const ATTACK_TABLE_5: [Bitboard; 32] = [0x0000010204885000,0x0000000004885000,0x0000000000805000,0x0000000000805000,0x0000000204885000,0x0000000004885000,0x0000000000805000,0x0000000000805000,0x0000010204085000,0x0000000004085000,0x0000000000005000,0x0000000000005000,0x0000000204085000,0x0000000004085000,0x0000000000005000,0x0000000000005000,0x0000000000885000,0x0000000000885000,0x0000000000805000,0x0000000000805000,0x0000000000885000,0x0000000000885000,0x0000000000805000,0x0000000000805000,0x0000000000085000,0x0000000000085000,0x0000000000005000,0x0000000000005000,0x0000000000085000,0x0000000000085000,0x0000000000005000,0x0000000000005000,];
-const ATTACK_TABLE_6: [Bitboard; 32] = [0x000102040810A000,0x000000000000A000,0x000002040810A000,0x000000000000A000,0x000000000000A000,0x000000000000A000,0x000000000000A000,0x000000000000A000,0x000000000000A000,0x000000000010A000,0x000000000000A000,0x000000000010A000,0x000000000810A000,0x000000040810A000,0x000000000810A000,0x000000040810A000,0x000000000010A000,0x000000000000A000,0x000000000010A000,0x000000000000A000,0x000000000000A000,0x000000000000A000,0x000000000000A000,0x000000000000A000,0x000000000000A000,0x000000000810A000,0x000000000000A000,0x000000000810A000,0x000000000010A000,0x000000000010A000,0x000000000010A000,0x000000000010A000,];
-const ATTACK_TABLE_7: [Bitboard; 64] = [0x0102040810204000,0x0000000000004000,0x0000000000204000,0x0000000000004000,0x0002040810204000,0x0000000000004000,0x0000000000204000,0x0000000000004000,0x0000000010204000,0x0000000000004000,0x0000000810204000,0x0000000000004000,0x0000000010204000,0x0000000000004000,0x0000000810204000,0x0000000000004000,0x0000040810204000,0x0000000000004000,0x0000000010204000,0x0000000000004000,0x0000040810204000,0x0000000000004000,0x0000000010204000,0x0000000000004000,0x0000000010204000,0x0000000000004000,0x0000000810204000,0x0000000000004000,0x0000000010204000,0x0000000000004000,0x0000000810204000,0x0000000000004000,0x0000000000204000,0x0000000000004000,0x0000000010204000,0x0000000000004000,0x0000000000204000,0x0000000000004000,0x0000000010204000,0x0000000000004000,0x0000000000204000,0x0000000000004000,0x0000000000204000,0x0000000000004000,0x0000000000204000,0x0000000000004000,0x0000000000204000,0x0000000000004000,0x0000000000204000,0x0000000000004000,0x0000000000204000,0x0000000000004000,0x0000000000204000,0x0000000000004000,0x0000000000204000,0x0000000000004000,0x0000000000204000,0x0000000000004000,0x0000000000204000,0x0000000000004000,0x0000000000204000,0x0000000000004000,0x0000000000204000,0x0000000000004000,];
-const ATTACK_TABLE_8: [Bitboard; 32] = [0x4020100804020002,0x0000000004020002,0x0000000000020002,0x0000000000020002,0x0020100804020002,0x0000000004020002,0x0000000000020002,0x0000000000020002,0x0000000000020002,0x0000000000020002,0x0000000000020002,0x0000000000020002,0x0000000000020002,0x0000000000020002,0x0000000000020002,0x0000000000020002,0x0000000000020002,0x0000000000020002,0x0000000804020002,0x0000000004020002,0x0000000000020002,0x0000000000020002,0x0000000804020002,0x0000000004020002,0x0000000804020002,0x0000000004020002,0x0000100804020002,0x0000000004020002,0x0000000804020002,0x0000000004020002,0x0000100804020002,0x0000000004020002,];
-const ATTACK_TABLE_9: [Bitboard; 32] = [0x8040201008050005,0x0040201008050005,0x0000000000050005,0x0000000000050005,0x0000000008050005,0x0000000008050005,0x0000000000050005,0x0000000000050005,0x0000001008050005,0x0000001008050005,0x0000000000050005,0x0000000000050005,0x0000000008050005,0x0000000008050005,0x0000000000050005,0x0000000000050005,0x0000201008050005,0x0000201008050005,0x0000000000050005,0x0000000000050005,0x0000000008050005,0x0000000008050005,0x0000000000050005,0x0000000000050005,0x0000001008050005,0x0000001008050005,0x0000000000050005,0x0000000000050005,0x0000000008050005,0x0000000008050005,0x0000000000050005,0x0000000000050005,];
-const ATTACK_TABLE_10: [Bitboard; 32] = [0x00804020110A000A,0x00804020100A000A,0x00000000110A000A,0x00000000100A000A,0x00000000010A000A,0x00000000000A000A,0x00000000010A000A,0x00000000000A000A,0x00000020110A000A,0x00000020100A000A,0x00000000110A000A,0x00000000100A000A,0x00000000010A000A,0x00000000000A000A,0x00000000010A000A,0x00000000000A000A,0x00004020110A000A,0x00004020100A000A,0x00000000110A000A,0x00000000100A000A,0x00000000010A000A,0x00000000000A000A,0x00000000010A000A,0x00000000000A000A,0x00000020110A000A,0x00000020100A000A,0x00000000110A000A,0x00000000100A000A,0x00000000010A000A,0x00000000000A000A,0x00000000010A000A,0x00000000000A000A,];
-const ATTACK_TABLE_11: [Bitboard; 32] = [0x0000804122140014,0x0000000002140014,0x0000000000140014,0x0000000000140014,0x0000004122140014,0x0000804022140014,0x0000804020140014,0x0000000000140014,0x0000000102140014,0x0000004022140014,0x0000004020140014,0x0000804020140014,0x0000000102140014,0x0000000002140014,0x0000000000140014,0x0000004020140014,0x0000000122140014,0x0000000002140014,0x0000000000140014,0x0000000000140014,0x0000000122140014,0x0000000022140014,0x0000000020140014,0x0000000000140014,0x0000000102140014,0x0000000022140014,0x0000000020140014,0x0000000020140014,0x0000000102140014,0x0000000002140014,0x0000000000140014,0x0000000020140014,];
-const ATTACK_TABLE_12: [Bitboard; 32] = [0x0000018244280028,0x0000010244280028,0x0000008044280028,0x0000000044280028,0x0000000000280028,0x0000000000280028,0x0000000000280028,0x0000000000280028,0x0000008244280028,0x0000000244280028,0x0000008044280028,0x0000000044280028,0x0000008040280028,0x0000000040280028,0x0000008040280028,0x0000000040280028,0x0000010204280028,0x0000010204280028,0x0000000004280028,0x0000000004280028,0x0000008040280028,0x0000000040280028,0x0000008040280028,0x0000000040280028,0x0000000204280028,0x0000000204280028,0x0000000004280028,0x0000000004280028,0x0000000000280028,0x0000000000280028,0x0000000000280028,0x0000000000280028,];
-const ATTACK_TABLE_13: [Bitboard; 32] = [0x0001020488500050,0x0000000488500050,0x0000000000500050,0x0000000000500050,0x0000000000500050,0x0000000000500050,0x0000000088500050,0x0000000088500050,0x0000020488500050,0x0000000488500050,0x0000000000500050,0x0000000000500050,0x0000000080500050,0x0000000080500050,0x0000000088500050,0x0000000088500050,0x0001020408500050,0x0000000408500050,0x0000000080500050,0x0000000080500050,0x0000000080500050,0x0000000080500050,0x0000000008500050,0x0000000008500050,0x0000020408500050,0x0000000408500050,0x0000000080500050,0x0000000080500050,0x0000000000500050,0x0000000000500050,0x0000000008500050,0x0000000008500050,];
-const ATTACK_TABLE_14: [Bitboard; 32] = [0x0102040810A000A0,0x0000000810A000A0,0x0000000000A000A0,0x0000000000A000A0,0x0000040810A000A0,0x0000000810A000A0,0x0000000010A000A0,0x0000000010A000A0,0x0000000000A000A0,0x0000000000A000A0,0x0000000010A000A0,0x0000000010A000A0,0x0000000000A000A0,0x0000000000A000A0,0x0000000000A000A0,0x0000000000A000A0,0x0002040810A000A0,0x0000000810A000A0,0x0000000000A000A0,0x0000000000A000A0,0x0000040810A000A0,0x0000000810A000A0,0x0000000010A000A0,0x0000000010A000A0,0x0000000000A000A0,0x0000000000A000A0,0x0000000010A000A0,0x0000000010A000A0,0x0000000000A000A0,0x0000000000A000A0,0x0000000000A000A0,0x0000000000A000A0,];
-const ATTACK_TABLE_15: [Bitboard; 32] = [0x0204081020400040,0x0000001020400040,0x0000000000400040,0x0000000000400040,0x0000000020400040,0x0000000020400040,0x0000000000400040,0x0000000000400040,0x0000000000400040,0x0000000000400040,0x0000081020400040,0x0000001020400040,0x0000000000400040,0x0000000000400040,0x0000000020400040,0x0000000020400040,0x0000001020400040,0x0004081020400040,0x0000000000400040,0x0000000000400040,0x0000000020400040,0x0000000020400040,0x0000000000400040,0x0000000000400040,0x0000000000400040,0x0000000000400040,0x0000001020400040,0x0000081020400040,0x0000000000400040,0x0000000000400040,0x0000000020400040,0x0000000020400040,];
-const ATTACK_TABLE_16: [Bitboard; 32] = [0x2010080402000204,0x0000080402000204,0x0000000002000200,0x0000000002000200,0x0000000002000200,0x0000000002000200,0x0000000402000204,0x0000000402000204,0x0010080402000204,0x0000080402000204,0x0000000002000200,0x0000000002000200,0x0000000002000204,0x0000000002000204,0x0000000402000204,0x0000000402000204,0x2010080402000200,0x0000080402000200,0x0000000002000204,0x0000000002000204,0x0000000002000204,0x0000000002000204,0x0000000402000200,0x0000000402000200,0x0010080402000200,0x0000080402000200,0x0000000002000204,0x0000000002000204,0x0000000002000200,0x0000000002000200,0x0000000402000200,0x0000000402000200,];
-const ATTACK_TABLE_17: [Bitboard; 64] = [0x4020100805000508,0x0000000805000500,0x4020100805000500,0x0000000805000508,0x0000000005000508,0x0000000805000500,0x0000000005000500,0x0000000005000508,0x0000000005000508,0x0000000005000500,0x0000000005000500,0x0000000005000508,0x0000000005000508,0x0000000005000500,0x0000000005000500,0x0000000005000508,0x0000100805000508,0x0000000005000500,0x0000100805000500,0x0000000805000508,0x0020100805000508,0x0000000805000500,0x0020100805000500,0x0000000805000508,0x4020100805000508,0x0000000805000500,0x4020100805000500,0x0000000805000508,0x0000000005000508,0x0000000805000500,0x0000000005000500,0x0000000005000508,0x0000000005000508,0x0000000005000500,0x0000000005000500,0x0000000005000508,0x0000100805000508,0x0000000005000500,0x0000100805000500,0x0000000805000508,0x0000100805000508,0x0000000805000500,0x0000100805000500,0x0000000805000508,0x0020100805000508,0x0000000805000500,0x0020100805000500,0x0000000805000508,0x0000000005000508,0x0000000805000500,0x0000000005000500,0x0000000005000508,0x0000000005000508,0x0000000005000500,0x0000000005000500,0x0000000005000508,0x0000000005000508,0x0000000005000500,0x0000000005000500,0x0000000005000508,0x0000100805000508,0x0000000005000500,0x0000100805000500,0x0000000805000508,];
-const ATTACK_TABLE_18: [Bitboard; 128] = [0x804020110A000A11,0x804020110A000A10,0x000000000A000A01,0x000000000A000A00,0x804020110A000A01,0x804020110A000A00,0x004020110A000A11,0x004020110A000A10,0x000000110A000A11,0x000000110A000A10,0x004020110A000A01,0x004020110A000A00,0x000000110A000A01,0x000000110A000A00,0x000000110A000A11,0x000000110A000A10,0x804020100A000A11,0x804020100A000A10,0x000000110A000A01,0x000000110A000A00,0x804020100A000A01,0x804020100A000A00,0x004020100A000A11,0x004020100A000A10,0x000000100A000A11,0x000000100A000A10,0x004020100A000A01,0x004020100A000A00,0x000000100A000A01,0x000000100A000A00,0x000000100A000A11,0x000000100A000A10,0x000020110A000A11,0x000020110A000A10,0x000000100A000A01,0x000000100A000A00,0x000020110A000A01,0x000020110A000A00,0x000020110A000A11,0x000020110A000A10,0x000000110A000A11,0x000000110A000A10,0x000020110A000A01,0x000020110A000A00,0x000000110A000A01,0x000000110A000A00,0x000000110A000A11,0x000000110A000A10,0x000020100A000A11,0x000020100A000A10,0x000000110A000A01,0x000000110A000A00,0x000020100A000A01,0x000020100A000A00,0x000020100A000A11,0x000020100A000A10,0x000000100A000A11,0x000000100A000A10,0x000020100A000A01,0x000020100A000A00,0x000000100A000A01,0x000000100A000A00,0x000000100A000A11,0x000000100A000A10,0x000000010A000A11,0x000000010A000A10,0x000000100A000A01,0x000000100A000A00,0x000000010A000A01,0x000000010A000A00,0x000000010A000A11,0x000000010A000A10,0x000000010A000A11,0x000000010A000A10,0x000000010A000A01,0x000000010A000A00,0x000000010A000A01,0x000000010A000A00,0x000000010A000A11,0x000000010A000A10,0x000000000A000A11,0x000000000A000A10,0x000000010A000A01,0x000000010A000A00,0x000000000A000A01,0x000000000A000A00,0x000000000A000A11,0x000000000A000A10,0x000000000A000A11,0x000000000A000A10,0x000000000A000A01,0x000000000A000A00,0x000000000A000A01,0x000000000A000A00,0x000000000A000A11,0x000000000A000A10,0x000000010A000A11,0x000000010A000A10,0x000000000A000A01,0x000000000A000A00,0x000000010A000A01,0x000000010A000A00,0x000000010A000A11,0x000000010A000A10,0x000000010A000A11,0x000000010A000A10,0x000000010A000A01,0x000000010A000A00,0x000000010A000A01,0x000000010A000A00,0x000000010A000A11,0x000000010A000A10,0x000000000A000A11,0x000000000A000A10,0x000000010A000A01,0x000000010A000A00,0x000000000A000A01,0x000000000A000A00,0x000000000A000A11,0x000000000A000A10,0x000000000A000A11,0x000000000A000A10,0x000000000A000A01,0x000000000A000A00,0x000000000A000A01,0x000000000A000A00,0x000000000A000A11,0x000000000A000A10,];
-const ATTACK_TABLE_19: [Bitboard; 256] = [0x0080412214001422,0x0080402014001422,0x0000412214001422,0x0000402014001422,0x0000010214001422,0x0000000014001422,0x0000010214001422,0x0000000014001422,0x0080412214001420,0x0080402014001420,0x0000412214001420,0x0000402014001420,0x0000010214001420,0x0000000014001420,0x0000010214001420,0x0000000014001420,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0080412214001402,0x0080402014001402,0x0000412214001402,0x0000402014001402,0x0000010214001402,0x0000000014001402,0x0000010214001402,0x0000000014001402,0x0080412214001400,0x0080402014001400,0x0000412214001400,0x0000402014001400,0x0000010214001400,0x0000000014001400,0x0000010214001400,0x0000000014001400,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0000012214001422,0x0000002014001422,0x0000012214001422,0x0000002014001422,0x0000010214001422,0x0000000014001422,0x0000010214001422,0x0000000014001422,0x0000012214001420,0x0000002014001420,0x0000012214001420,0x0000002014001420,0x0000010214001420,0x0000000014001420,0x0000010214001420,0x0000000014001420,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0000012214001402,0x0000002014001402,0x0000012214001402,0x0000002014001402,0x0000010214001402,0x0000000014001402,0x0000010214001402,0x0000000014001402,0x0000012214001400,0x0000002014001400,0x0000012214001400,0x0000002014001400,0x0000010214001400,0x0000000014001400,0x0000010214001400,0x0000000014001400,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0080402214001422,0x0080402014001422,0x0000402214001422,0x0000402014001422,0x0000000214001422,0x0000000014001422,0x0000000214001422,0x0000000014001422,0x0080402214001420,0x0080402014001420,0x0000402214001420,0x0000402014001420,0x0000000214001420,0x0000000014001420,0x0000000214001420,0x0000000014001420,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0080402214001402,0x0080402014001402,0x0000402214001402,0x0000402014001402,0x0000000214001402,0x0000000014001402,0x0000000214001402,0x0000000014001402,0x0080402214001400,0x0080402014001400,0x0000402214001400,0x0000402014001400,0x0000000214001400,0x0000000014001400,0x0000000214001400,0x0000000014001400,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0000002214001422,0x0000002014001422,0x0000002214001422,0x0000002014001422,0x0000000214001422,0x0000000014001422,0x0000000214001422,0x0000000014001422,0x0000002214001420,0x0000002014001420,0x0000002214001420,0x0000002014001420,0x0000000214001420,0x0000000014001420,0x0000000214001420,0x0000000014001420,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0000002214001402,0x0000002014001402,0x0000002214001402,0x0000002014001402,0x0000000214001402,0x0000000014001402,0x0000000214001402,0x0000000014001402,0x0000002214001400,0x0000002014001400,0x0000002214001400,0x0000002014001400,0x0000000214001400,0x0000000014001400,0x0000000214001400,0x0000000014001400,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,];
-const ATTACK_TABLE_20: [Bitboard; 128] = [0x0001824428002844,0x0000804428002844,0x0000804028002800,0x0000804028002800,0x0000000028002844,0x0000000028002844,0x0001020428002800,0x0000000428002800,0x0000020428002804,0x0000000428002804,0x0001824428002840,0x0000804428002840,0x0000004028002804,0x0000004028002804,0x0000000028002840,0x0000000028002840,0x0000000028002844,0x0000000028002844,0x0000020428002800,0x0000000428002800,0x0001024428002844,0x0000004428002844,0x0000004028002800,0x0000004028002800,0x0000000028002804,0x0000000028002804,0x0000000028002840,0x0000000028002840,0x0000020428002804,0x0000000428002804,0x0001024428002840,0x0000004428002840,0x0000824428002844,0x0000804428002844,0x0000000028002800,0x0000000028002800,0x0000000028002844,0x0000000028002844,0x0000020428002800,0x0000000428002800,0x0001824428002804,0x0000804428002804,0x0000824428002840,0x0000804428002840,0x0000000028002804,0x0000000028002804,0x0000000028002840,0x0000000028002840,0x0000804028002844,0x0000804028002844,0x0001824428002800,0x0000804428002800,0x0000024428002844,0x0000004428002844,0x0000000028002800,0x0000000028002800,0x0000000028002804,0x0000000028002804,0x0000804028002840,0x0000804028002840,0x0001024428002804,0x0000004428002804,0x0000024428002840,0x0000004428002840,0x0001020428002844,0x0000000428002844,0x0000000028002800,0x0000000028002800,0x0000004028002844,0x0000004028002844,0x0001024428002800,0x0000004428002800,0x0000824428002804,0x0000804428002804,0x0001020428002840,0x0000000428002840,0x0000000028002804,0x0000000028002804,0x0000004028002840,0x0000004028002840,0x0000804028002844,0x0000804028002844,0x0000824428002800,0x0000804428002800,0x0001020428002844,0x0000000428002844,0x0000000028002800,0x0000000028002800,0x0000804028002804,0x0000804028002804,0x0000804028002840,0x0000804028002840,0x0000024428002804,0x0000004428002804,0x0001020428002840,0x0000000428002840,0x0000020428002844,0x0000000428002844,0x0000804028002800,0x0000804028002800,0x0000004028002844,0x0000004028002844,0x0000024428002800,0x0000004428002800,0x0001020428002804,0x0000000428002804,0x0000020428002840,0x0000000428002840,0x0000004028002804,0x0000004028002804,0x0000004028002840,0x0000004028002840,0x0000000028002844,0x0000000028002844,0x0001020428002800,0x0000000428002800,0x0000020428002844,0x0000000428002844,0x0000004028002800,0x0000004028002800,0x0000804028002804,0x0000804028002804,0x0000000028002840,0x0000000028002840,0x0001020428002804,0x0000000428002804,0x0000020428002840,0x0000000428002840,];
-const ATTACK_TABLE_21: [Bitboard; 256] = [0x0102048850005088,0x0000048850005088,0x0000008050005088,0x0000008050005088,0x0000008050005088,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0102040850005088,0x0000040850005088,0x0000000050005088,0x0000000050005088,0x0000000050005088,0x0102048850005080,0x0000048850005080,0x0000008050005080,0x0000008050005080,0x0000008050005080,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0102040850005080,0x0000040850005080,0x0000000050005080,0x0000000050005080,0x0000000050005080,0x0000008850005008,0x0000008850005008,0x0000008050005008,0x0000008050005008,0x0000008050005008,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0000000850005008,0x0000000850005008,0x0000000050005008,0x0000000050005008,0x0000000050005008,0x0000008850005000,0x0000008850005000,0x0000008050005000,0x0000008050005000,0x0000008050005000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0000000850005000,0x0000000850005000,0x0000000050005000,0x0000000050005000,0x0000000050005000,0x0102048850005008,0x0000048850005008,0x0000008050005008,0x0000008050005008,0x0000008050005008,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0102040850005008,0x0000040850005008,0x0000000050005008,0x0000000050005008,0x0000000050005008,0x0102048850005000,0x0000048850005000,0x0000008050005000,0x0000008050005000,0x0000008050005000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0102040850005000,0x0000040850005000,0x0000000050005000,0x0000000050005000,0x0000000050005000,0x0000008850005088,0x0000008850005088,0x0000008050005088,0x0000008050005088,0x0000008050005088,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0000000850005088,0x0000000850005088,0x0000000050005088,0x0000000050005088,0x0000000050005088,0x0000008850005080,0x0000008850005080,0x0000008050005080,0x0000008050005080,0x0000008050005080,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0000000850005080,0x0000000850005080,0x0000000050005080,0x0000000050005080,0x0000000050005080,0x0002048850005088,0x0000048850005088,0x0000008050005088,0x0000008050005088,0x0000008050005088,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0002040850005088,0x0000040850005088,0x0000000050005088,0x0000000050005088,0x0000000050005088,0x0002048850005080,0x0000048850005080,0x0000008050005080,0x0000008050005080,0x0000008050005080,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0002040850005080,0x0000040850005080,0x0000000050005080,0x0000000050005080,0x0000000050005080,0x0000008850005008,0x0000008850005008,0x0000008050005008,0x0000008050005008,0x0000008050005008,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0000000850005008,0x0000000850005008,0x0000000050005008,0x0000000050005008,0x0000000050005008,0x0000008850005000,0x0000008850005000,0x0000008050005000,0x0000008050005000,0x0000008050005000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0000000850005000,0x0000000850005000,0x0000000050005000,0x0000000050005000,0x0000000050005000,0x0002048850005008,0x0000048850005008,0x0000008050005008,0x0000008050005008,0x0000008050005008,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0002040850005008,0x0000040850005008,0x0000000050005008,0x0000000050005008,0x0000000050005008,0x0002048850005000,0x0000048850005000,0x0000008050005000,0x0000008050005000,0x0000008050005000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0002040850005000,0x0000040850005000,0x0000000050005000,0x0000000050005000,0x0000000050005000,0x0000008850005088,0x0000008850005088,0x0000008050005088,0x0000008050005088,0x0000008050005088,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0000000850005088,0x0000000850005088,0x0000000050005088,0x0000000050005088,0x0000000050005088,0x0000008850005080,0x0000008850005080,0x0000008050005080,0x0000008050005080,0x0000008050005080,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0000000850005080,0x0000000850005080,0x0000000050005080,0x0000000050005080,0x0000000050005080,];
-const ATTACK_TABLE_22: [Bitboard; 32] = [0x02040810A000A010,0x00040810A000A010,0x00000000A000A000,0x00000000A000A000,0x00000000A000A010,0x00000000A000A010,0x00000010A000A000,0x00000010A000A000,0x02040810A000A000,0x00040810A000A000,0x00000010A000A010,0x00000010A000A010,0x00000000A000A000,0x00000000A000A000,0x00000000A000A010,0x00000000A000A010,0x00000000A000A010,0x00000000A000A010,0x00000010A000A000,0x00000010A000A000,0x00000810A000A010,0x00000810A000A010,0x00000000A000A000,0x00000000A000A000,0x00000000A000A000,0x00000000A000A000,0x00000000A000A010,0x00000000A000A010,0x00000810A000A000,0x00000810A000A000,0x00000010A000A010,0x00000010A000A010,];
-const ATTACK_TABLE_23: [Bitboard; 32] = [0x0408102040004020,0x0000102040004020,0x0008102040004020,0x0000102040004020,0x0000000040004020,0x0000000040004020,0x0000000040004020,0x0000000040004020,0x0408102040004000,0x0000102040004000,0x0008102040004000,0x0000102040004000,0x0000000040004000,0x0000000040004000,0x0000000040004000,0x0000000040004000,0x0000002040004020,0x0000002040004020,0x0000002040004020,0x0000002040004020,0x0000000040004020,0x0000000040004020,0x0000000040004020,0x0000000040004020,0x0000002040004000,0x0000002040004000,0x0000002040004000,0x0000002040004000,0x0000000040004000,0x0000000040004000,0x0000000040004000,0x0000000040004000,];
-const ATTACK_TABLE_24: [Bitboard; 32] = [0x1008040200020408,0x0000000200020400,0x0000040200020408,0x0000000200020000,0x0008040200020000,0x0000000200020000,0x0000040200020000,0x0000000200020000,0x1008040200020000,0x0000000200020000,0x0000040200020000,0x0000000200020408,0x0008040200020400,0x0000000200020408,0x0000040200020400,0x0000000200020408,0x1008040200020400,0x0000000200020408,0x0000040200020400,0x0000000200020000,0x0008040200020000,0x0000000200020000,0x0000040200020000,0x0000000200020000,0x1008040200020000,0x0000000200020000,0x0000040200020000,0x0000000200020400,0x0008040200020408,0x0000000200020400,0x0000040200020408,0x0000000200020400,];
-const ATTACK_TABLE_25: [Bitboard; 64] = [0x2010080500050810,0x0000000500050810,0x2010080500050000,0x0000000500050000,0x2010080500050810,0x0000000500050810,0x2010080500050000,0x0000000500050000,0x0000000500050800,0x0000000500050800,0x0000000500050000,0x0000000500050000,0x0000000500050800,0x0000000500050800,0x0000000500050000,0x0000000500050000,0x0000000500050810,0x0000080500050800,0x0000000500050000,0x0000080500050000,0x0000000500050810,0x0000080500050800,0x0000000500050000,0x0000080500050000,0x0010080500050810,0x0000080500050810,0x0010080500050000,0x0000080500050000,0x0010080500050810,0x0000080500050810,0x0010080500050000,0x0000080500050000,0x2010080500050800,0x0000000500050800,0x2010080500050000,0x0000000500050000,0x2010080500050800,0x0000000500050800,0x2010080500050000,0x0000000500050000,0x0000000500050810,0x0000000500050810,0x0000000500050000,0x0000000500050000,0x0000000500050810,0x0000000500050810,0x0000000500050000,0x0000000500050000,0x0000000500050800,0x0000080500050810,0x0000000500050000,0x0000080500050000,0x0000000500050800,0x0000080500050810,0x0000000500050000,0x0000080500050000,0x0010080500050800,0x0000080500050800,0x0010080500050000,0x0000080500050000,0x0010080500050800,0x0000080500050800,0x0010080500050000,0x0000080500050000,];
-const ATTACK_TABLE_26: [Bitboard; 128] = [0x4020110A000A1120,0x0020110A000A1120,0x4020110A000A1100,0x0020110A000A1100,0x0000110A000A1120,0x0000110A000A1120,0x0000110A000A1100,0x0000110A000A1100,0x4020100A000A1120,0x0020100A000A1120,0x4020100A000A1100,0x0020100A000A1100,0x0000100A000A1120,0x0000100A000A1120,0x0000100A000A1100,0x0000100A000A1100,0x4020110A000A0000,0x0020110A000A0000,0x4020110A000A0000,0x0020110A000A0000,0x0000110A000A0000,0x0000110A000A0000,0x0000110A000A0000,0x0000110A000A0000,0x4020100A000A0000,0x0020100A000A0000,0x4020100A000A0000,0x0020100A000A0000,0x0000100A000A0000,0x0000100A000A0000,0x0000100A000A0000,0x0000100A000A0000,0x0000010A000A1120,0x0000010A000A1120,0x0000010A000A1100,0x0000010A000A1100,0x0000010A000A1120,0x0000010A000A1120,0x0000010A000A1100,0x0000010A000A1100,0x0000000A000A1120,0x0000000A000A1120,0x0000000A000A1100,0x0000000A000A1100,0x0000000A000A1120,0x0000000A000A1120,0x0000000A000A1100,0x0000000A000A1100,0x0000010A000A0000,0x0000010A000A0000,0x0000010A000A0000,0x0000010A000A0000,0x0000010A000A0000,0x0000010A000A0000,0x0000010A000A0000,0x0000010A000A0000,0x0000000A000A0000,0x0000000A000A0000,0x0000000A000A0000,0x0000000A000A0000,0x0000000A000A0000,0x0000000A000A0000,0x0000000A000A0000,0x0000000A000A0000,0x4020110A000A0100,0x0020110A000A0100,0x4020110A000A0100,0x0020110A000A0100,0x0000110A000A0100,0x0000110A000A0100,0x0000110A000A0100,0x0000110A000A0100,0x4020100A000A0100,0x0020100A000A0100,0x4020100A000A0100,0x0020100A000A0100,0x0000100A000A0100,0x0000100A000A0100,0x0000100A000A0100,0x0000100A000A0100,0x4020110A000A1020,0x0020110A000A1020,0x4020110A000A1000,0x0020110A000A1000,0x0000110A000A1020,0x0000110A000A1020,0x0000110A000A1000,0x0000110A000A1000,0x4020100A000A1020,0x0020100A000A1020,0x4020100A000A1000,0x0020100A000A1000,0x0000100A000A1020,0x0000100A000A1020,0x0000100A000A1000,0x0000100A000A1000,0x0000010A000A0100,0x0000010A000A0100,0x0000010A000A0100,0x0000010A000A0100,0x0000010A000A0100,0x0000010A000A0100,0x0000010A000A0100,0x0000010A000A0100,0x0000000A000A0100,0x0000000A000A0100,0x0000000A000A0100,0x0000000A000A0100,0x0000000A000A0100,0x0000000A000A0100,0x0000000A000A0100,0x0000000A000A0100,0x0000010A000A1020,0x0000010A000A1020,0x0000010A000A1000,0x0000010A000A1000,0x0000010A000A1020,0x0000010A000A1020,0x0000010A000A1000,0x0000010A000A1000,0x0000000A000A1020,0x0000000A000A1020,0x0000000A000A1000,0x0000000A000A1000,0x0000000A000A1020,0x0000000A000A1020,0x0000000A000A1000,0x0000000A000A1000,];
-const ATTACK_TABLE_27: [Bitboard; 4096] = [0x8041221400142241,0x8040221400142241,0x8041221400142241,0x8040221400142241,0x0001221400142040,0x0000221400142040,0x0001221400142040,0x0000221400142040,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x0001221400142200,0x0000221400142200,0x0001221400142200,0x0000221400142200,0x8041221400142240,0x8040221400142240,0x8041221400142240,0x8040221400142240,0x0001221400142241,0x0000221400142241,0x0001221400142241,0x0000221400142241,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x0000001400142241,0x0000001400142241,0x0000001400142241,0x0000001400142241,0x0001221400142240,0x0000221400142240,0x0001221400142240,0x0000221400142240,0x0040201400140000,0x0040201400140000,0x0040201400140000,0x0040201400140000,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x0000001400142240,0x0000001400142240,0x0000001400142240,0x0000001400142240,0x0000001400142241,0x0000001400142241,0x0000001400142241,0x0000001400142241,0x0040201400140000,0x0040201400140000,0x0040201400140000,0x0040201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0001021400140201,0x0000021400140201,0x0001021400140201,0x0000021400140201,0x0000001400142240,0x0000001400142240,0x0000001400142240,0x0000001400142240,0x0041221400142000,0x0040221400142000,0x0041221400142000,0x0040221400142000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0001021400140200,0x0000021400140200,0x0001021400140200,0x0000021400140200,0x0001021400140201,0x0000021400140201,0x0001021400140201,0x0000021400140201,0x0041221400142000,0x0040221400142000,0x0041221400142000,0x0040221400142000,0x0001221400142000,0x0000221400142000,0x0001221400142000,0x0000221400142000,0x0040201400142241,0x0040201400142241,0x0040201400142241,0x0040201400142241,0x0001021400140200,0x0000021400140200,0x0001021400140200,0x0000021400140200,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0001221400142000,0x0000221400142000,0x0001221400142000,0x0000221400142000,0x0040201400142240,0x0040201400142240,0x0040201400142240,0x0040201400142240,0x0000201400142241,0x0000201400142241,0x0000201400142241,0x0000201400142241,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000201400142240,0x0000201400142240,0x0000201400142240,0x0000201400142240,0x0000001400142201,0x0000001400142201,0x0000001400142201,0x0000001400142201,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142200,0x0000001400142200,0x0000001400142200,0x0000001400142200,0x0000001400142201,0x0000001400142201,0x0000001400142201,0x0000001400142201,0x8041221400142241,0x8040221400142241,0x8041221400142241,0x8040221400142241,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x0000001400142200,0x0000001400142200,0x0000001400142200,0x0000001400142200,0x8041221400142240,0x8040221400142240,0x8041221400142240,0x8040221400142240,0x0001221400142241,0x0000221400142241,0x0001221400142241,0x0000221400142241,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x0040201400142040,0x0040201400142040,0x0040201400142040,0x0040201400142040,0x0001221400142240,0x0000221400142240,0x0001221400142240,0x0000221400142240,0x0040201400142201,0x0040201400142201,0x0040201400142201,0x0040201400142201,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x0040201400142040,0x0040201400142040,0x0040201400142040,0x0040201400142040,0x0000201400142040,0x0000201400142040,0x0000201400142040,0x0000201400142040,0x0040201400142200,0x0040201400142200,0x0040201400142200,0x0040201400142200,0x0000201400142201,0x0000201400142201,0x0000201400142201,0x0000201400142201,0x0001021400140201,0x0000021400140201,0x0001021400140201,0x0000021400140201,0x0000201400142040,0x0000201400142040,0x0000201400142040,0x0000201400142040,0x0041221400142000,0x0040221400142000,0x0041221400142000,0x0040221400142000,0x0000201400142200,0x0000201400142200,0x0000201400142200,0x0000201400142200,0x0001021400140200,0x0000021400140200,0x0001021400140200,0x0000021400140200,0x0001021400140201,0x0000021400140201,0x0001021400140201,0x0000021400140201,0x0041221400142000,0x0040221400142000,0x0041221400142000,0x0040221400142000,0x0001221400142000,0x0000221400142000,0x0001221400142000,0x0000221400142000,0x8041221400142040,0x8040221400142040,0x8041221400142040,0x8040221400142040,0x0001021400140200,0x0000021400140200,0x0001021400140200,0x0000021400140200,0x8041221400142201,0x8040221400142201,0x8041221400142201,0x8040221400142201,0x0001221400142000,0x0000221400142000,0x0001221400142000,0x0000221400142000,0x8041221400142040,0x8040221400142040,0x8041221400142040,0x8040221400142040,0x0001221400142040,0x0000221400142040,0x0001221400142040,0x0000221400142040,0x8041221400142200,0x8040221400142200,0x8041221400142200,0x8040221400142200,0x0001221400142201,0x0000221400142201,0x0001221400142201,0x0000221400142201,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0001221400142040,0x0000221400142040,0x0001221400142040,0x0000221400142040,0x0000001400142201,0x0000001400142201,0x0000001400142201,0x0000001400142201,0x0001221400142200,0x0000221400142200,0x0001221400142200,0x0000221400142200,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142200,0x0000001400142200,0x0000001400142200,0x0000001400142200,0x0000001400142201,0x0000001400142201,0x0000001400142201,0x0000001400142201,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0001021400140201,0x0000021400140201,0x0001021400140201,0x0000021400140201,0x0000001400142200,0x0000001400142200,0x0000001400142200,0x0000001400142200,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140200,0x0000021400140200,0x0001021400140200,0x0000021400140200,0x0001021400140201,0x0000021400140201,0x0001021400140201,0x0000021400140201,0x0040201400142040,0x0040201400142040,0x0040201400142040,0x0040201400142040,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0040201400142201,0x0040201400142201,0x0040201400142201,0x0040201400142201,0x0001021400140200,0x0000021400140200,0x0001021400140200,0x0000021400140200,0x0040201400142040,0x0040201400142040,0x0040201400142040,0x0040201400142040,0x0000201400142040,0x0000201400142040,0x0000201400142040,0x0000201400142040,0x0040201400142200,0x0040201400142200,0x0040201400142200,0x0040201400142200,0x0000201400142201,0x0000201400142201,0x0000201400142201,0x0000201400142201,0x8040201400142241,0x8040201400142241,0x8040201400142241,0x8040201400142241,0x0000201400142040,0x0000201400142040,0x0000201400142040,0x0000201400142040,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000201400142200,0x0000201400142200,0x0000201400142200,0x0000201400142200,0x8040201400142240,0x8040201400142240,0x8040201400142240,0x8040201400142240,0x0000201400142241,0x0000201400142241,0x0000201400142241,0x0000201400142241,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x8041221400142040,0x8040221400142040,0x8041221400142040,0x8040221400142040,0x0000201400142240,0x0000201400142240,0x0000201400142240,0x0000201400142240,0x8041221400142201,0x8040221400142201,0x8041221400142201,0x8040221400142201,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x8041221400142040,0x8040221400142040,0x8041221400142040,0x8040221400142040,0x0001221400142040,0x0000221400142040,0x0001221400142040,0x0000221400142040,0x8041221400142200,0x8040221400142200,0x8041221400142200,0x8040221400142200,0x0001221400142201,0x0000221400142201,0x0001221400142201,0x0000221400142201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0001221400142040,0x0000221400142040,0x0001221400142040,0x0000221400142040,0x0040201400142000,0x0040201400142000,0x0040201400142000,0x0040201400142000,0x0001221400142200,0x0000221400142200,0x0001221400142200,0x0000221400142200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0040201400142000,0x0040201400142000,0x0040201400142000,0x0040201400142000,0x0000201400142000,0x0000201400142000,0x0000201400142000,0x0000201400142000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0001021400140201,0x0000021400140201,0x0001021400140201,0x0000021400140201,0x0000201400142000,0x0000201400142000,0x0000201400142000,0x0000201400142000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140200,0x0000021400140200,0x0001021400140200,0x0000021400140200,0x0001021400140201,0x0000021400140201,0x0001021400140201,0x0000021400140201,0x0001021400140201,0x0000021400140201,0x0001021400140201,0x0000021400140201,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x8041221400142000,0x8040221400142000,0x8041221400142000,0x8040221400142000,0x0001021400140200,0x0000021400140200,0x0001021400140200,0x0000021400140200,0x0001021400140200,0x0000021400140200,0x0001021400140200,0x0000021400140200,0x0001021400140201,0x0000021400140201,0x0001021400140201,0x0000021400140201,0x8041221400142000,0x8040221400142000,0x8041221400142000,0x8040221400142000,0x0001221400142000,0x0000221400142000,0x0001221400142000,0x0000221400142000,0x8040201400142241,0x8040201400142241,0x8040201400142241,0x8040201400142241,0x0001021400140200,0x0000021400140200,0x0001021400140200,0x0000021400140200,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0001221400142000,0x0000221400142000,0x0001221400142000,0x0000221400142000,0x8040201400142240,0x8040201400142240,0x8040201400142240,0x8040201400142240,0x0000201400142241,0x0000201400142241,0x0000201400142241,0x0000201400142241,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0041221400140201,0x0040221400140201,0x0041221400140201,0x0040221400140201,0x0000201400142240,0x0000201400142240,0x0000201400142240,0x0000201400142240,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0041221400140200,0x0040221400140200,0x0041221400140200,0x0040221400140200,0x0001221400140201,0x0000221400140201,0x0001221400140201,0x0000221400140201,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0001221400140200,0x0000221400140200,0x0001221400140200,0x0000221400140200,0x0040201400142000,0x0040201400142000,0x0040201400142000,0x0040201400142000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0040201400142000,0x0040201400142000,0x0040201400142000,0x0040201400142000,0x0000201400142000,0x0000201400142000,0x0000201400142000,0x0000201400142000,0x8040201400142040,0x8040201400142040,0x8040201400142040,0x8040201400142040,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x8040201400142201,0x8040201400142201,0x8040201400142201,0x8040201400142201,0x0000201400142000,0x0000201400142000,0x0000201400142000,0x0000201400142000,0x8040201400142040,0x8040201400142040,0x8040201400142040,0x8040201400142040,0x0000201400142040,0x0000201400142040,0x0000201400142040,0x0000201400142040,0x8040201400142200,0x8040201400142200,0x8040201400142200,0x8040201400142200,0x0000201400142201,0x0000201400142201,0x0000201400142201,0x0000201400142201,0x0001021400140201,0x0000021400140201,0x0001021400140201,0x0000021400140201,0x0000201400142040,0x0000201400142040,0x0000201400142040,0x0000201400142040,0x8041221400142000,0x8040221400142000,0x8041221400142000,0x8040221400142000,0x0000201400142200,0x0000201400142200,0x0000201400142200,0x0000201400142200,0x0001021400140200,0x0000021400140200,0x0001021400140200,0x0000021400140200,0x0001021400140201,0x0000021400140201,0x0001021400140201,0x0000021400140201,0x8041221400142000,0x8040221400142000,0x8041221400142000,0x8040221400142000,0x0001221400142000,0x0000221400142000,0x0001221400142000,0x0000221400142000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0001021400140200,0x0000021400140200,0x0001021400140200,0x0000021400140200,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0001221400142000,0x0000221400142000,0x0001221400142000,0x0000221400142000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0041221400140201,0x0040221400140201,0x0041221400140201,0x0040221400140201,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0041221400140200,0x0040221400140200,0x0041221400140200,0x0040221400140200,0x0001221400140201,0x0000221400140201,0x0001221400140201,0x0000221400140201,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001221400140200,0x0000221400140200,0x0001221400140200,0x0000221400140200,0x0001021400140201,0x0000021400140201,0x0001021400140201,0x0000021400140201,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140200,0x0000021400140200,0x0001021400140200,0x0000021400140200,0x0001021400140201,0x0000021400140201,0x0001021400140201,0x0000021400140201,0x8040201400142040,0x8040201400142040,0x8040201400142040,0x8040201400142040,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x8040201400142201,0x8040201400142201,0x8040201400142201,0x8040201400142201,0x0001021400140200,0x0000021400140200,0x0001021400140200,0x0000021400140200,0x8040201400142040,0x8040201400142040,0x8040201400142040,0x8040201400142040,0x0000201400142040,0x0000201400142040,0x0000201400142040,0x0000201400142040,0x8040201400142200,0x8040201400142200,0x8040201400142200,0x8040201400142200,0x0000201400142201,0x0000201400142201,0x0000201400142201,0x0000201400142201,0x0041221400140000,0x0040221400140000,0x0041221400140000,0x0040221400140000,0x0000201400142040,0x0000201400142040,0x0000201400142040,0x0000201400142040,0x0041221400140201,0x0040221400140201,0x0041221400140201,0x0040221400140201,0x0000201400142200,0x0000201400142200,0x0000201400142200,0x0000201400142200,0x0041221400140000,0x0040221400140000,0x0041221400140000,0x0040221400140000,0x0001221400140000,0x0000221400140000,0x0001221400140000,0x0000221400140000,0x0041221400140200,0x0040221400140200,0x0041221400140200,0x0040221400140200,0x0001221400140201,0x0000221400140201,0x0001221400140201,0x0000221400140201,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0001221400140000,0x0000221400140000,0x0001221400140000,0x0000221400140000,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0001221400140200,0x0000221400140200,0x0001221400140200,0x0000221400140200,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x8040201400142000,0x8040201400142000,0x8040201400142000,0x8040201400142000,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x8040201400142000,0x8040201400142000,0x8040201400142000,0x8040201400142000,0x0000201400142000,0x0000201400142000,0x0000201400142000,0x0000201400142000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0001021400140201,0x0000021400140201,0x0001021400140201,0x0000021400140201,0x0000201400142000,0x0000201400142000,0x0000201400142000,0x0000201400142000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140200,0x0000021400140200,0x0001021400140200,0x0000021400140200,0x0001021400140201,0x0000021400140201,0x0001021400140201,0x0000021400140201,0x0040201400140201,0x0040201400140201,0x0040201400140201,0x0040201400140201,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0001021400140200,0x0000021400140200,0x0001021400140200,0x0000021400140200,0x0040201400140200,0x0040201400140200,0x0040201400140200,0x0040201400140200,0x0000201400140201,0x0000201400140201,0x0000201400140201,0x0000201400140201,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0041221400140000,0x0040221400140000,0x0041221400140000,0x0040221400140000,0x0000201400140200,0x0000201400140200,0x0000201400140200,0x0000201400140200,0x0041221400140201,0x0040221400140201,0x0041221400140201,0x0040221400140201,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0041221400140000,0x0040221400140000,0x0041221400140000,0x0040221400140000,0x0001221400140000,0x0000221400140000,0x0001221400140000,0x0000221400140000,0x0041221400140200,0x0040221400140200,0x0041221400140200,0x0040221400140200,0x0001221400140201,0x0000221400140201,0x0001221400140201,0x0000221400140201,0x8041221400140201,0x8040221400140201,0x8041221400140201,0x8040221400140201,0x0001221400140000,0x0000221400140000,0x0001221400140000,0x0000221400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001221400140200,0x0000221400140200,0x0001221400140200,0x0000221400140200,0x8041221400140200,0x8040221400140200,0x8041221400140200,0x8040221400140200,0x0001221400140201,0x0000221400140201,0x0001221400140201,0x0000221400140201,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0001221400140200,0x0000221400140200,0x0001221400140200,0x0000221400140200,0x8040201400142000,0x8040201400142000,0x8040201400142000,0x8040201400142000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x8040201400142000,0x8040201400142000,0x8040201400142000,0x8040201400142000,0x0000201400142000,0x0000201400142000,0x0000201400142000,0x0000201400142000,0x0001021400142241,0x0000021400142241,0x0001021400142241,0x0000021400142241,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0041221400140000,0x0040221400140000,0x0041221400140000,0x0040221400140000,0x0000201400142000,0x0000201400142000,0x0000201400142000,0x0000201400142000,0x0001021400142240,0x0000021400142240,0x0001021400142240,0x0000021400142240,0x0001021400142241,0x0000021400142241,0x0001021400142241,0x0000021400142241,0x0041221400140000,0x0040221400140000,0x0041221400140000,0x0040221400140000,0x0001221400140000,0x0000221400140000,0x0001221400140000,0x0000221400140000,0x0040201400140201,0x0040201400140201,0x0040201400140201,0x0040201400140201,0x0001021400142240,0x0000021400142240,0x0001021400142240,0x0000021400142240,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0001221400140000,0x0000221400140000,0x0001221400140000,0x0000221400140000,0x0040201400140200,0x0040201400140200,0x0040201400140200,0x0040201400140200,0x0000201400140201,0x0000201400140201,0x0000201400140201,0x0000201400140201,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000201400140200,0x0000201400140200,0x0000201400140200,0x0000201400140200,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x8041221400140201,0x8040221400140201,0x8041221400140201,0x8040221400140201,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x8041221400140200,0x8040221400140200,0x8041221400140200,0x8040221400140200,0x0001221400140201,0x0000221400140201,0x0001221400140201,0x0000221400140201,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0040201400140000,0x0040201400140000,0x0040201400140000,0x0040201400140000,0x0001221400140200,0x0000221400140200,0x0001221400140200,0x0000221400140200,0x0040201400140201,0x0040201400140201,0x0040201400140201,0x0040201400140201,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0040201400140000,0x0040201400140000,0x0040201400140000,0x0040201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0040201400140200,0x0040201400140200,0x0040201400140200,0x0040201400140200,0x0000201400140201,0x0000201400140201,0x0000201400140201,0x0000201400140201,0x0001021400142241,0x0000021400142241,0x0001021400142241,0x0000021400142241,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0041221400140000,0x0040221400140000,0x0041221400140000,0x0040221400140000,0x0000201400140200,0x0000201400140200,0x0000201400140200,0x0000201400140200,0x0001021400142240,0x0000021400142240,0x0001021400142240,0x0000021400142240,0x0001021400142241,0x0000021400142241,0x0001021400142241,0x0000021400142241,0x0041221400140000,0x0040221400140000,0x0041221400140000,0x0040221400140000,0x0001221400140000,0x0000221400140000,0x0001221400140000,0x0000221400140000,0x8041221400140000,0x8040221400140000,0x8041221400140000,0x8040221400140000,0x0001021400142240,0x0000021400142240,0x0001021400142240,0x0000021400142240,0x8041221400140201,0x8040221400140201,0x8041221400140201,0x8040221400140201,0x0001221400140000,0x0000221400140000,0x0001221400140000,0x0000221400140000,0x8041221400140000,0x8040221400140000,0x8041221400140000,0x8040221400140000,0x0001221400140000,0x0000221400140000,0x0001221400140000,0x0000221400140000,0x8041221400140200,0x8040221400140200,0x8041221400140200,0x8040221400140200,0x0001221400140201,0x0000221400140201,0x0001221400140201,0x0000221400140201,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0001221400140000,0x0000221400140000,0x0001221400140000,0x0000221400140000,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0001221400140200,0x0000221400140200,0x0001221400140200,0x0000221400140200,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0001021400142201,0x0000021400142201,0x0001021400142201,0x0000021400142201,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x0001021400142200,0x0000021400142200,0x0001021400142200,0x0000021400142200,0x0001021400142201,0x0000021400142201,0x0001021400142201,0x0000021400142201,0x0040201400140000,0x0040201400140000,0x0040201400140000,0x0040201400140000,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x0040201400140201,0x0040201400140201,0x0040201400140201,0x0040201400140201,0x0001021400142200,0x0000021400142200,0x0001021400142200,0x0000021400142200,0x0040201400140000,0x0040201400140000,0x0040201400140000,0x0040201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0040201400140200,0x0040201400140200,0x0040201400140200,0x0040201400140200,0x0000201400140201,0x0000201400140201,0x0000201400140201,0x0000201400140201,0x8040201400140201,0x8040201400140201,0x8040201400140201,0x8040201400140201,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000201400140200,0x0000201400140200,0x0000201400140200,0x0000201400140200,0x8040201400140200,0x8040201400140200,0x8040201400140200,0x8040201400140200,0x0000201400140201,0x0000201400140201,0x0000201400140201,0x0000201400140201,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x8041221400140000,0x8040221400140000,0x8041221400140000,0x8040221400140000,0x0000201400140200,0x0000201400140200,0x0000201400140200,0x0000201400140200,0x8041221400140201,0x8040221400140201,0x8041221400140201,0x8040221400140201,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x8041221400140000,0x8040221400140000,0x8041221400140000,0x8040221400140000,0x0001221400140000,0x0000221400140000,0x0001221400140000,0x0000221400140000,0x8041221400140200,0x8040221400140200,0x8041221400140200,0x8040221400140200,0x0001221400140201,0x0000221400140201,0x0001221400140201,0x0000221400140201,0x0000001400142241,0x0000001400142241,0x0000001400142241,0x0000001400142241,0x0001221400140000,0x0000221400140000,0x0001221400140000,0x0000221400140000,0x0040201400140000,0x0040201400140000,0x0040201400140000,0x0040201400140000,0x0001221400140200,0x0000221400140200,0x0001221400140200,0x0000221400140200,0x0000001400142240,0x0000001400142240,0x0000001400142240,0x0000001400142240,0x0000001400142241,0x0000001400142241,0x0000001400142241,0x0000001400142241,0x0040201400140000,0x0040201400140000,0x0040201400140000,0x0040201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x0000001400142240,0x0000001400142240,0x0000001400142240,0x0000001400142240,0x0001021400142201,0x0000021400142201,0x0001021400142201,0x0000021400142201,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x0001021400142200,0x0000021400142200,0x0001021400142200,0x0000021400142200,0x0001021400142201,0x0000021400142201,0x0001021400142201,0x0000021400142201,0x0001021400142241,0x0000021400142241,0x0001021400142241,0x0000021400142241,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x8041221400140000,0x8040221400140000,0x8041221400140000,0x8040221400140000,0x0001021400142200,0x0000021400142200,0x0001021400142200,0x0000021400142200,0x0001021400142240,0x0000021400142240,0x0001021400142240,0x0000021400142240,0x0001021400142241,0x0000021400142241,0x0001021400142241,0x0000021400142241,0x8041221400140000,0x8040221400140000,0x8041221400140000,0x8040221400140000,0x0001221400140000,0x0000221400140000,0x0001221400140000,0x0000221400140000,0x8040201400140201,0x8040201400140201,0x8040201400140201,0x8040201400140201,0x0001021400142240,0x0000021400142240,0x0001021400142240,0x0000021400142240,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0001221400140000,0x0000221400140000,0x0001221400140000,0x0000221400140000,0x8040201400140200,0x8040201400140200,0x8040201400140200,0x8040201400140200,0x0000201400140201,0x0000201400140201,0x0000201400140201,0x0000201400140201,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x8041221400142241,0x8040221400142241,0x8041221400142241,0x8040221400142241,0x0000201400140200,0x0000201400140200,0x0000201400140200,0x0000201400140200,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x8041221400142240,0x8040221400142240,0x8041221400142240,0x8040221400142240,0x0001221400142241,0x0000221400142241,0x0001221400142241,0x0000221400142241,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x0000001400142241,0x0000001400142241,0x0000001400142241,0x0000001400142241,0x0001221400142240,0x0000221400142240,0x0001221400142240,0x0000221400142240,0x0040201400140000,0x0040201400140000,0x0040201400140000,0x0040201400140000,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x0000001400142240,0x0000001400142240,0x0000001400142240,0x0000001400142240,0x0000001400142241,0x0000001400142241,0x0000001400142241,0x0000001400142241,0x0040201400140000,0x0040201400140000,0x0040201400140000,0x0040201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x8040201400140000,0x8040201400140000,0x8040201400140000,0x8040201400140000,0x0000001400142240,0x0000001400142240,0x0000001400142240,0x0000001400142240,0x8040201400140201,0x8040201400140201,0x8040201400140201,0x8040201400140201,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x8040201400140000,0x8040201400140000,0x8040201400140000,0x8040201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x8040201400140200,0x8040201400140200,0x8040201400140200,0x8040201400140200,0x0000201400140201,0x0000201400140201,0x0000201400140201,0x0000201400140201,0x0001021400142241,0x0000021400142241,0x0001021400142241,0x0000021400142241,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x8041221400140000,0x8040221400140000,0x8041221400140000,0x8040221400140000,0x0000201400140200,0x0000201400140200,0x0000201400140200,0x0000201400140200,0x0001021400142240,0x0000021400142240,0x0001021400142240,0x0000021400142240,0x0001021400142241,0x0000021400142241,0x0001021400142241,0x0000021400142241,0x8041221400140000,0x8040221400140000,0x8041221400140000,0x8040221400140000,0x0001221400140000,0x0000221400140000,0x0001221400140000,0x0000221400140000,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0001021400142240,0x0000021400142240,0x0001021400142240,0x0000021400142240,0x0000001400142201,0x0000001400142201,0x0000001400142201,0x0000001400142201,0x0001221400140000,0x0000221400140000,0x0001221400140000,0x0000221400140000,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142200,0x0000001400142200,0x0000001400142200,0x0000001400142200,0x0000001400142201,0x0000001400142201,0x0000001400142201,0x0000001400142201,0x8041221400142241,0x8040221400142241,0x8041221400142241,0x8040221400142241,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x0000001400142200,0x0000001400142200,0x0000001400142200,0x0000001400142200,0x8041221400142240,0x8040221400142240,0x8041221400142240,0x8040221400142240,0x0001221400142241,0x0000221400142241,0x0001221400142241,0x0000221400142241,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x0001221400142240,0x0000221400142240,0x0001221400142240,0x0000221400142240,0x0001021400142201,0x0000021400142201,0x0001021400142201,0x0000021400142201,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x0001021400142200,0x0000021400142200,0x0001021400142200,0x0000021400142200,0x0001021400142201,0x0000021400142201,0x0001021400142201,0x0000021400142201,0x8040201400140000,0x8040201400140000,0x8040201400140000,0x8040201400140000,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x8040201400140201,0x8040201400140201,0x8040201400140201,0x8040201400140201,0x0001021400142200,0x0000021400142200,0x0001021400142200,0x0000021400142200,0x8040201400140000,0x8040201400140000,0x8040201400140000,0x8040201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x8040201400140200,0x8040201400140200,0x8040201400140200,0x8040201400140200,0x0000201400140201,0x0000201400140201,0x0000201400140201,0x0000201400140201,0x8041221400142040,0x8040221400142040,0x8041221400142040,0x8040221400142040,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x8041221400142201,0x8040221400142201,0x8041221400142201,0x8040221400142201,0x0000201400140200,0x0000201400140200,0x0000201400140200,0x0000201400140200,0x8041221400142040,0x8040221400142040,0x8041221400142040,0x8040221400142040,0x0001221400142040,0x0000221400142040,0x0001221400142040,0x0000221400142040,0x8041221400142200,0x8040221400142200,0x8041221400142200,0x8040221400142200,0x0001221400142201,0x0000221400142201,0x0001221400142201,0x0000221400142201,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0001221400142040,0x0000221400142040,0x0001221400142040,0x0000221400142040,0x0000001400142201,0x0000001400142201,0x0000001400142201,0x0000001400142201,0x0001221400142200,0x0000221400142200,0x0001221400142200,0x0000221400142200,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142200,0x0000001400142200,0x0000001400142200,0x0000001400142200,0x0000001400142201,0x0000001400142201,0x0000001400142201,0x0000001400142201,0x0000001400142241,0x0000001400142241,0x0000001400142241,0x0000001400142241,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x8040201400140000,0x8040201400140000,0x8040201400140000,0x8040201400140000,0x0000001400142200,0x0000001400142200,0x0000001400142200,0x0000001400142200,0x0000001400142240,0x0000001400142240,0x0000001400142240,0x0000001400142240,0x0000001400142241,0x0000001400142241,0x0000001400142241,0x0000001400142241,0x8040201400140000,0x8040201400140000,0x8040201400140000,0x8040201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x0000001400142240,0x0000001400142240,0x0000001400142240,0x0000001400142240,0x0001021400142201,0x0000021400142201,0x0001021400142201,0x0000021400142201,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x0001021400142200,0x0000021400142200,0x0001021400142200,0x0000021400142200,0x0001021400142201,0x0000021400142201,0x0001021400142201,0x0000021400142201,0x8040201400142241,0x8040201400142241,0x8040201400142241,0x8040201400142241,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0001021400142200,0x0000021400142200,0x0001021400142200,0x0000021400142200,0x8040201400142240,0x8040201400142240,0x8040201400142240,0x8040201400142240,0x0000201400142241,0x0000201400142241,0x0000201400142241,0x0000201400142241,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x8041221400142040,0x8040221400142040,0x8041221400142040,0x8040221400142040,0x0000201400142240,0x0000201400142240,0x0000201400142240,0x0000201400142240,0x8041221400142201,0x8040221400142201,0x8041221400142201,0x8040221400142201,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x8041221400142040,0x8040221400142040,0x8041221400142040,0x8040221400142040,0x0001221400142040,0x0000221400142040,0x0001221400142040,0x0000221400142040,0x8041221400142200,0x8040221400142200,0x8041221400142200,0x8040221400142200,0x0001221400142201,0x0000221400142201,0x0001221400142201,0x0000221400142201,0x0041221400142241,0x0040221400142241,0x0041221400142241,0x0040221400142241,0x0001221400142040,0x0000221400142040,0x0001221400142040,0x0000221400142040,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x0001221400142200,0x0000221400142200,0x0001221400142200,0x0000221400142200,0x0041221400142240,0x0040221400142240,0x0041221400142240,0x0040221400142240,0x0001221400142241,0x0000221400142241,0x0001221400142241,0x0000221400142241,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x0000001400142241,0x0000001400142241,0x0000001400142241,0x0000001400142241,0x0001221400142240,0x0000221400142240,0x0001221400142240,0x0000221400142240,0x8040201400140000,0x8040201400140000,0x8040201400140000,0x8040201400140000,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x0000001400142240,0x0000001400142240,0x0000001400142240,0x0000001400142240,0x0000001400142241,0x0000001400142241,0x0000001400142241,0x0000001400142241,0x8040201400140000,0x8040201400140000,0x8040201400140000,0x8040201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0001021400140201,0x0000021400140201,0x0001021400140201,0x0000021400140201,0x0000001400142240,0x0000001400142240,0x0000001400142240,0x0000001400142240,0x8041221400142000,0x8040221400142000,0x8041221400142000,0x8040221400142000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0001021400140200,0x0000021400140200,0x0001021400140200,0x0000021400140200,0x0001021400140201,0x0000021400140201,0x0001021400140201,0x0000021400140201,0x8041221400142000,0x8040221400142000,0x8041221400142000,0x8040221400142000,0x0001221400142000,0x0000221400142000,0x0001221400142000,0x0000221400142000,0x8040201400142241,0x8040201400142241,0x8040201400142241,0x8040201400142241,0x0001021400140200,0x0000021400140200,0x0001021400140200,0x0000021400140200,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0001221400142000,0x0000221400142000,0x0001221400142000,0x0000221400142000,0x8040201400142240,0x8040201400142240,0x8040201400142240,0x8040201400142240,0x0000201400142241,0x0000201400142241,0x0000201400142241,0x0000201400142241,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000201400142240,0x0000201400142240,0x0000201400142240,0x0000201400142240,0x0000001400142201,0x0000001400142201,0x0000001400142201,0x0000001400142201,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142200,0x0000001400142200,0x0000001400142200,0x0000001400142200,0x0000001400142201,0x0000001400142201,0x0000001400142201,0x0000001400142201,0x0041221400142241,0x0040221400142241,0x0041221400142241,0x0040221400142241,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x0000001400142200,0x0000001400142200,0x0000001400142200,0x0000001400142200,0x0041221400142240,0x0040221400142240,0x0041221400142240,0x0040221400142240,0x0001221400142241,0x0000221400142241,0x0001221400142241,0x0000221400142241,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x8040201400142040,0x8040201400142040,0x8040201400142040,0x8040201400142040,0x0001221400142240,0x0000221400142240,0x0001221400142240,0x0000221400142240,0x8040201400142201,0x8040201400142201,0x8040201400142201,0x8040201400142201,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x8040201400142040,0x8040201400142040,0x8040201400142040,0x8040201400142040,0x0000201400142040,0x0000201400142040,0x0000201400142040,0x0000201400142040,0x8040201400142200,0x8040201400142200,0x8040201400142200,0x8040201400142200,0x0000201400142201,0x0000201400142201,0x0000201400142201,0x0000201400142201,0x0001021400140201,0x0000021400140201,0x0001021400140201,0x0000021400140201,0x0000201400142040,0x0000201400142040,0x0000201400142040,0x0000201400142040,0x8041221400142000,0x8040221400142000,0x8041221400142000,0x8040221400142000,0x0000201400142200,0x0000201400142200,0x0000201400142200,0x0000201400142200,0x0001021400140200,0x0000021400140200,0x0001021400140200,0x0000021400140200,0x0001021400140201,0x0000021400140201,0x0001021400140201,0x0000021400140201,0x8041221400142000,0x8040221400142000,0x8041221400142000,0x8040221400142000,0x0001221400142000,0x0000221400142000,0x0001221400142000,0x0000221400142000,0x0041221400142040,0x0040221400142040,0x0041221400142040,0x0040221400142040,0x0001021400140200,0x0000021400140200,0x0001021400140200,0x0000021400140200,0x0041221400142201,0x0040221400142201,0x0041221400142201,0x0040221400142201,0x0001221400142000,0x0000221400142000,0x0001221400142000,0x0000221400142000,0x0041221400142040,0x0040221400142040,0x0041221400142040,0x0040221400142040,0x0001221400142040,0x0000221400142040,0x0001221400142040,0x0000221400142040,0x0041221400142200,0x0040221400142200,0x0041221400142200,0x0040221400142200,0x0001221400142201,0x0000221400142201,0x0001221400142201,0x0000221400142201,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0001221400142040,0x0000221400142040,0x0001221400142040,0x0000221400142040,0x0000001400142201,0x0000001400142201,0x0000001400142201,0x0000001400142201,0x0001221400142200,0x0000221400142200,0x0001221400142200,0x0000221400142200,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142200,0x0000001400142200,0x0000001400142200,0x0000001400142200,0x0000001400142201,0x0000001400142201,0x0000001400142201,0x0000001400142201,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0001021400140201,0x0000021400140201,0x0001021400140201,0x0000021400140201,0x0000001400142200,0x0000001400142200,0x0000001400142200,0x0000001400142200,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140200,0x0000021400140200,0x0001021400140200,0x0000021400140200,0x0001021400140201,0x0000021400140201,0x0001021400140201,0x0000021400140201,0x8040201400142040,0x8040201400142040,0x8040201400142040,0x8040201400142040,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x8040201400142201,0x8040201400142201,0x8040201400142201,0x8040201400142201,0x0001021400140200,0x0000021400140200,0x0001021400140200,0x0000021400140200,0x8040201400142040,0x8040201400142040,0x8040201400142040,0x8040201400142040,0x0000201400142040,0x0000201400142040,0x0000201400142040,0x0000201400142040,0x8040201400142200,0x8040201400142200,0x8040201400142200,0x8040201400142200,0x0000201400142201,0x0000201400142201,0x0000201400142201,0x0000201400142201,0x0040201400142241,0x0040201400142241,0x0040201400142241,0x0040201400142241,0x0000201400142040,0x0000201400142040,0x0000201400142040,0x0000201400142040,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000201400142200,0x0000201400142200,0x0000201400142200,0x0000201400142200,0x0040201400142240,0x0040201400142240,0x0040201400142240,0x0040201400142240,0x0000201400142241,0x0000201400142241,0x0000201400142241,0x0000201400142241,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0041221400142040,0x0040221400142040,0x0041221400142040,0x0040221400142040,0x0000201400142240,0x0000201400142240,0x0000201400142240,0x0000201400142240,0x0041221400142201,0x0040221400142201,0x0041221400142201,0x0040221400142201,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0041221400142040,0x0040221400142040,0x0041221400142040,0x0040221400142040,0x0001221400142040,0x0000221400142040,0x0001221400142040,0x0000221400142040,0x0041221400142200,0x0040221400142200,0x0041221400142200,0x0040221400142200,0x0001221400142201,0x0000221400142201,0x0001221400142201,0x0000221400142201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0001221400142040,0x0000221400142040,0x0001221400142040,0x0000221400142040,0x8040201400142000,0x8040201400142000,0x8040201400142000,0x8040201400142000,0x0001221400142200,0x0000221400142200,0x0001221400142200,0x0000221400142200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x8040201400142000,0x8040201400142000,0x8040201400142000,0x8040201400142000,0x0000201400142000,0x0000201400142000,0x0000201400142000,0x0000201400142000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0001021400140201,0x0000021400140201,0x0001021400140201,0x0000021400140201,0x0000201400142000,0x0000201400142000,0x0000201400142000,0x0000201400142000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140200,0x0000021400140200,0x0001021400140200,0x0000021400140200,0x0001021400140201,0x0000021400140201,0x0001021400140201,0x0000021400140201,0x0001021400140201,0x0000021400140201,0x0001021400140201,0x0000021400140201,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0041221400142000,0x0040221400142000,0x0041221400142000,0x0040221400142000,0x0001021400140200,0x0000021400140200,0x0001021400140200,0x0000021400140200,0x0001021400140200,0x0000021400140200,0x0001021400140200,0x0000021400140200,0x0001021400140201,0x0000021400140201,0x0001021400140201,0x0000021400140201,0x0041221400142000,0x0040221400142000,0x0041221400142000,0x0040221400142000,0x0001221400142000,0x0000221400142000,0x0001221400142000,0x0000221400142000,0x0040201400142241,0x0040201400142241,0x0040201400142241,0x0040201400142241,0x0001021400140200,0x0000021400140200,0x0001021400140200,0x0000021400140200,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0001221400142000,0x0000221400142000,0x0001221400142000,0x0000221400142000,0x0040201400142240,0x0040201400142240,0x0040201400142240,0x0040201400142240,0x0000201400142241,0x0000201400142241,0x0000201400142241,0x0000201400142241,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x8041221400140201,0x8040221400140201,0x8041221400140201,0x8040221400140201,0x0000201400142240,0x0000201400142240,0x0000201400142240,0x0000201400142240,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x8041221400140200,0x8040221400140200,0x8041221400140200,0x8040221400140200,0x0001221400140201,0x0000221400140201,0x0001221400140201,0x0000221400140201,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0001221400140200,0x0000221400140200,0x0001221400140200,0x0000221400140200,0x8040201400142000,0x8040201400142000,0x8040201400142000,0x8040201400142000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x8040201400142000,0x8040201400142000,0x8040201400142000,0x8040201400142000,0x0000201400142000,0x0000201400142000,0x0000201400142000,0x0000201400142000,0x0040201400142040,0x0040201400142040,0x0040201400142040,0x0040201400142040,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0040201400142201,0x0040201400142201,0x0040201400142201,0x0040201400142201,0x0000201400142000,0x0000201400142000,0x0000201400142000,0x0000201400142000,0x0040201400142040,0x0040201400142040,0x0040201400142040,0x0040201400142040,0x0000201400142040,0x0000201400142040,0x0000201400142040,0x0000201400142040,0x0040201400142200,0x0040201400142200,0x0040201400142200,0x0040201400142200,0x0000201400142201,0x0000201400142201,0x0000201400142201,0x0000201400142201,0x0001021400140201,0x0000021400140201,0x0001021400140201,0x0000021400140201,0x0000201400142040,0x0000201400142040,0x0000201400142040,0x0000201400142040,0x0041221400142000,0x0040221400142000,0x0041221400142000,0x0040221400142000,0x0000201400142200,0x0000201400142200,0x0000201400142200,0x0000201400142200,0x0001021400140200,0x0000021400140200,0x0001021400140200,0x0000021400140200,0x0001021400140201,0x0000021400140201,0x0001021400140201,0x0000021400140201,0x0041221400142000,0x0040221400142000,0x0041221400142000,0x0040221400142000,0x0001221400142000,0x0000221400142000,0x0001221400142000,0x0000221400142000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0001021400140200,0x0000021400140200,0x0001021400140200,0x0000021400140200,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0001221400142000,0x0000221400142000,0x0001221400142000,0x0000221400142000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x8041221400140201,0x8040221400140201,0x8041221400140201,0x8040221400140201,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x8041221400140200,0x8040221400140200,0x8041221400140200,0x8040221400140200,0x0001221400140201,0x0000221400140201,0x0001221400140201,0x0000221400140201,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001221400140200,0x0000221400140200,0x0001221400140200,0x0000221400140200,0x0001021400140201,0x0000021400140201,0x0001021400140201,0x0000021400140201,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140200,0x0000021400140200,0x0001021400140200,0x0000021400140200,0x0001021400140201,0x0000021400140201,0x0001021400140201,0x0000021400140201,0x0040201400142040,0x0040201400142040,0x0040201400142040,0x0040201400142040,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0040201400142201,0x0040201400142201,0x0040201400142201,0x0040201400142201,0x0001021400140200,0x0000021400140200,0x0001021400140200,0x0000021400140200,0x0040201400142040,0x0040201400142040,0x0040201400142040,0x0040201400142040,0x0000201400142040,0x0000201400142040,0x0000201400142040,0x0000201400142040,0x0040201400142200,0x0040201400142200,0x0040201400142200,0x0040201400142200,0x0000201400142201,0x0000201400142201,0x0000201400142201,0x0000201400142201,0x8041221400140000,0x8040221400140000,0x8041221400140000,0x8040221400140000,0x0000201400142040,0x0000201400142040,0x0000201400142040,0x0000201400142040,0x8041221400140201,0x8040221400140201,0x8041221400140201,0x8040221400140201,0x0000201400142200,0x0000201400142200,0x0000201400142200,0x0000201400142200,0x8041221400140000,0x8040221400140000,0x8041221400140000,0x8040221400140000,0x0001221400140000,0x0000221400140000,0x0001221400140000,0x0000221400140000,0x8041221400140200,0x8040221400140200,0x8041221400140200,0x8040221400140200,0x0001221400140201,0x0000221400140201,0x0001221400140201,0x0000221400140201,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0001221400140000,0x0000221400140000,0x0001221400140000,0x0000221400140000,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0001221400140200,0x0000221400140200,0x0001221400140200,0x0000221400140200,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0040201400142000,0x0040201400142000,0x0040201400142000,0x0040201400142000,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0040201400142000,0x0040201400142000,0x0040201400142000,0x0040201400142000,0x0000201400142000,0x0000201400142000,0x0000201400142000,0x0000201400142000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0001021400140201,0x0000021400140201,0x0001021400140201,0x0000021400140201,0x0000201400142000,0x0000201400142000,0x0000201400142000,0x0000201400142000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140200,0x0000021400140200,0x0001021400140200,0x0000021400140200,0x0001021400140201,0x0000021400140201,0x0001021400140201,0x0000021400140201,0x8040201400140201,0x8040201400140201,0x8040201400140201,0x8040201400140201,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0001021400140200,0x0000021400140200,0x0001021400140200,0x0000021400140200,0x8040201400140200,0x8040201400140200,0x8040201400140200,0x8040201400140200,0x0000201400140201,0x0000201400140201,0x0000201400140201,0x0000201400140201,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x8041221400140000,0x8040221400140000,0x8041221400140000,0x8040221400140000,0x0000201400140200,0x0000201400140200,0x0000201400140200,0x0000201400140200,0x8041221400140201,0x8040221400140201,0x8041221400140201,0x8040221400140201,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x8041221400140000,0x8040221400140000,0x8041221400140000,0x8040221400140000,0x0001221400140000,0x0000221400140000,0x0001221400140000,0x0000221400140000,0x8041221400140200,0x8040221400140200,0x8041221400140200,0x8040221400140200,0x0001221400140201,0x0000221400140201,0x0001221400140201,0x0000221400140201,0x0041221400140201,0x0040221400140201,0x0041221400140201,0x0040221400140201,0x0001221400140000,0x0000221400140000,0x0001221400140000,0x0000221400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001221400140200,0x0000221400140200,0x0001221400140200,0x0000221400140200,0x0041221400140200,0x0040221400140200,0x0041221400140200,0x0040221400140200,0x0001221400140201,0x0000221400140201,0x0001221400140201,0x0000221400140201,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0001221400140200,0x0000221400140200,0x0001221400140200,0x0000221400140200,0x0040201400142000,0x0040201400142000,0x0040201400142000,0x0040201400142000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0040201400142000,0x0040201400142000,0x0040201400142000,0x0040201400142000,0x0000201400142000,0x0000201400142000,0x0000201400142000,0x0000201400142000,0x0001021400142241,0x0000021400142241,0x0001021400142241,0x0000021400142241,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x8041221400140000,0x8040221400140000,0x8041221400140000,0x8040221400140000,0x0000201400142000,0x0000201400142000,0x0000201400142000,0x0000201400142000,0x0001021400142240,0x0000021400142240,0x0001021400142240,0x0000021400142240,0x0001021400142241,0x0000021400142241,0x0001021400142241,0x0000021400142241,0x8041221400140000,0x8040221400140000,0x8041221400140000,0x8040221400140000,0x0001221400140000,0x0000221400140000,0x0001221400140000,0x0000221400140000,0x8040201400140201,0x8040201400140201,0x8040201400140201,0x8040201400140201,0x0001021400142240,0x0000021400142240,0x0001021400142240,0x0000021400142240,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0001221400140000,0x0000221400140000,0x0001221400140000,0x0000221400140000,0x8040201400140200,0x8040201400140200,0x8040201400140200,0x8040201400140200,0x0000201400140201,0x0000201400140201,0x0000201400140201,0x0000201400140201,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000201400140200,0x0000201400140200,0x0000201400140200,0x0000201400140200,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0041221400140201,0x0040221400140201,0x0041221400140201,0x0040221400140201,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0041221400140200,0x0040221400140200,0x0041221400140200,0x0040221400140200,0x0001221400140201,0x0000221400140201,0x0001221400140201,0x0000221400140201,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x8040201400140000,0x8040201400140000,0x8040201400140000,0x8040201400140000,0x0001221400140200,0x0000221400140200,0x0001221400140200,0x0000221400140200,0x8040201400140201,0x8040201400140201,0x8040201400140201,0x8040201400140201,0x0001021400140000,0x0000021400140000,0x0001021400140000,0x0000021400140000,0x8040201400140000,0x8040201400140000,0x8040201400140000,0x8040201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x8040201400140200,0x8040201400140200,0x8040201400140200,0x8040201400140200,0x0000201400140201,0x0000201400140201,0x0000201400140201,0x0000201400140201,0x0001021400142241,0x0000021400142241,0x0001021400142241,0x0000021400142241,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x8041221400140000,0x8040221400140000,0x8041221400140000,0x8040221400140000,0x0000201400140200,0x0000201400140200,0x0000201400140200,0x0000201400140200,0x0001021400142240,0x0000021400142240,0x0001021400142240,0x0000021400142240,0x0001021400142241,0x0000021400142241,0x0001021400142241,0x0000021400142241,0x8041221400140000,0x8040221400140000,0x8041221400140000,0x8040221400140000,0x0001221400140000,0x0000221400140000,0x0001221400140000,0x0000221400140000,0x0041221400140000,0x0040221400140000,0x0041221400140000,0x0040221400140000,0x0001021400142240,0x0000021400142240,0x0001021400142240,0x0000021400142240,0x0041221400140201,0x0040221400140201,0x0041221400140201,0x0040221400140201,0x0001221400140000,0x0000221400140000,0x0001221400140000,0x0000221400140000,0x0041221400140000,0x0040221400140000,0x0041221400140000,0x0040221400140000,0x0001221400140000,0x0000221400140000,0x0001221400140000,0x0000221400140000,0x0041221400140200,0x0040221400140200,0x0041221400140200,0x0040221400140200,0x0001221400140201,0x0000221400140201,0x0001221400140201,0x0000221400140201,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0001221400140000,0x0000221400140000,0x0001221400140000,0x0000221400140000,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0001221400140200,0x0000221400140200,0x0001221400140200,0x0000221400140200,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0000001400140201,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0001021400142201,0x0000021400142201,0x0001021400142201,0x0000021400142201,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0000001400140200,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x0001021400142200,0x0000021400142200,0x0001021400142200,0x0000021400142200,0x0001021400142201,0x0000021400142201,0x0001021400142201,0x0000021400142201,0x8040201400140000,0x8040201400140000,0x8040201400140000,0x8040201400140000,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x8040201400140201,0x8040201400140201,0x8040201400140201,0x8040201400140201,0x0001021400142200,0x0000021400142200,0x0001021400142200,0x0000021400142200,0x8040201400140000,0x8040201400140000,0x8040201400140000,0x8040201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x8040201400140200,0x8040201400140200,0x8040201400140200,0x8040201400140200,0x0000201400140201,0x0000201400140201,0x0000201400140201,0x0000201400140201,0x0040201400140201,0x0040201400140201,0x0040201400140201,0x0040201400140201,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000201400140200,0x0000201400140200,0x0000201400140200,0x0000201400140200,0x0040201400140200,0x0040201400140200,0x0040201400140200,0x0040201400140200,0x0000201400140201,0x0000201400140201,0x0000201400140201,0x0000201400140201,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0041221400140000,0x0040221400140000,0x0041221400140000,0x0040221400140000,0x0000201400140200,0x0000201400140200,0x0000201400140200,0x0000201400140200,0x0041221400140201,0x0040221400140201,0x0041221400140201,0x0040221400140201,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0041221400140000,0x0040221400140000,0x0041221400140000,0x0040221400140000,0x0001221400140000,0x0000221400140000,0x0001221400140000,0x0000221400140000,0x0041221400140200,0x0040221400140200,0x0041221400140200,0x0040221400140200,0x0001221400140201,0x0000221400140201,0x0001221400140201,0x0000221400140201,0x0000001400142241,0x0000001400142241,0x0000001400142241,0x0000001400142241,0x0001221400140000,0x0000221400140000,0x0001221400140000,0x0000221400140000,0x8040201400140000,0x8040201400140000,0x8040201400140000,0x8040201400140000,0x0001221400140200,0x0000221400140200,0x0001221400140200,0x0000221400140200,0x0000001400142240,0x0000001400142240,0x0000001400142240,0x0000001400142240,0x0000001400142241,0x0000001400142241,0x0000001400142241,0x0000001400142241,0x8040201400140000,0x8040201400140000,0x8040201400140000,0x8040201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x0000001400142240,0x0000001400142240,0x0000001400142240,0x0000001400142240,0x0001021400142201,0x0000021400142201,0x0001021400142201,0x0000021400142201,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x0001021400142200,0x0000021400142200,0x0001021400142200,0x0000021400142200,0x0001021400142201,0x0000021400142201,0x0001021400142201,0x0000021400142201,0x0001021400142241,0x0000021400142241,0x0001021400142241,0x0000021400142241,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x0041221400140000,0x0040221400140000,0x0041221400140000,0x0040221400140000,0x0001021400142200,0x0000021400142200,0x0001021400142200,0x0000021400142200,0x0001021400142240,0x0000021400142240,0x0001021400142240,0x0000021400142240,0x0001021400142241,0x0000021400142241,0x0001021400142241,0x0000021400142241,0x0041221400140000,0x0040221400140000,0x0041221400140000,0x0040221400140000,0x0001221400140000,0x0000221400140000,0x0001221400140000,0x0000221400140000,0x0040201400140201,0x0040201400140201,0x0040201400140201,0x0040201400140201,0x0001021400142240,0x0000021400142240,0x0001021400142240,0x0000021400142240,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0001221400140000,0x0000221400140000,0x0001221400140000,0x0000221400140000,0x0040201400140200,0x0040201400140200,0x0040201400140200,0x0040201400140200,0x0000201400140201,0x0000201400140201,0x0000201400140201,0x0000201400140201,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0041221400142241,0x0040221400142241,0x0041221400142241,0x0040221400142241,0x0000201400140200,0x0000201400140200,0x0000201400140200,0x0000201400140200,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0000001400140000,0x0041221400142240,0x0040221400142240,0x0041221400142240,0x0040221400142240,0x0001221400142241,0x0000221400142241,0x0001221400142241,0x0000221400142241,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x0000001400142241,0x0000001400142241,0x0000001400142241,0x0000001400142241,0x0001221400142240,0x0000221400142240,0x0001221400142240,0x0000221400142240,0x8040201400140000,0x8040201400140000,0x8040201400140000,0x8040201400140000,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x0000001400142240,0x0000001400142240,0x0000001400142240,0x0000001400142240,0x0000001400142241,0x0000001400142241,0x0000001400142241,0x0000001400142241,0x8040201400140000,0x8040201400140000,0x8040201400140000,0x8040201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0040201400140000,0x0040201400140000,0x0040201400140000,0x0040201400140000,0x0000001400142240,0x0000001400142240,0x0000001400142240,0x0000001400142240,0x0040201400140201,0x0040201400140201,0x0040201400140201,0x0040201400140201,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0040201400140000,0x0040201400140000,0x0040201400140000,0x0040201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0040201400140200,0x0040201400140200,0x0040201400140200,0x0040201400140200,0x0000201400140201,0x0000201400140201,0x0000201400140201,0x0000201400140201,0x0001021400142241,0x0000021400142241,0x0001021400142241,0x0000021400142241,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0041221400140000,0x0040221400140000,0x0041221400140000,0x0040221400140000,0x0000201400140200,0x0000201400140200,0x0000201400140200,0x0000201400140200,0x0001021400142240,0x0000021400142240,0x0001021400142240,0x0000021400142240,0x0001021400142241,0x0000021400142241,0x0001021400142241,0x0000021400142241,0x0041221400140000,0x0040221400140000,0x0041221400140000,0x0040221400140000,0x0001221400140000,0x0000221400140000,0x0001221400140000,0x0000221400140000,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0001021400142240,0x0000021400142240,0x0001021400142240,0x0000021400142240,0x0000001400142201,0x0000001400142201,0x0000001400142201,0x0000001400142201,0x0001221400140000,0x0000221400140000,0x0001221400140000,0x0000221400140000,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142200,0x0000001400142200,0x0000001400142200,0x0000001400142200,0x0000001400142201,0x0000001400142201,0x0000001400142201,0x0000001400142201,0x0041221400142241,0x0040221400142241,0x0041221400142241,0x0040221400142241,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x0000001400142200,0x0000001400142200,0x0000001400142200,0x0000001400142200,0x0041221400142240,0x0040221400142240,0x0041221400142240,0x0040221400142240,0x0001221400142241,0x0000221400142241,0x0001221400142241,0x0000221400142241,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x0001221400142240,0x0000221400142240,0x0001221400142240,0x0000221400142240,0x0001021400142201,0x0000021400142201,0x0001021400142201,0x0000021400142201,0x0001021400142000,0x0000021400142000,0x0001021400142000,0x0000021400142000,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x0001021400142200,0x0000021400142200,0x0001021400142200,0x0000021400142200,0x0001021400142201,0x0000021400142201,0x0001021400142201,0x0000021400142201,0x0040201400140000,0x0040201400140000,0x0040201400140000,0x0040201400140000,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x0040201400140201,0x0040201400140201,0x0040201400140201,0x0040201400140201,0x0001021400142200,0x0000021400142200,0x0001021400142200,0x0000021400142200,0x0040201400140000,0x0040201400140000,0x0040201400140000,0x0040201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0040201400140200,0x0040201400140200,0x0040201400140200,0x0040201400140200,0x0000201400140201,0x0000201400140201,0x0000201400140201,0x0000201400140201,0x0041221400142040,0x0040221400142040,0x0041221400142040,0x0040221400142040,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0041221400142201,0x0040221400142201,0x0041221400142201,0x0040221400142201,0x0000201400140200,0x0000201400140200,0x0000201400140200,0x0000201400140200,0x0041221400142040,0x0040221400142040,0x0041221400142040,0x0040221400142040,0x0001221400142040,0x0000221400142040,0x0001221400142040,0x0000221400142040,0x0041221400142200,0x0040221400142200,0x0041221400142200,0x0040221400142200,0x0001221400142201,0x0000221400142201,0x0001221400142201,0x0000221400142201,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0001221400142040,0x0000221400142040,0x0001221400142040,0x0000221400142040,0x0000001400142201,0x0000001400142201,0x0000001400142201,0x0000001400142201,0x0001221400142200,0x0000221400142200,0x0001221400142200,0x0000221400142200,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142200,0x0000001400142200,0x0000001400142200,0x0000001400142200,0x0000001400142201,0x0000001400142201,0x0000001400142201,0x0000001400142201,0x0000001400142241,0x0000001400142241,0x0000001400142241,0x0000001400142241,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0000001400142040,0x0040201400140000,0x0040201400140000,0x0040201400140000,0x0040201400140000,0x0000001400142200,0x0000001400142200,0x0000001400142200,0x0000001400142200,0x0000001400142240,0x0000001400142240,0x0000001400142240,0x0000001400142240,0x0000001400142241,0x0000001400142241,0x0000001400142241,0x0000001400142241,0x0040201400140000,0x0040201400140000,0x0040201400140000,0x0040201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x0000001400142240,0x0000001400142240,0x0000001400142240,0x0000001400142240,0x0001021400142201,0x0000021400142201,0x0001021400142201,0x0000021400142201,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0000201400140000,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x0001021400142200,0x0000021400142200,0x0001021400142200,0x0000021400142200,0x0001021400142201,0x0000021400142201,0x0001021400142201,0x0000021400142201,0x0040201400142241,0x0040201400142241,0x0040201400142241,0x0040201400142241,0x0001021400142040,0x0000021400142040,0x0001021400142040,0x0000021400142040,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0001021400142200,0x0000021400142200,0x0001021400142200,0x0000021400142200,0x0040201400142240,0x0040201400142240,0x0040201400142240,0x0040201400142240,0x0000201400142241,0x0000201400142241,0x0000201400142241,0x0000201400142241,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0041221400142040,0x0040221400142040,0x0041221400142040,0x0040221400142040,0x0000201400142240,0x0000201400142240,0x0000201400142240,0x0000201400142240,0x0041221400142201,0x0040221400142201,0x0041221400142201,0x0040221400142201,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0000001400142000,0x0041221400142040,0x0040221400142040,0x0041221400142040,0x0040221400142040,0x0001221400142040,0x0000221400142040,0x0001221400142040,0x0000221400142040,0x0041221400142200,0x0040221400142200,0x0041221400142200,0x0040221400142200,0x0001221400142201,0x0000221400142201,0x0001221400142201,0x0000221400142201,];
-const ATTACK_TABLE_28: [Bitboard; 512] = [0x0182442800284482,0x0080442800284482,0x0082442800280000,0x0080442800280000,0x0182442800284480,0x0080442800284480,0x0082442800280000,0x0080442800280000,0x0080402800284482,0x0080402800284482,0x0080402800280000,0x0080402800280000,0x0080402800284480,0x0080402800284480,0x0080402800280000,0x0080402800280000,0x0102442800284482,0x0000442800284482,0x0002442800280000,0x0000442800280000,0x0102442800284480,0x0000442800284480,0x0002442800280000,0x0000442800280000,0x0000402800284482,0x0000402800284482,0x0000402800280000,0x0000402800280000,0x0000402800284480,0x0000402800284480,0x0000402800280000,0x0000402800280000,0x0102042800284482,0x0000042800284482,0x0002042800280000,0x0000042800280000,0x0102042800284480,0x0000042800284480,0x0002042800280000,0x0000042800280000,0x0000002800284482,0x0000002800284482,0x0000002800280000,0x0000002800280000,0x0000002800284480,0x0000002800284480,0x0000002800280000,0x0000002800280000,0x0102042800284482,0x0000042800284482,0x0002042800280000,0x0000042800280000,0x0102042800284480,0x0000042800284480,0x0002042800280000,0x0000042800280000,0x0000002800284482,0x0000002800284482,0x0000002800280000,0x0000002800280000,0x0000002800284480,0x0000002800284480,0x0000002800280000,0x0000002800280000,0x0182442800284080,0x0080442800284080,0x0182442800284402,0x0080442800284402,0x0182442800284080,0x0080442800284080,0x0182442800284400,0x0080442800284400,0x0080402800284080,0x0080402800284080,0x0080402800284402,0x0080402800284402,0x0080402800284080,0x0080402800284080,0x0080402800284400,0x0080402800284400,0x0102442800284080,0x0000442800284080,0x0102442800284402,0x0000442800284402,0x0102442800284080,0x0000442800284080,0x0102442800284400,0x0000442800284400,0x0000402800284080,0x0000402800284080,0x0000402800284402,0x0000402800284402,0x0000402800284080,0x0000402800284080,0x0000402800284400,0x0000402800284400,0x0102042800284080,0x0000042800284080,0x0102042800284402,0x0000042800284402,0x0102042800284080,0x0000042800284080,0x0102042800284400,0x0000042800284400,0x0000002800284080,0x0000002800284080,0x0000002800284402,0x0000002800284402,0x0000002800284080,0x0000002800284080,0x0000002800284400,0x0000002800284400,0x0102042800284080,0x0000042800284080,0x0102042800284402,0x0000042800284402,0x0102042800284080,0x0000042800284080,0x0102042800284400,0x0000042800284400,0x0000002800284080,0x0000002800284080,0x0000002800284402,0x0000002800284402,0x0000002800284080,0x0000002800284080,0x0000002800284400,0x0000002800284400,0x0082442800284482,0x0080442800284482,0x0182442800284000,0x0080442800284000,0x0082442800284480,0x0080442800284480,0x0182442800284000,0x0080442800284000,0x0080402800284482,0x0080402800284482,0x0080402800284000,0x0080402800284000,0x0080402800284480,0x0080402800284480,0x0080402800284000,0x0080402800284000,0x0002442800284482,0x0000442800284482,0x0102442800284000,0x0000442800284000,0x0002442800284480,0x0000442800284480,0x0102442800284000,0x0000442800284000,0x0000402800284482,0x0000402800284482,0x0000402800284000,0x0000402800284000,0x0000402800284480,0x0000402800284480,0x0000402800284000,0x0000402800284000,0x0002042800284482,0x0000042800284482,0x0102042800284000,0x0000042800284000,0x0002042800284480,0x0000042800284480,0x0102042800284000,0x0000042800284000,0x0000002800284482,0x0000002800284482,0x0000002800284000,0x0000002800284000,0x0000002800284480,0x0000002800284480,0x0000002800284000,0x0000002800284000,0x0002042800284482,0x0000042800284482,0x0102042800284000,0x0000042800284000,0x0002042800284480,0x0000042800284480,0x0102042800284000,0x0000042800284000,0x0000002800284482,0x0000002800284482,0x0000002800284000,0x0000002800284000,0x0000002800284480,0x0000002800284480,0x0000002800284000,0x0000002800284000,0x0082442800284080,0x0080442800284080,0x0082442800284402,0x0080442800284402,0x0082442800284080,0x0080442800284080,0x0082442800284400,0x0080442800284400,0x0080402800284080,0x0080402800284080,0x0080402800284402,0x0080402800284402,0x0080402800284080,0x0080402800284080,0x0080402800284400,0x0080402800284400,0x0002442800284080,0x0000442800284080,0x0002442800284402,0x0000442800284402,0x0002442800284080,0x0000442800284080,0x0002442800284400,0x0000442800284400,0x0000402800284080,0x0000402800284080,0x0000402800284402,0x0000402800284402,0x0000402800284080,0x0000402800284080,0x0000402800284400,0x0000402800284400,0x0002042800284080,0x0000042800284080,0x0002042800284402,0x0000042800284402,0x0002042800284080,0x0000042800284080,0x0002042800284400,0x0000042800284400,0x0000002800284080,0x0000002800284080,0x0000002800284402,0x0000002800284402,0x0000002800284080,0x0000002800284080,0x0000002800284400,0x0000002800284400,0x0002042800284080,0x0000042800284080,0x0002042800284402,0x0000042800284402,0x0002042800284080,0x0000042800284080,0x0002042800284400,0x0000042800284400,0x0000002800284080,0x0000002800284080,0x0000002800284402,0x0000002800284402,0x0000002800284080,0x0000002800284080,0x0000002800284400,0x0000002800284400,0x0182442800280402,0x0080442800280402,0x0082442800284000,0x0080442800284000,0x0182442800280400,0x0080442800280400,0x0082442800284000,0x0080442800284000,0x0080402800280402,0x0080402800280402,0x0080402800284000,0x0080402800284000,0x0080402800280400,0x0080402800280400,0x0080402800284000,0x0080402800284000,0x0102442800280402,0x0000442800280402,0x0002442800284000,0x0000442800284000,0x0102442800280400,0x0000442800280400,0x0002442800284000,0x0000442800284000,0x0000402800280402,0x0000402800280402,0x0000402800284000,0x0000402800284000,0x0000402800280400,0x0000402800280400,0x0000402800284000,0x0000402800284000,0x0102042800280402,0x0000042800280402,0x0002042800284000,0x0000042800284000,0x0102042800280400,0x0000042800280400,0x0002042800284000,0x0000042800284000,0x0000002800280402,0x0000002800280402,0x0000002800284000,0x0000002800284000,0x0000002800280400,0x0000002800280400,0x0000002800284000,0x0000002800284000,0x0102042800280402,0x0000042800280402,0x0002042800284000,0x0000042800284000,0x0102042800280400,0x0000042800280400,0x0002042800284000,0x0000042800284000,0x0000002800280402,0x0000002800280402,0x0000002800284000,0x0000002800284000,0x0000002800280400,0x0000002800280400,0x0000002800284000,0x0000002800284000,0x0182442800280000,0x0080442800280000,0x0182442800280402,0x0080442800280402,0x0182442800280000,0x0080442800280000,0x0182442800280400,0x0080442800280400,0x0080402800280000,0x0080402800280000,0x0080402800280402,0x0080402800280402,0x0080402800280000,0x0080402800280000,0x0080402800280400,0x0080402800280400,0x0102442800280000,0x0000442800280000,0x0102442800280402,0x0000442800280402,0x0102442800280000,0x0000442800280000,0x0102442800280400,0x0000442800280400,0x0000402800280000,0x0000402800280000,0x0000402800280402,0x0000402800280402,0x0000402800280000,0x0000402800280000,0x0000402800280400,0x0000402800280400,0x0102042800280000,0x0000042800280000,0x0102042800280402,0x0000042800280402,0x0102042800280000,0x0000042800280000,0x0102042800280400,0x0000042800280400,0x0000002800280000,0x0000002800280000,0x0000002800280402,0x0000002800280402,0x0000002800280000,0x0000002800280000,0x0000002800280400,0x0000002800280400,0x0102042800280000,0x0000042800280000,0x0102042800280402,0x0000042800280402,0x0102042800280000,0x0000042800280000,0x0102042800280400,0x0000042800280400,0x0000002800280000,0x0000002800280000,0x0000002800280402,0x0000002800280402,0x0000002800280000,0x0000002800280000,0x0000002800280400,0x0000002800280400,0x0082442800280402,0x0080442800280402,0x0182442800280000,0x0080442800280000,0x0082442800280400,0x0080442800280400,0x0182442800280000,0x0080442800280000,0x0080402800280402,0x0080402800280402,0x0080402800280000,0x0080402800280000,0x0080402800280400,0x0080402800280400,0x0080402800280000,0x0080402800280000,0x0002442800280402,0x0000442800280402,0x0102442800280000,0x0000442800280000,0x0002442800280400,0x0000442800280400,0x0102442800280000,0x0000442800280000,0x0000402800280402,0x0000402800280402,0x0000402800280000,0x0000402800280000,0x0000402800280400,0x0000402800280400,0x0000402800280000,0x0000402800280000,0x0002042800280402,0x0000042800280402,0x0102042800280000,0x0000042800280000,0x0002042800280400,0x0000042800280400,0x0102042800280000,0x0000042800280000,0x0000002800280402,0x0000002800280402,0x0000002800280000,0x0000002800280000,0x0000002800280400,0x0000002800280400,0x0000002800280000,0x0000002800280000,0x0002042800280402,0x0000042800280402,0x0102042800280000,0x0000042800280000,0x0002042800280400,0x0000042800280400,0x0102042800280000,0x0000042800280000,0x0000002800280402,0x0000002800280402,0x0000002800280000,0x0000002800280000,0x0000002800280400,0x0000002800280400,0x0000002800280000,0x0000002800280000,0x0082442800280000,0x0080442800280000,0x0082442800280402,0x0080442800280402,0x0082442800280000,0x0080442800280000,0x0082442800280400,0x0080442800280400,0x0080402800280000,0x0080402800280000,0x0080402800280402,0x0080402800280402,0x0080402800280000,0x0080402800280000,0x0080402800280400,0x0080402800280400,0x0002442800280000,0x0000442800280000,0x0002442800280402,0x0000442800280402,0x0002442800280000,0x0000442800280000,0x0002442800280400,0x0000442800280400,0x0000402800280000,0x0000402800280000,0x0000402800280402,0x0000402800280402,0x0000402800280000,0x0000402800280000,0x0000402800280400,0x0000402800280400,0x0002042800280000,0x0000042800280000,0x0002042800280402,0x0000042800280402,0x0002042800280000,0x0000042800280000,0x0002042800280400,0x0000042800280400,0x0000002800280000,0x0000002800280000,0x0000002800280402,0x0000002800280402,0x0000002800280000,0x0000002800280000,0x0000002800280400,0x0000002800280400,0x0002042800280000,0x0000042800280000,0x0002042800280402,0x0000042800280402,0x0002042800280000,0x0000042800280000,0x0002042800280400,0x0000042800280400,0x0000002800280000,0x0000002800280000,0x0000002800280402,0x0000002800280402,0x0000002800280000,0x0000002800280000,0x0000002800280400,0x0000002800280400,];
-const ATTACK_TABLE_29: [Bitboard; 256] = [0x0204885000508804,0x0000005000500804,0x0004885000508804,0x0000805000508800,0x0000085000500000,0x0000805000508800,0x0000085000500000,0x0000005000500000,0x0204885000508000,0x0000005000500000,0x0004885000508000,0x0000805000508000,0x0204085000508804,0x0000805000508000,0x0004085000508804,0x0000005000508800,0x0204885000508804,0x0000005000508800,0x0004885000508804,0x0000805000508800,0x0204085000508000,0x0000805000508800,0x0004085000508000,0x0000005000508000,0x0204885000508000,0x0000005000508000,0x0004885000508000,0x0000805000508000,0x0204085000508804,0x0000805000508000,0x0004085000508804,0x0000005000508800,0x0204885000500804,0x0000005000508800,0x0004885000500804,0x0000805000500800,0x0204085000508000,0x0000805000500800,0x0004085000508000,0x0000005000508000,0x0204885000500000,0x0000005000508000,0x0004885000500000,0x0000805000500000,0x0204085000500804,0x0000805000500000,0x0004085000500804,0x0000005000500800,0x0204885000500804,0x0000005000500800,0x0004885000500804,0x0000805000500800,0x0204085000500000,0x0000805000500800,0x0004085000500000,0x0000005000500000,0x0204885000500000,0x0000005000500000,0x0004885000500000,0x0000805000500000,0x0204085000500804,0x0000805000500000,0x0004085000500804,0x0000005000500800,0x0204885000508800,0x0000005000500800,0x0004885000508800,0x0000805000508804,0x0204085000500000,0x0000805000508804,0x0004085000500000,0x0000005000500000,0x0204885000508000,0x0000005000500000,0x0004885000508000,0x0000805000508000,0x0204085000508800,0x0000805000508000,0x0004085000508800,0x0000005000508804,0x0204885000508800,0x0000005000508804,0x0004885000508800,0x0000805000508804,0x0204085000508000,0x0000805000508804,0x0004085000508000,0x0000005000508000,0x0204885000508000,0x0000005000508000,0x0004885000508000,0x0000805000508000,0x0204085000508800,0x0000805000508000,0x0004085000508800,0x0000005000508804,0x0204885000500800,0x0000005000508804,0x0004885000500800,0x0000805000500804,0x0204085000508000,0x0000805000500804,0x0004085000508000,0x0000005000508000,0x0204885000500000,0x0000005000508000,0x0004885000500000,0x0000805000500000,0x0204085000500800,0x0000805000500000,0x0004085000500800,0x0000005000500804,0x0204885000500800,0x0000005000500804,0x0004885000500800,0x0000805000500804,0x0204085000500000,0x0000805000500804,0x0004085000500000,0x0000005000500000,0x0204885000500000,0x0000005000500000,0x0004885000500000,0x0000805000500000,0x0204085000500800,0x0000805000500000,0x0004085000500800,0x0000005000500804,0x0000885000508804,0x0000005000500804,0x0000885000508804,0x0000805000508800,0x0204085000500000,0x0000805000508800,0x0004085000500000,0x0000005000500000,0x0000885000508000,0x0000005000500000,0x0000885000508000,0x0000805000508000,0x0000085000508804,0x0000805000508000,0x0000085000508804,0x0000005000508800,0x0000885000508804,0x0000005000508800,0x0000885000508804,0x0000805000508800,0x0000085000508000,0x0000805000508800,0x0000085000508000,0x0000005000508000,0x0000885000508000,0x0000005000508000,0x0000885000508000,0x0000805000508000,0x0000085000508804,0x0000805000508000,0x0000085000508804,0x0000005000508800,0x0000885000500804,0x0000005000508800,0x0000885000500804,0x0000805000500800,0x0000085000508000,0x0000805000500800,0x0000085000508000,0x0000005000508000,0x0000885000500000,0x0000005000508000,0x0000885000500000,0x0000805000500000,0x0000085000500804,0x0000805000500000,0x0000085000500804,0x0000005000500800,0x0000885000500804,0x0000005000500800,0x0000885000500804,0x0000805000500800,0x0000085000500000,0x0000805000500800,0x0000085000500000,0x0000005000500000,0x0000885000500000,0x0000005000500000,0x0000885000500000,0x0000805000500000,0x0000085000500804,0x0000805000500000,0x0000085000500804,0x0000005000500800,0x0000885000508800,0x0000005000500800,0x0000885000508800,0x0000805000508804,0x0000085000500000,0x0000805000508804,0x0000085000500000,0x0000005000500000,0x0000885000508000,0x0000005000500000,0x0000885000508000,0x0000805000508000,0x0000085000508800,0x0000805000508000,0x0000085000508800,0x0000005000508804,0x0000885000508800,0x0000005000508804,0x0000885000508800,0x0000805000508804,0x0000085000508000,0x0000805000508804,0x0000085000508000,0x0000005000508000,0x0000885000508000,0x0000005000508000,0x0000885000508000,0x0000805000508000,0x0000085000508800,0x0000805000508000,0x0000085000508800,0x0000005000508804,0x0000885000500800,0x0000005000508804,0x0000885000500800,0x0000805000500804,0x0000085000508000,0x0000805000500804,0x0000085000508000,0x0000005000508000,0x0000885000500000,0x0000005000508000,0x0000885000500000,0x0000805000500000,0x0000085000500800,0x0000805000500000,0x0000085000500800,0x0000005000500804,0x0000885000500800,0x0000005000500804,0x0000885000500800,0x0000805000500804,0x0000085000500000,0x0000805000500804,0x0000085000500000,0x0000005000500000,0x0000885000500000,0x0000005000500000,0x0000885000500000,0x0000805000500000,0x0000085000500800,0x0000805000500000,0x0000085000500800,0x0000005000500804,];
-const ATTACK_TABLE_30: [Bitboard; 32] = [0x040810A000A01008,0x040810A000A01000,0x000000A000A01008,0x000000A000A01000,0x000010A000A01008,0x000010A000A01000,0x000000A000A01008,0x000000A000A01000,0x000000A000A00000,0x000000A000A00000,0x000810A000A00000,0x000810A000A00000,0x000000A000A00000,0x000000A000A00000,0x000010A000A00000,0x000010A000A00000,0x000000A000A01008,0x000000A000A01000,0x000810A000A01008,0x000810A000A01000,0x000000A000A01008,0x000000A000A01000,0x000010A000A01008,0x000010A000A01000,0x040810A000A00000,0x040810A000A00000,0x000000A000A00000,0x000000A000A00000,0x000010A000A00000,0x000010A000A00000,0x000000A000A00000,0x000000A000A00000,];
-const ATTACK_TABLE_31: [Bitboard; 32] = [0x0810204000402010,0x0000204000402010,0x0010204000400000,0x0000204000400000,0x0000004000402000,0x0000004000402000,0x0000004000400000,0x0000004000400000,0x0000004000402010,0x0000004000402010,0x0000004000400000,0x0000004000400000,0x0810204000402000,0x0000204000402000,0x0010204000400000,0x0000204000400000,0x0010204000402010,0x0000204000402010,0x0810204000400000,0x0000204000400000,0x0000004000402000,0x0000004000402000,0x0000004000400000,0x0000004000400000,0x0000004000402010,0x0000004000402010,0x0000004000400000,0x0000004000400000,0x0010204000402000,0x0000204000402000,0x0810204000400000,0x0000204000400000,];
-const ATTACK_TABLE_32: [Bitboard; 32] = [0x0804020002040810,0x0804020002040000,0x0004020002000000,0x0004020002000000,0x0000020002040810,0x0000020002040000,0x0000020002000000,0x0000020002000000,0x0804020002000000,0x0804020002000000,0x0004020002040810,0x0004020002040000,0x0000020002000000,0x0000020002000000,0x0000020002040810,0x0000020002040000,0x0804020002040800,0x0804020002040000,0x0004020002000000,0x0004020002000000,0x0000020002040800,0x0000020002040000,0x0000020002000000,0x0000020002000000,0x0804020002000000,0x0804020002000000,0x0004020002040800,0x0004020002040000,0x0000020002000000,0x0000020002000000,0x0000020002040800,0x0000020002040000,];
-const ATTACK_TABLE_33: [Bitboard; 64] = [0x1008050005081020,0x1008050005000000,0x1008050005080000,0x1008050005000000,0x1008050005081020,0x1008050005000000,0x0008050005080000,0x0008050005000000,0x0008050005081000,0x0008050005000000,0x0008050005080000,0x0008050005000000,0x0008050005081000,0x0008050005000000,0x0000050005080000,0x0000050005000000,0x0000050005081020,0x0000050005000000,0x0000050005080000,0x0000050005000000,0x0000050005081020,0x0000050005000000,0x0000050005080000,0x0000050005000000,0x0000050005081000,0x0000050005000000,0x0000050005080000,0x0000050005000000,0x0000050005081000,0x0000050005000000,0x1008050005080000,0x1008050005000000,0x1008050005081000,0x1008050005000000,0x1008050005080000,0x1008050005000000,0x1008050005081000,0x1008050005000000,0x0008050005080000,0x0008050005000000,0x0008050005081020,0x0008050005000000,0x0008050005080000,0x0008050005000000,0x0008050005081020,0x0008050005000000,0x0000050005080000,0x0000050005000000,0x0000050005081000,0x0000050005000000,0x0000050005080000,0x0000050005000000,0x0000050005081000,0x0000050005000000,0x0000050005080000,0x0000050005000000,0x0000050005081020,0x0000050005000000,0x0000050005080000,0x0000050005000000,0x0000050005081020,0x0000050005000000,0x1008050005080000,0x1008050005000000,];
-const ATTACK_TABLE_34: [Bitboard; 256] = [0x20110A000A112040,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x00100A000A000000,0x20110A000A110000,0x00110A000A112040,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x20110A000A010000,0x00110A000A110000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x20110A000A010000,0x00110A000A010000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x00110A000A010000,0x00000A000A102040,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x00000A000A100000,0x00000A000A102040,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x00000A000A000000,0x00000A000A100000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x00000A000A000000,0x00000A000A000000,0x20110A000A112000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x00000A000A000000,0x20110A000A110000,0x00110A000A112000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x20110A000A010000,0x00110A000A110000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x20110A000A010000,0x00110A000A010000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x00110A000A010000,0x00000A000A102000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x00000A000A100000,0x00000A000A102000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x00000A000A000000,0x00000A000A100000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x00000A000A000000,0x00000A000A000000,0x00010A000A112040,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x00000A000A000000,0x00010A000A110000,0x00010A000A112040,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x00010A000A010000,0x00010A000A110000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x00010A000A010000,0x00010A000A010000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x20100A000A112040,0x00010A000A010000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x20100A000A110000,0x00100A000A112040,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x20100A000A010000,0x00100A000A110000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x20100A000A010000,0x00100A000A010000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x00010A000A112000,0x00100A000A010000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x00010A000A110000,0x00010A000A112000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x00010A000A010000,0x00010A000A110000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x00010A000A010000,0x00010A000A010000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x20100A000A112000,0x00010A000A010000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x20100A000A110000,0x00100A000A112000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x20100A000A010000,0x00100A000A110000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x20100A000A010000,0x00100A000A010000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x00100A000A010000,0x20110A000A102040,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x20110A000A100000,0x00110A000A102040,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x20110A000A000000,0x00110A000A100000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x20110A000A000000,0x00110A000A000000,0x00000A000A112040,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x00110A000A000000,0x00000A000A110000,0x00000A000A112040,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x00000A000A010000,0x00000A000A110000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x00000A000A010000,0x00000A000A010000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x00000A000A010000,0x20110A000A102000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x20110A000A100000,0x00110A000A102000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x20110A000A000000,0x00110A000A100000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x20110A000A000000,0x00110A000A000000,0x00000A000A112000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x00110A000A000000,0x00000A000A110000,0x00000A000A112000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x00000A000A010000,0x00000A000A110000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x00000A000A010000,0x00000A000A010000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x00000A000A010000,0x00010A000A102040,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x00010A000A100000,0x00010A000A102040,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x00010A000A000000,0x00010A000A100000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x00010A000A000000,0x00010A000A000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x20100A000A102040,0x00010A000A000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x20100A000A100000,0x00100A000A102040,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x20100A000A000000,0x00100A000A100000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x20100A000A000000,0x00100A000A000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x00010A000A102000,0x00100A000A000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x00010A000A100000,0x00010A000A102000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x00010A000A000000,0x00010A000A100000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x00010A000A000000,0x00010A000A000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x20100A000A102000,0x00010A000A000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x20100A000A100000,0x00100A000A102000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x20100A000A000000,0x00100A000A100000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x20100A000A000000,0x00100A000A000000,];
-const ATTACK_TABLE_35: [Bitboard; 1024] = [0x4122140014224180,0x0102140014224180,0xDEADBEEFDEADBEEF,0x4122140014224100,0x0102140014224100,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4022140014200000,0x0002140014200000,0xDEADBEEFDEADBEEF,0x4022140014200000,0x0002140014200000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4020140014220100,0x0000140014220100,0xDEADBEEFDEADBEEF,0x4020140014220100,0x0000140014220100,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4122140014224080,0x0102140014224080,0xDEADBEEFDEADBEEF,0x4122140014224000,0x0102140014224000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4022140014224180,0x0002140014224180,0xDEADBEEFDEADBEEF,0x4022140014224100,0x0002140014224100,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4020140014220000,0x0000140014220000,0xDEADBEEFDEADBEEF,0x4020140014220000,0x0000140014220000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4020140014220100,0x0000140014220100,0xDEADBEEFDEADBEEF,0x4020140014220100,0x0000140014220100,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4022140014224080,0x0002140014224080,0xDEADBEEFDEADBEEF,0x4022140014224000,0x0002140014224000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0122140014204080,0x0102140014204080,0xDEADBEEFDEADBEEF,0x0122140014204000,0x0102140014204000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4020140014220000,0x0000140014220000,0xDEADBEEFDEADBEEF,0x4020140014220000,0x0000140014220000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0020140014200000,0x0000140014200000,0xDEADBEEFDEADBEEF,0x0020140014200000,0x0000140014200000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0122140014204080,0x0102140014204080,0xDEADBEEFDEADBEEF,0x0122140014204000,0x0102140014204000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0022140014204080,0x0002140014204080,0xDEADBEEFDEADBEEF,0x0022140014204000,0x0002140014204000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0020140014200000,0x0000140014200000,0xDEADBEEFDEADBEEF,0x0020140014200000,0x0000140014200000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0020140014200000,0x0000140014200000,0xDEADBEEFDEADBEEF,0x0020140014200000,0x0000140014200000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0022140014204080,0x0002140014204080,0xDEADBEEFDEADBEEF,0x0022140014204000,0x0002140014204000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0020140014224180,0x0000140014224180,0xDEADBEEFDEADBEEF,0x0020140014224100,0x0000140014224100,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0020140014200000,0x0000140014200000,0xDEADBEEFDEADBEEF,0x0020140014200000,0x0000140014200000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0122140014020100,0x0102140014020100,0xDEADBEEFDEADBEEF,0x0122140014020100,0x0102140014020100,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0020140014224080,0x0000140014224080,0xDEADBEEFDEADBEEF,0x0020140014224000,0x0000140014224000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0020140014224180,0x0000140014224180,0xDEADBEEFDEADBEEF,0x0020140014224100,0x0000140014224100,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0122140014020000,0x0102140014020000,0xDEADBEEFDEADBEEF,0x0122140014020000,0x0102140014020000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0022140014020100,0x0002140014020100,0xDEADBEEFDEADBEEF,0x0022140014020100,0x0002140014020100,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0020140014224080,0x0000140014224080,0xDEADBEEFDEADBEEF,0x0020140014224000,0x0000140014224000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4020140014204080,0x0000140014204080,0xDEADBEEFDEADBEEF,0x4020140014204000,0x0000140014204000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0022140014020000,0x0002140014020000,0xDEADBEEFDEADBEEF,0x0022140014020000,0x0002140014020000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4122140014000000,0x0102140014000000,0xDEADBEEFDEADBEEF,0x4122140014000000,0x0102140014000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4020140014204080,0x0000140014204080,0xDEADBEEFDEADBEEF,0x4020140014204000,0x0000140014204000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4020140014204080,0x0000140014204080,0xDEADBEEFDEADBEEF,0x4020140014204000,0x0000140014204000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4122140014000000,0x0102140014000000,0xDEADBEEFDEADBEEF,0x4122140014000000,0x0102140014000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4022140014000000,0x0002140014000000,0xDEADBEEFDEADBEEF,0x4022140014000000,0x0002140014000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4020140014204080,0x0000140014204080,0xDEADBEEFDEADBEEF,0x4020140014204000,0x0000140014204000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4122140014020100,0x0102140014020100,0xDEADBEEFDEADBEEF,0x4122140014020100,0x0102140014020100,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4022140014000000,0x0002140014000000,0xDEADBEEFDEADBEEF,0x4022140014000000,0x0002140014000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4020140014020100,0x0000140014020100,0xDEADBEEFDEADBEEF,0x4020140014020100,0x0000140014020100,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4122140014020000,0x0102140014020000,0xDEADBEEFDEADBEEF,0x4122140014020000,0x0102140014020000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4022140014020100,0x0002140014020100,0xDEADBEEFDEADBEEF,0x4022140014020100,0x0002140014020100,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4020140014020000,0x0000140014020000,0xDEADBEEFDEADBEEF,0x4020140014020000,0x0000140014020000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4020140014020100,0x0000140014020100,0xDEADBEEFDEADBEEF,0x4020140014020100,0x0000140014020100,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4022140014020000,0x0002140014020000,0xDEADBEEFDEADBEEF,0x4022140014020000,0x0002140014020000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0122140014000000,0x0102140014000000,0xDEADBEEFDEADBEEF,0x0122140014000000,0x0102140014000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4020140014020000,0x0000140014020000,0xDEADBEEFDEADBEEF,0x4020140014020000,0x0000140014020000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0020140014000000,0x0000140014000000,0xDEADBEEFDEADBEEF,0x0020140014000000,0x0000140014000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0122140014000000,0x0102140014000000,0xDEADBEEFDEADBEEF,0x0122140014000000,0x0102140014000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0022140014000000,0x0002140014000000,0xDEADBEEFDEADBEEF,0x0022140014000000,0x0002140014000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0020140014000000,0x0000140014000000,0xDEADBEEFDEADBEEF,0x0020140014000000,0x0000140014000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0020140014000000,0x0000140014000000,0xDEADBEEFDEADBEEF,0x0020140014000000,0x0000140014000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0022140014000000,0x0002140014000000,0xDEADBEEFDEADBEEF,0x0022140014000000,0x0002140014000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0020140014020100,0x0000140014020100,0xDEADBEEFDEADBEEF,0x0020140014020100,0x0000140014020100,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0020140014000000,0x0000140014000000,0xDEADBEEFDEADBEEF,0x0020140014000000,0x0000140014000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4122140014220100,0x0102140014220100,0xDEADBEEFDEADBEEF,0x4122140014220100,0x0102140014220100,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0020140014020000,0x0000140014020000,0xDEADBEEFDEADBEEF,0x0020140014020000,0x0000140014020000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0020140014020100,0x0000140014020100,0xDEADBEEFDEADBEEF,0x0020140014020100,0x0000140014020100,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4122140014220000,0x0102140014220000,0xDEADBEEFDEADBEEF,0x4122140014220000,0x0102140014220000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4022140014220100,0x0002140014220100,0xDEADBEEFDEADBEEF,0x4022140014220100,0x0002140014220100,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0020140014020000,0x0000140014020000,0xDEADBEEFDEADBEEF,0x0020140014020000,0x0000140014020000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4020140014000000,0x0000140014000000,0xDEADBEEFDEADBEEF,0x4020140014000000,0x0000140014000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4022140014220000,0x0002140014220000,0xDEADBEEFDEADBEEF,0x4022140014220000,0x0002140014220000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0122140014200000,0x0102140014200000,0xDEADBEEFDEADBEEF,0x0122140014200000,0x0102140014200000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4020140014000000,0x0000140014000000,0xDEADBEEFDEADBEEF,0x4020140014000000,0x0000140014000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4020140014000000,0x0000140014000000,0xDEADBEEFDEADBEEF,0x4020140014000000,0x0000140014000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0122140014200000,0x0102140014200000,0xDEADBEEFDEADBEEF,0x0122140014200000,0x0102140014200000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0022140014200000,0x0002140014200000,0xDEADBEEFDEADBEEF,0x0022140014200000,0x0002140014200000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4020140014000000,0x0000140014000000,0xDEADBEEFDEADBEEF,0x4020140014000000,0x0000140014000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0122140014224180,0x0102140014224180,0xDEADBEEFDEADBEEF,0x0122140014224100,0x0102140014224100,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0022140014200000,0x0002140014200000,0xDEADBEEFDEADBEEF,0x0022140014200000,0x0002140014200000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0020140014220100,0x0000140014220100,0xDEADBEEFDEADBEEF,0x0020140014220100,0x0000140014220100,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0122140014224080,0x0102140014224080,0xDEADBEEFDEADBEEF,0x0122140014224000,0x0102140014224000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0022140014224180,0x0002140014224180,0xDEADBEEFDEADBEEF,0x0022140014224100,0x0002140014224100,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0020140014220000,0x0000140014220000,0xDEADBEEFDEADBEEF,0x0020140014220000,0x0000140014220000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0020140014220100,0x0000140014220100,0xDEADBEEFDEADBEEF,0x0020140014220100,0x0000140014220100,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0022140014224080,0x0002140014224080,0xDEADBEEFDEADBEEF,0x0022140014224000,0x0002140014224000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4122140014204080,0x0102140014204080,0xDEADBEEFDEADBEEF,0x4122140014204000,0x0102140014204000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0020140014220000,0x0000140014220000,0xDEADBEEFDEADBEEF,0x0020140014220000,0x0000140014220000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4020140014200000,0x0000140014200000,0xDEADBEEFDEADBEEF,0x4020140014200000,0x0000140014200000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4122140014204080,0x0102140014204080,0xDEADBEEFDEADBEEF,0x4122140014204000,0x0102140014204000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4022140014204080,0x0002140014204080,0xDEADBEEFDEADBEEF,0x4022140014204000,0x0002140014204000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4020140014200000,0x0000140014200000,0xDEADBEEFDEADBEEF,0x4020140014200000,0x0000140014200000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4020140014200000,0x0000140014200000,0xDEADBEEFDEADBEEF,0x4020140014200000,0x0000140014200000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4022140014204080,0x0002140014204080,0xDEADBEEFDEADBEEF,0x4022140014204000,0x0002140014204000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4020140014224180,0x0000140014224180,0xDEADBEEFDEADBEEF,0x4020140014224100,0x0000140014224100,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4020140014200000,0x0000140014200000,0xDEADBEEFDEADBEEF,0x4020140014200000,0x0000140014200000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4122140014020100,0x0102140014020100,0xDEADBEEFDEADBEEF,0x4122140014020100,0x0102140014020100,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4020140014224080,0x0000140014224080,0xDEADBEEFDEADBEEF,0x4020140014224000,0x0000140014224000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4020140014224180,0x0000140014224180,0xDEADBEEFDEADBEEF,0x4020140014224100,0x0000140014224100,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4122140014020000,0x0102140014020000,0xDEADBEEFDEADBEEF,0x4122140014020000,0x0102140014020000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4022140014020100,0x0002140014020100,0xDEADBEEFDEADBEEF,0x4022140014020100,0x0002140014020100,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4020140014224080,0x0000140014224080,0xDEADBEEFDEADBEEF,0x4020140014224000,0x0000140014224000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0020140014204080,0x0000140014204080,0xDEADBEEFDEADBEEF,0x0020140014204000,0x0000140014204000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4022140014020000,0x0002140014020000,0xDEADBEEFDEADBEEF,0x4022140014020000,0x0002140014020000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0122140014000000,0x0102140014000000,0xDEADBEEFDEADBEEF,0x0122140014000000,0x0102140014000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0020140014204080,0x0000140014204080,0xDEADBEEFDEADBEEF,0x0020140014204000,0x0000140014204000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0020140014204080,0x0000140014204080,0xDEADBEEFDEADBEEF,0x0020140014204000,0x0000140014204000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0122140014000000,0x0102140014000000,0xDEADBEEFDEADBEEF,0x0122140014000000,0x0102140014000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0022140014000000,0x0002140014000000,0xDEADBEEFDEADBEEF,0x0022140014000000,0x0002140014000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0020140014204080,0x0000140014204080,0xDEADBEEFDEADBEEF,0x0020140014204000,0x0000140014204000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0122140014020100,0x0102140014020100,0xDEADBEEFDEADBEEF,0x0122140014020100,0x0102140014020100,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0022140014000000,0x0002140014000000,0xDEADBEEFDEADBEEF,0x0022140014000000,0x0002140014000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0020140014020100,0x0000140014020100,0xDEADBEEFDEADBEEF,0x0020140014020100,0x0000140014020100,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0122140014020000,0x0102140014020000,0xDEADBEEFDEADBEEF,0x0122140014020000,0x0102140014020000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0022140014020100,0x0002140014020100,0xDEADBEEFDEADBEEF,0x0022140014020100,0x0002140014020100,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0020140014020000,0x0000140014020000,0xDEADBEEFDEADBEEF,0x0020140014020000,0x0000140014020000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0020140014020100,0x0000140014020100,0xDEADBEEFDEADBEEF,0x0020140014020100,0x0000140014020100,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0022140014020000,0x0002140014020000,0xDEADBEEFDEADBEEF,0x0022140014020000,0x0002140014020000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4122140014000000,0x0102140014000000,0xDEADBEEFDEADBEEF,0x4122140014000000,0x0102140014000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0020140014020000,0x0000140014020000,0xDEADBEEFDEADBEEF,0x0020140014020000,0x0000140014020000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4020140014000000,0x0000140014000000,0xDEADBEEFDEADBEEF,0x4020140014000000,0x0000140014000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4122140014000000,0x0102140014000000,0xDEADBEEFDEADBEEF,0x4122140014000000,0x0102140014000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4022140014000000,0x0002140014000000,0xDEADBEEFDEADBEEF,0x4022140014000000,0x0002140014000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4020140014000000,0x0000140014000000,0xDEADBEEFDEADBEEF,0x4020140014000000,0x0000140014000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4020140014000000,0x0000140014000000,0xDEADBEEFDEADBEEF,0x4020140014000000,0x0000140014000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4022140014000000,0x0002140014000000,0xDEADBEEFDEADBEEF,0x4022140014000000,0x0002140014000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4020140014020100,0x0000140014020100,0xDEADBEEFDEADBEEF,0x4020140014020100,0x0000140014020100,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4020140014000000,0x0000140014000000,0xDEADBEEFDEADBEEF,0x4020140014000000,0x0000140014000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0122140014220100,0x0102140014220100,0xDEADBEEFDEADBEEF,0x0122140014220100,0x0102140014220100,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4020140014020000,0x0000140014020000,0xDEADBEEFDEADBEEF,0x4020140014020000,0x0000140014020000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4020140014020100,0x0000140014020100,0xDEADBEEFDEADBEEF,0x4020140014020100,0x0000140014020100,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0122140014220000,0x0102140014220000,0xDEADBEEFDEADBEEF,0x0122140014220000,0x0102140014220000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0022140014220100,0x0002140014220100,0xDEADBEEFDEADBEEF,0x0022140014220100,0x0002140014220100,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4020140014020000,0x0000140014020000,0xDEADBEEFDEADBEEF,0x4020140014020000,0x0000140014020000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0020140014000000,0x0000140014000000,0xDEADBEEFDEADBEEF,0x0020140014000000,0x0000140014000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0022140014220000,0x0002140014220000,0xDEADBEEFDEADBEEF,0x0022140014220000,0x0002140014220000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4122140014200000,0x0102140014200000,0xDEADBEEFDEADBEEF,0x4122140014200000,0x0102140014200000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0020140014000000,0x0000140014000000,0xDEADBEEFDEADBEEF,0x0020140014000000,0x0000140014000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0020140014000000,0x0000140014000000,0xDEADBEEFDEADBEEF,0x0020140014000000,0x0000140014000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4122140014200000,0x0102140014200000,0xDEADBEEFDEADBEEF,0x4122140014200000,0x0102140014200000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x4022140014200000,0x0002140014200000,0xDEADBEEFDEADBEEF,0x4022140014200000,0x0002140014200000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0020140014000000,0x0000140014000000,0xDEADBEEFDEADBEEF,0x0020140014000000,0x0000140014000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,];
-const ATTACK_TABLE_36: [Bitboard; 2048] = [0x8244280028448201,0x8244280028448000,0x0204280028448201,0x0204280028448000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028408000,0x0040280028408000,0x0000280028408000,0x0000280028408000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8244280028440201,0x8244280028440000,0x0204280028440201,0x0204280028440000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028400000,0x0040280028400000,0x0000280028400000,0x0000280028400000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8244280028040201,0x8244280028040000,0x0204280028040201,0x0204280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028000000,0x0040280028000000,0x0000280028000000,0x0000280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8244280028040201,0x8244280028040000,0x0204280028040201,0x0204280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028000000,0x0040280028000000,0x0000280028000000,0x0000280028000000,0xDEADBEEFDEADBEEF,0x8044280028448201,0x8044280028448000,0x0004280028448201,0x0004280028448000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028408000,0x0040280028408000,0x0000280028408000,0x0000280028408000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8044280028440201,0x8044280028440000,0x0004280028440201,0x0004280028440000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028400000,0x0040280028400000,0x0000280028400000,0x0000280028400000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8044280028040201,0x8044280028040000,0x0004280028040201,0x0004280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028000000,0x0040280028000000,0x0000280028000000,0x0000280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8044280028040201,0x8044280028040000,0x0004280028040201,0x0004280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028000000,0x0040280028000000,0x0000280028000000,0x0000280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028448200,0x8040280028448000,0x0000280028448200,0x0000280028448000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8244280028408000,0x8244280028408000,0x0204280028408000,0x0204280028408000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028440200,0x8040280028440000,0x0000280028440200,0x0000280028440000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8244280028400000,0x8244280028400000,0x0204280028400000,0x0204280028400000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028040200,0x8040280028040000,0x0000280028040200,0x0000280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8244280028000000,0x8244280028000000,0x0204280028000000,0x0204280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028040200,0x8040280028040000,0x0000280028040200,0x0000280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8244280028000000,0x8244280028000000,0x0204280028000000,0x0204280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028448200,0x8040280028448000,0x0000280028448200,0x0000280028448000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8044280028408000,0x8044280028408000,0x0004280028408000,0x0004280028408000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028440200,0x8040280028440000,0x0000280028440200,0x0000280028440000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8044280028400000,0x8044280028400000,0x0004280028400000,0x0004280028400000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028040200,0x8040280028040000,0x0000280028040200,0x0000280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8044280028000000,0x8044280028000000,0x0004280028000000,0x0004280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028040200,0x8040280028040000,0x0000280028040200,0x0000280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8044280028000000,0x8044280028000000,0x0004280028000000,0x0004280028000000,0xDEADBEEFDEADBEEF,0x8244280028448200,0x8244280028448000,0x0204280028448200,0x0204280028448000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028408000,0x0040280028408000,0x0000280028408000,0x0000280028408000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8244280028440200,0x8244280028440000,0x0204280028440200,0x0204280028440000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028400000,0x0040280028400000,0x0000280028400000,0x0000280028400000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8244280028040200,0x8244280028040000,0x0204280028040200,0x0204280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028000000,0x0040280028000000,0x0000280028000000,0x0000280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8244280028040200,0x8244280028040000,0x0204280028040200,0x0204280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028000000,0x0040280028000000,0x0000280028000000,0x0000280028000000,0xDEADBEEFDEADBEEF,0x8044280028448200,0x8044280028448000,0x0004280028448200,0x0004280028448000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028408000,0x0040280028408000,0x0000280028408000,0x0000280028408000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8044280028440200,0x8044280028440000,0x0004280028440200,0x0004280028440000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028400000,0x0040280028400000,0x0000280028400000,0x0000280028400000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8044280028040200,0x8044280028040000,0x0004280028040200,0x0004280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028000000,0x0040280028000000,0x0000280028000000,0x0000280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8044280028040200,0x8044280028040000,0x0004280028040200,0x0004280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028000000,0x0040280028000000,0x0000280028000000,0x0000280028000000,0xDEADBEEFDEADBEEF,0x0244280028448201,0x0244280028448000,0x0204280028448201,0x0204280028448000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028408000,0x8040280028408000,0x0000280028408000,0x0000280028408000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0244280028440201,0x0244280028440000,0x0204280028440201,0x0204280028440000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028400000,0x8040280028400000,0x0000280028400000,0x0000280028400000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0244280028040201,0x0244280028040000,0x0204280028040201,0x0204280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028000000,0x8040280028000000,0x0000280028000000,0x0000280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0244280028040201,0x0244280028040000,0x0204280028040201,0x0204280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028000000,0x8040280028000000,0x0000280028000000,0x0000280028000000,0xDEADBEEFDEADBEEF,0x0044280028448201,0x0044280028448000,0x0004280028448201,0x0004280028448000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028408000,0x8040280028408000,0x0000280028408000,0x0000280028408000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0044280028440201,0x0044280028440000,0x0004280028440201,0x0004280028440000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028400000,0x8040280028400000,0x0000280028400000,0x0000280028400000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0044280028040201,0x0044280028040000,0x0004280028040201,0x0004280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028000000,0x8040280028000000,0x0000280028000000,0x0000280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0044280028040201,0x0044280028040000,0x0004280028040201,0x0004280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028000000,0x8040280028000000,0x0000280028000000,0x0000280028000000,0xDEADBEEFDEADBEEF,0x8040280028448201,0x8040280028448000,0x0000280028448201,0x0000280028448000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8244280028408000,0x8244280028408000,0x0204280028408000,0x0204280028408000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028440201,0x8040280028440000,0x0000280028440201,0x0000280028440000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8244280028400000,0x8244280028400000,0x0204280028400000,0x0204280028400000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028040201,0x8040280028040000,0x0000280028040201,0x0000280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8244280028000000,0x8244280028000000,0x0204280028000000,0x0204280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028040201,0x8040280028040000,0x0000280028040201,0x0000280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8244280028000000,0x8244280028000000,0x0204280028000000,0x0204280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028448201,0x8040280028448000,0x0000280028448201,0x0000280028448000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8044280028408000,0x8044280028408000,0x0004280028408000,0x0004280028408000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028440201,0x8040280028440000,0x0000280028440201,0x0000280028440000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8044280028400000,0x8044280028400000,0x0004280028400000,0x0004280028400000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028040201,0x8040280028040000,0x0000280028040201,0x0000280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8044280028000000,0x8044280028000000,0x0004280028000000,0x0004280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028040201,0x8040280028040000,0x0000280028040201,0x0000280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8044280028000000,0x8044280028000000,0x0004280028000000,0x0004280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0244280028448200,0x0244280028448000,0x0204280028448200,0x0204280028448000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028408000,0x8040280028408000,0x0000280028408000,0x0000280028408000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0244280028440200,0x0244280028440000,0x0204280028440200,0x0204280028440000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028400000,0x8040280028400000,0x0000280028400000,0x0000280028400000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0244280028040200,0x0244280028040000,0x0204280028040200,0x0204280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028000000,0x8040280028000000,0x0000280028000000,0x0000280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0244280028040200,0x0244280028040000,0x0204280028040200,0x0204280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028000000,0x8040280028000000,0x0000280028000000,0x0000280028000000,0xDEADBEEFDEADBEEF,0x0044280028448200,0x0044280028448000,0x0004280028448200,0x0004280028448000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028408000,0x8040280028408000,0x0000280028408000,0x0000280028408000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0044280028440200,0x0044280028440000,0x0004280028440200,0x0004280028440000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028400000,0x8040280028400000,0x0000280028400000,0x0000280028400000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0044280028040200,0x0044280028040000,0x0004280028040200,0x0004280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028000000,0x8040280028000000,0x0000280028000000,0x0000280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0044280028040200,0x0044280028040000,0x0004280028040200,0x0004280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028000000,0x8040280028000000,0x0000280028000000,0x0000280028000000,0xDEADBEEFDEADBEEF,0x8040280028448200,0x8040280028448000,0x0000280028448200,0x0000280028448000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8244280028408000,0x8244280028408000,0x0204280028408000,0x0204280028408000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028440200,0x8040280028440000,0x0000280028440200,0x0000280028440000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8244280028400000,0x8244280028400000,0x0204280028400000,0x0204280028400000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028040200,0x8040280028040000,0x0000280028040200,0x0000280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8244280028000000,0x8244280028000000,0x0204280028000000,0x0204280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028040200,0x8040280028040000,0x0000280028040200,0x0000280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8244280028000000,0x8244280028000000,0x0204280028000000,0x0204280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028448200,0x8040280028448000,0x0000280028448200,0x0000280028448000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8044280028408000,0x8044280028408000,0x0004280028408000,0x0004280028408000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028440200,0x8040280028440000,0x0000280028440200,0x0000280028440000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8044280028400000,0x8044280028400000,0x0004280028400000,0x0004280028400000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028040200,0x8040280028040000,0x0000280028040200,0x0000280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8044280028000000,0x8044280028000000,0x0004280028000000,0x0004280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028040200,0x8040280028040000,0x0000280028040200,0x0000280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8044280028000000,0x8044280028000000,0x0004280028000000,0x0004280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028448201,0x0040280028448000,0x0000280028448201,0x0000280028448000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0244280028408000,0x0244280028408000,0x0204280028408000,0x0204280028408000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028440201,0x0040280028440000,0x0000280028440201,0x0000280028440000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0244280028400000,0x0244280028400000,0x0204280028400000,0x0204280028400000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028040201,0x0040280028040000,0x0000280028040201,0x0000280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0244280028000000,0x0244280028000000,0x0204280028000000,0x0204280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028040201,0x0040280028040000,0x0000280028040201,0x0000280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0244280028000000,0x0244280028000000,0x0204280028000000,0x0204280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028448201,0x0040280028448000,0x0000280028448201,0x0000280028448000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0044280028408000,0x0044280028408000,0x0004280028408000,0x0004280028408000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028440201,0x0040280028440000,0x0000280028440201,0x0000280028440000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0044280028400000,0x0044280028400000,0x0004280028400000,0x0004280028400000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028040201,0x0040280028040000,0x0000280028040201,0x0000280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0044280028000000,0x0044280028000000,0x0004280028000000,0x0004280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028040201,0x0040280028040000,0x0000280028040201,0x0000280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0044280028000000,0x0044280028000000,0x0004280028000000,0x0004280028000000,0xDEADBEEFDEADBEEF,0x0244280028448201,0x0244280028448000,0x0204280028448201,0x0204280028448000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028408000,0x8040280028408000,0x0000280028408000,0x0000280028408000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0244280028440201,0x0244280028440000,0x0204280028440201,0x0204280028440000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028400000,0x8040280028400000,0x0000280028400000,0x0000280028400000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0244280028040201,0x0244280028040000,0x0204280028040201,0x0204280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028000000,0x8040280028000000,0x0000280028000000,0x0000280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0244280028040201,0x0244280028040000,0x0204280028040201,0x0204280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028000000,0x8040280028000000,0x0000280028000000,0x0000280028000000,0xDEADBEEFDEADBEEF,0x0044280028448201,0x0044280028448000,0x0004280028448201,0x0004280028448000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028408000,0x8040280028408000,0x0000280028408000,0x0000280028408000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0044280028440201,0x0044280028440000,0x0004280028440201,0x0004280028440000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028400000,0x8040280028400000,0x0000280028400000,0x0000280028400000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0044280028040201,0x0044280028040000,0x0004280028040201,0x0004280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028000000,0x8040280028000000,0x0000280028000000,0x0000280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0044280028040201,0x0044280028040000,0x0004280028040201,0x0004280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028000000,0x8040280028000000,0x0000280028000000,0x0000280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028448200,0x0040280028448000,0x0000280028448200,0x0000280028448000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0244280028408000,0x0244280028408000,0x0204280028408000,0x0204280028408000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028440200,0x0040280028440000,0x0000280028440200,0x0000280028440000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0244280028400000,0x0244280028400000,0x0204280028400000,0x0204280028400000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028040200,0x0040280028040000,0x0000280028040200,0x0000280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0244280028000000,0x0244280028000000,0x0204280028000000,0x0204280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028040200,0x0040280028040000,0x0000280028040200,0x0000280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0244280028000000,0x0244280028000000,0x0204280028000000,0x0204280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028448200,0x0040280028448000,0x0000280028448200,0x0000280028448000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0044280028408000,0x0044280028408000,0x0004280028408000,0x0004280028408000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028440200,0x0040280028440000,0x0000280028440200,0x0000280028440000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0044280028400000,0x0044280028400000,0x0004280028400000,0x0004280028400000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028040200,0x0040280028040000,0x0000280028040200,0x0000280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0044280028000000,0x0044280028000000,0x0004280028000000,0x0004280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028040200,0x0040280028040000,0x0000280028040200,0x0000280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0044280028000000,0x0044280028000000,0x0004280028000000,0x0004280028000000,0xDEADBEEFDEADBEEF,0x0244280028448200,0x0244280028448000,0x0204280028448200,0x0204280028448000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028408000,0x8040280028408000,0x0000280028408000,0x0000280028408000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0244280028440200,0x0244280028440000,0x0204280028440200,0x0204280028440000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028400000,0x8040280028400000,0x0000280028400000,0x0000280028400000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0244280028040200,0x0244280028040000,0x0204280028040200,0x0204280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028000000,0x8040280028000000,0x0000280028000000,0x0000280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0244280028040200,0x0244280028040000,0x0204280028040200,0x0204280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028000000,0x8040280028000000,0x0000280028000000,0x0000280028000000,0xDEADBEEFDEADBEEF,0x0044280028448200,0x0044280028448000,0x0004280028448200,0x0004280028448000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028408000,0x8040280028408000,0x0000280028408000,0x0000280028408000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0044280028440200,0x0044280028440000,0x0004280028440200,0x0004280028440000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028400000,0x8040280028400000,0x0000280028400000,0x0000280028400000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0044280028040200,0x0044280028040000,0x0004280028040200,0x0004280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028000000,0x8040280028000000,0x0000280028000000,0x0000280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0044280028040200,0x0044280028040000,0x0004280028040200,0x0004280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028000000,0x8040280028000000,0x0000280028000000,0x0000280028000000,0xDEADBEEFDEADBEEF,0x8244280028448201,0x8244280028448000,0x0204280028448201,0x0204280028448000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028408000,0x0040280028408000,0x0000280028408000,0x0000280028408000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8244280028440201,0x8244280028440000,0x0204280028440201,0x0204280028440000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028400000,0x0040280028400000,0x0000280028400000,0x0000280028400000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8244280028040201,0x8244280028040000,0x0204280028040201,0x0204280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028000000,0x0040280028000000,0x0000280028000000,0x0000280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8244280028040201,0x8244280028040000,0x0204280028040201,0x0204280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028000000,0x0040280028000000,0x0000280028000000,0x0000280028000000,0xDEADBEEFDEADBEEF,0x8044280028448201,0x8044280028448000,0x0004280028448201,0x0004280028448000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028408000,0x0040280028408000,0x0000280028408000,0x0000280028408000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8044280028440201,0x8044280028440000,0x0004280028440201,0x0004280028440000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028400000,0x0040280028400000,0x0000280028400000,0x0000280028400000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8044280028040201,0x8044280028040000,0x0004280028040201,0x0004280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028000000,0x0040280028000000,0x0000280028000000,0x0000280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8044280028040201,0x8044280028040000,0x0004280028040201,0x0004280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028000000,0x0040280028000000,0x0000280028000000,0x0000280028000000,0xDEADBEEFDEADBEEF,0x0040280028448201,0x0040280028448000,0x0000280028448201,0x0000280028448000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0244280028408000,0x0244280028408000,0x0204280028408000,0x0204280028408000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028440201,0x0040280028440000,0x0000280028440201,0x0000280028440000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0244280028400000,0x0244280028400000,0x0204280028400000,0x0204280028400000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028040201,0x0040280028040000,0x0000280028040201,0x0000280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0244280028000000,0x0244280028000000,0x0204280028000000,0x0204280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028040201,0x0040280028040000,0x0000280028040201,0x0000280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0244280028000000,0x0244280028000000,0x0204280028000000,0x0204280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028448201,0x0040280028448000,0x0000280028448201,0x0000280028448000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0044280028408000,0x0044280028408000,0x0004280028408000,0x0004280028408000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028440201,0x0040280028440000,0x0000280028440201,0x0000280028440000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0044280028400000,0x0044280028400000,0x0004280028400000,0x0004280028400000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028040201,0x0040280028040000,0x0000280028040201,0x0000280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0044280028000000,0x0044280028000000,0x0004280028000000,0x0004280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028040201,0x0040280028040000,0x0000280028040201,0x0000280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0044280028000000,0x0044280028000000,0x0004280028000000,0x0004280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8244280028448200,0x8244280028448000,0x0204280028448200,0x0204280028448000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028408000,0x0040280028408000,0x0000280028408000,0x0000280028408000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8244280028440200,0x8244280028440000,0x0204280028440200,0x0204280028440000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028400000,0x0040280028400000,0x0000280028400000,0x0000280028400000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8244280028040200,0x8244280028040000,0x0204280028040200,0x0204280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028000000,0x0040280028000000,0x0000280028000000,0x0000280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8244280028040200,0x8244280028040000,0x0204280028040200,0x0204280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028000000,0x0040280028000000,0x0000280028000000,0x0000280028000000,0xDEADBEEFDEADBEEF,0x8044280028448200,0x8044280028448000,0x0004280028448200,0x0004280028448000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028408000,0x0040280028408000,0x0000280028408000,0x0000280028408000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8044280028440200,0x8044280028440000,0x0004280028440200,0x0004280028440000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028400000,0x0040280028400000,0x0000280028400000,0x0000280028400000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8044280028040200,0x8044280028040000,0x0004280028040200,0x0004280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028000000,0x0040280028000000,0x0000280028000000,0x0000280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8044280028040200,0x8044280028040000,0x0004280028040200,0x0004280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028000000,0x0040280028000000,0x0000280028000000,0x0000280028000000,0xDEADBEEFDEADBEEF,0x0040280028448200,0x0040280028448000,0x0000280028448200,0x0000280028448000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0244280028408000,0x0244280028408000,0x0204280028408000,0x0204280028408000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028440200,0x0040280028440000,0x0000280028440200,0x0000280028440000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0244280028400000,0x0244280028400000,0x0204280028400000,0x0204280028400000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028040200,0x0040280028040000,0x0000280028040200,0x0000280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0244280028000000,0x0244280028000000,0x0204280028000000,0x0204280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028040200,0x0040280028040000,0x0000280028040200,0x0000280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0244280028000000,0x0244280028000000,0x0204280028000000,0x0204280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028448200,0x0040280028448000,0x0000280028448200,0x0000280028448000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0044280028408000,0x0044280028408000,0x0004280028408000,0x0004280028408000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028440200,0x0040280028440000,0x0000280028440200,0x0000280028440000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0044280028400000,0x0044280028400000,0x0004280028400000,0x0004280028400000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028040200,0x0040280028040000,0x0000280028040200,0x0000280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0044280028000000,0x0044280028000000,0x0004280028000000,0x0004280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0040280028040200,0x0040280028040000,0x0000280028040200,0x0000280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x0044280028000000,0x0044280028000000,0x0004280028000000,0x0004280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028448201,0x8040280028448000,0x0000280028448201,0x0000280028448000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8244280028408000,0x8244280028408000,0x0204280028408000,0x0204280028408000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028440201,0x8040280028440000,0x0000280028440201,0x0000280028440000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8244280028400000,0x8244280028400000,0x0204280028400000,0x0204280028400000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028040201,0x8040280028040000,0x0000280028040201,0x0000280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8244280028000000,0x8244280028000000,0x0204280028000000,0x0204280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028040201,0x8040280028040000,0x0000280028040201,0x0000280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8244280028000000,0x8244280028000000,0x0204280028000000,0x0204280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028448201,0x8040280028448000,0x0000280028448201,0x0000280028448000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8044280028408000,0x8044280028408000,0x0004280028408000,0x0004280028408000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028440201,0x8040280028440000,0x0000280028440201,0x0000280028440000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8044280028400000,0x8044280028400000,0x0004280028400000,0x0004280028400000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028040201,0x8040280028040000,0x0000280028040201,0x0000280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8044280028000000,0x8044280028000000,0x0004280028000000,0x0004280028000000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8040280028040201,0x8040280028040000,0x0000280028040201,0x0000280028040000,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0xDEADBEEFDEADBEEF,0x8044280028000000,0x8044280028000000,0x0004280028000000,0x0004280028000000,0xDEADBEEFDEADBEEF,];
-const ATTACK_TABLE_37: [Bitboard; 128] = [0x0488500050880402,0x0408500050880402,0x0488500050880000,0x0408500050880000,0x0488500050800000,0x0408500050800000,0x0488500050800000,0x0408500050800000,0x0080500050880000,0x0000500050880000,0x0080500050880400,0x0000500050880400,0x0080500050800000,0x0000500050800000,0x0080500050800000,0x0000500050800000,0x0488500050080402,0x0408500050080402,0x0488500050080000,0x0408500050080000,0x0488500050000000,0x0408500050000000,0x0488500050000000,0x0408500050000000,0x0080500050080000,0x0000500050080000,0x0080500050080400,0x0000500050080400,0x0080500050000000,0x0000500050000000,0x0080500050000000,0x0000500050000000,0x0080500050880402,0x0000500050880402,0x0080500050880000,0x0000500050880000,0x0080500050800000,0x0000500050800000,0x0080500050800000,0x0000500050800000,0x0088500050880402,0x0008500050880402,0x0088500050880000,0x0008500050880000,0x0088500050800000,0x0008500050800000,0x0088500050800000,0x0008500050800000,0x0080500050080402,0x0000500050080402,0x0080500050080000,0x0000500050080000,0x0080500050000000,0x0000500050000000,0x0080500050000000,0x0000500050000000,0x0088500050080402,0x0008500050080402,0x0088500050080000,0x0008500050080000,0x0088500050000000,0x0008500050000000,0x0088500050000000,0x0008500050000000,0x0488500050880000,0x0408500050880000,0x0488500050880400,0x0408500050880400,0x0488500050800000,0x0408500050800000,0x0488500050800000,0x0408500050800000,0x0080500050880402,0x0000500050880402,0x0080500050880000,0x0000500050880000,0x0080500050800000,0x0000500050800000,0x0080500050800000,0x0000500050800000,0x0488500050080000,0x0408500050080000,0x0488500050080400,0x0408500050080400,0x0488500050000000,0x0408500050000000,0x0488500050000000,0x0408500050000000,0x0080500050080402,0x0000500050080402,0x0080500050080000,0x0000500050080000,0x0080500050000000,0x0000500050000000,0x0080500050000000,0x0000500050000000,0x0080500050880000,0x0000500050880000,0x0080500050880400,0x0000500050880400,0x0080500050800000,0x0000500050800000,0x0080500050800000,0x0000500050800000,0x0088500050880000,0x0008500050880000,0x0088500050880400,0x0008500050880400,0x0088500050800000,0x0008500050800000,0x0088500050800000,0x0008500050800000,0x0080500050080000,0x0000500050080000,0x0080500050080400,0x0000500050080400,0x0080500050000000,0x0000500050000000,0x0080500050000000,0x0000500050000000,0x0088500050080000,0x0008500050080000,0x0088500050080400,0x0008500050080400,0x0088500050000000,0x0008500050000000,0x0088500050000000,0x0008500050000000,];
-const ATTACK_TABLE_38: [Bitboard; 32] = [0x0810A000A0100804,0x0010A000A0100804,0x0810A000A0100800,0x0010A000A0100800,0x0810A000A0000000,0x0010A000A0000000,0x0810A000A0000000,0x0010A000A0000000,0x0000A000A0000000,0x0000A000A0000000,0x0000A000A0000000,0x0000A000A0000000,0x0810A000A0100000,0x0010A000A0100000,0x0810A000A0100000,0x0010A000A0100000,0x0000A000A0100804,0x0000A000A0100804,0x0000A000A0100800,0x0000A000A0100800,0x0000A000A0000000,0x0000A000A0000000,0x0000A000A0000000,0x0000A000A0000000,0x0810A000A0000000,0x0010A000A0000000,0x0810A000A0000000,0x0010A000A0000000,0x0000A000A0100000,0x0000A000A0100000,0x0000A000A0100000,0x0000A000A0100000,];
-const ATTACK_TABLE_39: [Bitboard; 32] = [0x1020400040201008,0x1020400040000000,0x0000400040200000,0x0000400040000000,0x0020400040201008,0x0020400040000000,0x1020400040200000,0x1020400040000000,0x0000400040201000,0x0000400040000000,0x0020400040200000,0x0020400040000000,0x0000400040201000,0x0000400040000000,0x0000400040200000,0x0000400040000000,0x1020400040201000,0x1020400040000000,0x0000400040200000,0x0000400040000000,0x0020400040201000,0x0020400040000000,0x1020400040200000,0x1020400040000000,0x0000400040201008,0x0000400040000000,0x0020400040200000,0x0020400040000000,0x0000400040201008,0x0000400040000000,0x0000400040200000,0x0000400040000000,];
-const ATTACK_TABLE_40: [Bitboard; 32] = [0x0402000204081020,0x0402000204080000,0x0402000200000000,0x0402000200000000,0x0002000204000000,0x0002000204000000,0x0002000200000000,0x0002000200000000,0x0402000204000000,0x0402000204000000,0x0402000200000000,0x0402000200000000,0x0002000204081000,0x0002000204080000,0x0002000200000000,0x0002000200000000,0x0402000204081000,0x0402000204080000,0x0402000200000000,0x0402000200000000,0x0002000204000000,0x0002000204000000,0x0002000200000000,0x0002000200000000,0x0402000204000000,0x0402000204000000,0x0402000200000000,0x0402000200000000,0x0002000204081020,0x0002000204080000,0x0002000200000000,0x0002000200000000,];
-const ATTACK_TABLE_41: [Bitboard; 64] = [0x0805000508102040,0x0005000508102040,0x0805000508000000,0x0005000508000000,0x0805000508102040,0x0005000508102040,0x0805000508000000,0x0005000508000000,0x0805000508102000,0x0005000508102000,0x0805000508000000,0x0005000508000000,0x0805000508102000,0x0005000508102000,0x0805000508000000,0x0005000508000000,0x0805000500000000,0x0005000500000000,0x0805000500000000,0x0005000500000000,0x0805000500000000,0x0005000500000000,0x0805000500000000,0x0005000500000000,0x0805000500000000,0x0005000500000000,0x0805000500000000,0x0005000500000000,0x0805000500000000,0x0005000500000000,0x0805000500000000,0x0005000500000000,0x0805000508100000,0x0005000508100000,0x0805000508000000,0x0005000508000000,0x0805000508100000,0x0005000508100000,0x0805000508000000,0x0005000508000000,0x0805000508100000,0x0005000508100000,0x0805000508000000,0x0005000508000000,0x0805000508100000,0x0005000508100000,0x0805000508000000,0x0005000508000000,0x0805000500000000,0x0005000500000000,0x0805000500000000,0x0005000500000000,0x0805000500000000,0x0005000500000000,0x0805000500000000,0x0005000500000000,0x0805000500000000,0x0005000500000000,0x0805000500000000,0x0005000500000000,0x0805000500000000,0x0005000500000000,0x0805000500000000,0x0005000500000000,];
-const ATTACK_TABLE_42: [Bitboard; 128] = [0x110A000A11204080,0x110A000A11000000,0x000A000A10200000,0x000A000A10000000,0x100A000A11204080,0x100A000A11000000,0x110A000A11204000,0x110A000A11000000,0x110A000A00000000,0x110A000A00000000,0x100A000A11204000,0x100A000A11000000,0x100A000A00000000,0x100A000A00000000,0x110A000A00000000,0x110A000A00000000,0x010A000A11204080,0x010A000A11000000,0x100A000A00000000,0x100A000A00000000,0x000A000A11204080,0x000A000A11000000,0x010A000A11204000,0x010A000A11000000,0x010A000A00000000,0x010A000A00000000,0x000A000A11204000,0x000A000A11000000,0x000A000A00000000,0x000A000A00000000,0x010A000A00000000,0x010A000A00000000,0x110A000A01000000,0x110A000A01000000,0x000A000A00000000,0x000A000A00000000,0x100A000A01000000,0x100A000A01000000,0x110A000A01000000,0x110A000A01000000,0x110A000A10204080,0x110A000A10000000,0x100A000A01000000,0x100A000A01000000,0x100A000A10204080,0x100A000A10000000,0x110A000A10204000,0x110A000A10000000,0x010A000A01000000,0x010A000A01000000,0x100A000A10204000,0x100A000A10000000,0x000A000A01000000,0x000A000A01000000,0x010A000A01000000,0x010A000A01000000,0x010A000A10204080,0x010A000A10000000,0x000A000A01000000,0x000A000A01000000,0x000A000A10204080,0x000A000A10000000,0x010A000A10204000,0x010A000A10000000,0x110A000A11200000,0x110A000A11000000,0x000A000A10204000,0x000A000A10000000,0x100A000A11200000,0x100A000A11000000,0x110A000A11200000,0x110A000A11000000,0x110A000A00000000,0x110A000A00000000,0x100A000A11200000,0x100A000A11000000,0x100A000A00000000,0x100A000A00000000,0x110A000A00000000,0x110A000A00000000,0x010A000A11200000,0x010A000A11000000,0x100A000A00000000,0x100A000A00000000,0x000A000A11200000,0x000A000A11000000,0x010A000A11200000,0x010A000A11000000,0x010A000A00000000,0x010A000A00000000,0x000A000A11200000,0x000A000A11000000,0x000A000A00000000,0x000A000A00000000,0x010A000A00000000,0x010A000A00000000,0x110A000A01000000,0x110A000A01000000,0x000A000A00000000,0x000A000A00000000,0x100A000A01000000,0x100A000A01000000,0x110A000A01000000,0x110A000A01000000,0x110A000A10200000,0x110A000A10000000,0x100A000A01000000,0x100A000A01000000,0x100A000A10200000,0x100A000A10000000,0x110A000A10200000,0x110A000A10000000,0x010A000A01000000,0x010A000A01000000,0x100A000A10200000,0x100A000A10000000,0x000A000A01000000,0x000A000A01000000,0x010A000A01000000,0x010A000A01000000,0x010A000A10200000,0x010A000A10000000,0x000A000A01000000,0x000A000A01000000,0x000A000A10200000,0x000A000A10000000,0x010A000A10200000,0x010A000A10000000,];
-const ATTACK_TABLE_43: [Bitboard; 128] = [0x2214001422418000,0x2214001422010000,0x0014001420408000,0x0014001420000000,0x2214001422410000,0x2214001422010000,0x0014001420400000,0x0014001420000000,0x0214001402010000,0x0214001402010000,0x2014001400000000,0x2014001400000000,0x0214001402010000,0x0214001402010000,0x2014001400000000,0x2014001400000000,0x2014001422418000,0x2014001422010000,0x0214001420408000,0x0214001420000000,0x2014001422410000,0x2014001422010000,0x0214001420400000,0x0214001420000000,0x0014001402010000,0x0014001402010000,0x2214001400000000,0x2214001400000000,0x0014001402010000,0x0014001402010000,0x2214001400000000,0x2214001400000000,0x2214001422408000,0x2214001422000000,0x0014001420408000,0x0014001420000000,0x2214001422400000,0x2214001422000000,0x0014001420400000,0x0014001420000000,0x0214001402000000,0x0214001402000000,0x2014001400000000,0x2014001400000000,0x0214001402000000,0x0214001402000000,0x2014001400000000,0x2014001400000000,0x2014001422408000,0x2014001422000000,0x2214001420408000,0x2214001420000000,0x2014001422400000,0x2014001422000000,0x2214001420400000,0x2214001420000000,0x0014001402000000,0x0014001402000000,0x0214001400000000,0x0214001400000000,0x0014001402000000,0x0014001402000000,0x0214001400000000,0x0214001400000000,0x0214001422418000,0x0214001422010000,0x2014001420408000,0x2014001420000000,0x0214001422410000,0x0214001422010000,0x2014001420400000,0x2014001420000000,0x2214001402010000,0x2214001402010000,0x0014001400000000,0x0014001400000000,0x2214001402010000,0x2214001402010000,0x0014001400000000,0x0014001400000000,0x0014001422418000,0x0014001422010000,0x2214001420408000,0x2214001420000000,0x0014001422410000,0x0014001422010000,0x2214001420400000,0x2214001420000000,0x2014001402010000,0x2014001402010000,0x0214001400000000,0x0214001400000000,0x2014001402010000,0x2014001402010000,0x0214001400000000,0x0214001400000000,0x0214001422408000,0x0214001422000000,0x2014001420408000,0x2014001420000000,0x0214001422400000,0x0214001422000000,0x2014001420400000,0x2014001420000000,0x2214001402000000,0x2214001402000000,0x0014001400000000,0x0014001400000000,0x2214001402000000,0x2214001402000000,0x0014001400000000,0x0014001400000000,0x0014001422408000,0x0014001422000000,0x0214001420408000,0x0214001420000000,0x0014001422400000,0x0014001422000000,0x0214001420400000,0x0214001420000000,0x2014001402000000,0x2014001402000000,0x2214001400000000,0x2214001400000000,0x2014001402000000,0x2014001402000000,0x2214001400000000,0x2214001400000000,];
-const ATTACK_TABLE_44: [Bitboard; 128] = [0x4428002844820100,0x0028002800000000,0x4428002844800000,0x0028002800000000,0x0428002840800000,0x4028002804020000,0x0428002840800000,0x4028002804000000,0x4428002844820000,0x0028002800000000,0x4428002844800000,0x0028002800000000,0x0428002840800000,0x4028002800000000,0x0428002840800000,0x4028002800000000,0x4428002840800000,0x0028002804020100,0x4428002840800000,0x0028002804000000,0x0428002844020100,0x4028002800000000,0x0428002844000000,0x4028002800000000,0x4428002840800000,0x0028002804020000,0x4428002840800000,0x0028002804000000,0x0428002844020000,0x4028002804020100,0x0428002844000000,0x4028002804000000,0x4428002844020100,0x0028002800000000,0x4428002844000000,0x0028002800000000,0x0428002840000000,0x4028002804020000,0x0428002840000000,0x4028002804000000,0x4428002844020000,0x0028002800000000,0x4428002844000000,0x0028002800000000,0x0428002840000000,0x4028002800000000,0x0428002840000000,0x4028002800000000,0x4428002840000000,0x0028002844820100,0x4428002840000000,0x0028002844800000,0x0428002804020100,0x4028002800000000,0x0428002804000000,0x4028002800000000,0x4428002840000000,0x0028002844820000,0x4428002840000000,0x0028002844800000,0x0428002804020000,0x4028002844820100,0x0428002804000000,0x4028002844800000,0x4428002804020100,0x0028002840800000,0x4428002804000000,0x0028002840800000,0x0428002800000000,0x4028002844820000,0x0428002800000000,0x4028002844800000,0x4428002804020000,0x0028002840800000,0x4428002804000000,0x0028002840800000,0x0428002800000000,0x4028002840800000,0x0428002800000000,0x4028002840800000,0x4428002800000000,0x0028002844020100,0x4428002800000000,0x0028002844000000,0x0428002804020100,0x4028002840800000,0x0428002804000000,0x4028002840800000,0x4428002800000000,0x0028002844020000,0x4428002800000000,0x0028002844000000,0x0428002804020000,0x4028002844020100,0x0428002804000000,0x4028002844000000,0x4428002804020100,0x0028002840000000,0x4428002804000000,0x0028002840000000,0x0428002800000000,0x4028002844020000,0x0428002800000000,0x4028002844000000,0x4428002804020000,0x0028002840000000,0x4428002804000000,0x0028002840000000,0x0428002800000000,0x4028002840000000,0x0428002800000000,0x4028002840000000,0x4428002800000000,0x0028002804020100,0x4428002800000000,0x0028002804000000,0x0428002844820100,0x4028002840000000,0x0428002844800000,0x4028002840000000,0x4428002800000000,0x0028002804020000,0x4428002800000000,0x0028002804000000,0x0428002844820000,0x4028002804020100,0x0428002844800000,0x4028002804000000,];
-const ATTACK_TABLE_45: [Bitboard; 128] = [0x8850005088040201,0x0050005080000000,0x8850005080000000,0x0050005008040000,0x0850005008000000,0x8050005008000000,0x0850005080000000,0x8050005000000000,0x8850005080000000,0x0050005088000000,0x8850005008000000,0x0050005000000000,0x0850005000000000,0x8050005000000000,0x0850005088000000,0x8050005088000000,0x8850005088040200,0x0050005080000000,0x8850005000000000,0x0050005008040000,0x0850005088040201,0x8050005008000000,0x0850005080000000,0x8050005080000000,0x8850005080000000,0x0050005008000000,0x8850005008000000,0x0050005000000000,0x0850005080000000,0x8050005000000000,0x0850005008000000,0x8050005088000000,0x8850005008040201,0x0050005000000000,0x8850005000000000,0x0050005088000000,0x0850005088040200,0x8050005088040201,0x0850005000000000,0x8050005080000000,0x8850005000000000,0x0050005008000000,0x8850005088040000,0x0050005080000000,0x0850005080000000,0x8050005080000000,0x0850005008000000,0x8050005008000000,0x8850005008040200,0x0050005000000000,0x8850005080000000,0x0050005088000000,0x0850005008040201,0x8050005088040200,0x0850005000000000,0x8050005000000000,0x8850005000000000,0x0050005088040201,0x8850005088040000,0x0050005080000000,0x0850005000000000,0x8050005080000000,0x0850005088040000,0x8050005008000000,0x8850005088000000,0x0050005080000000,0x8850005080000000,0x0050005008000000,0x0850005008040200,0x8050005008040201,0x0850005080000000,0x8050005000000000,0x8850005080000000,0x0050005088040200,0x8850005008040000,0x0050005000000000,0x0850005000000000,0x8050005000000000,0x0850005088040000,0x8050005088040000,0x8850005088000000,0x0050005080000000,0x8850005000000000,0x0050005008000000,0x0850005088000000,0x8050005008040200,0x0850005080000000,0x8050005080000000,0x8850005080000000,0x0050005008040201,0x8850005008040000,0x0050005000000000,0x0850005080000000,0x8050005000000000,0x0850005008040000,0x8050005088040000,0x8850005008000000,0x0050005000000000,0x8850005000000000,0x0050005088040000,0x0850005088000000,0x8050005088000000,0x0850005000000000,0x8050005080000000,0x8850005000000000,0x0050005008040200,0x8850005088000000,0x0050005080000000,0x0850005080000000,0x8050005080000000,0x0850005008040000,0x8050005008040000,0x8850005008000000,0x0050005000000000,0x8850005080000000,0x0050005088040000,0x0850005008000000,0x8050005088000000,0x0850005000000000,0x8050005000000000,0x8850005000000000,0x0050005088000000,0x8850005088000000,0x0050005080000000,0x0850005000000000,0x8050005080000000,0x0850005088000000,0x8050005008040000,];
-const ATTACK_TABLE_46: [Bitboard; 32] = [0x10A000A010080402,0x00A000A000000000,0x00A000A000000000,0x00A000A010000000,0x00A000A000000000,0x00A000A010000000,0x00A000A010080000,0x10A000A000000000,0x10A000A000000000,0x10A000A010000000,0x10A000A010080400,0x00A000A000000000,0x10A000A010080000,0x00A000A000000000,0x00A000A000000000,0x00A000A010000000,0x00A000A010080402,0x10A000A000000000,0x10A000A000000000,0x10A000A010000000,0x10A000A000000000,0x10A000A010000000,0x10A000A010080000,0x00A000A000000000,0x00A000A000000000,0x00A000A010000000,0x00A000A010080400,0x10A000A000000000,0x00A000A010080000,0x10A000A000000000,0x10A000A000000000,0x10A000A010000000,];
-const ATTACK_TABLE_47: [Bitboard; 32] = [0x2040004020100804,0x2040004020100800,0x2040004020000000,0x2040004020000000,0x0040004020100804,0x0040004020100800,0x0040004020000000,0x0040004020000000,0x2040004020100000,0x2040004020100000,0x2040004020000000,0x2040004020000000,0x0040004020100000,0x0040004020100000,0x0040004020000000,0x0040004020000000,0x2040004000000000,0x2040004000000000,0x2040004000000000,0x2040004000000000,0x0040004000000000,0x0040004000000000,0x0040004000000000,0x0040004000000000,0x2040004000000000,0x2040004000000000,0x2040004000000000,0x2040004000000000,0x0040004000000000,0x0040004000000000,0x0040004000000000,0x0040004000000000,];
-const ATTACK_TABLE_48: [Bitboard; 32] = [0x0200020408102040,0x0200020000000000,0x0200020400000000,0x0200020000000000,0x0200020408000000,0x0200020000000000,0x0200020400000000,0x0200020000000000,0x0200020408102000,0x0200020000000000,0x0200020400000000,0x0200020000000000,0x0200020408000000,0x0200020000000000,0x0200020400000000,0x0200020000000000,0x0200020408100000,0x0200020000000000,0x0200020400000000,0x0200020000000000,0x0200020408000000,0x0200020000000000,0x0200020400000000,0x0200020000000000,0x0200020408100000,0x0200020000000000,0x0200020400000000,0x0200020000000000,0x0200020408000000,0x0200020000000000,0x0200020400000000,0x0200020000000000,];
-const ATTACK_TABLE_49: [Bitboard; 32] = [0x0500050810204080,0x0500050810200000,0x0500050000000000,0x0500050000000000,0x0500050810204000,0x0500050810200000,0x0500050000000000,0x0500050000000000,0x0500050810000000,0x0500050810000000,0x0500050000000000,0x0500050000000000,0x0500050810000000,0x0500050810000000,0x0500050000000000,0x0500050000000000,0x0500050800000000,0x0500050800000000,0x0500050000000000,0x0500050000000000,0x0500050800000000,0x0500050800000000,0x0500050000000000,0x0500050000000000,0x0500050800000000,0x0500050800000000,0x0500050000000000,0x0500050000000000,0x0500050800000000,0x0500050800000000,0x0500050000000000,0x0500050000000000,];
-const ATTACK_TABLE_50: [Bitboard; 32] = [0x0A000A1120408000,0x0A000A1100000000,0x0A000A0100000000,0x0A000A0100000000,0x0A000A1020408000,0x0A000A1000000000,0x0A000A0000000000,0x0A000A0000000000,0x0A000A1120400000,0x0A000A1100000000,0x0A000A0100000000,0x0A000A0100000000,0x0A000A1020400000,0x0A000A1000000000,0x0A000A0000000000,0x0A000A0000000000,0x0A000A1120000000,0x0A000A1100000000,0x0A000A0100000000,0x0A000A0100000000,0x0A000A1020000000,0x0A000A1000000000,0x0A000A0000000000,0x0A000A0000000000,0x0A000A1120000000,0x0A000A1100000000,0x0A000A0100000000,0x0A000A0100000000,0x0A000A1020000000,0x0A000A1000000000,0x0A000A0000000000,0x0A000A0000000000,];
-const ATTACK_TABLE_51: [Bitboard; 32] = [0x1400142241800000,0x1400142040800000,0x1400142241000000,0x1400142040000000,0x1400140201000000,0x1400140000000000,0x1400140201000000,0x1400140000000000,0x1400142201000000,0x1400142000000000,0x1400142201000000,0x1400142000000000,0x1400140201000000,0x1400140000000000,0x1400140201000000,0x1400140000000000,0x1400142240800000,0x1400142040800000,0x1400142240000000,0x1400142040000000,0x1400140200000000,0x1400140000000000,0x1400140200000000,0x1400140000000000,0x1400142200000000,0x1400142000000000,0x1400142200000000,0x1400142000000000,0x1400140200000000,0x1400140000000000,0x1400140200000000,0x1400140000000000,];
-const ATTACK_TABLE_52: [Bitboard; 32] = [0x2800284482010000,0x2800280000000000,0x2800284080000000,0x2800284480000000,0x2800284482000000,0x2800284080000000,0x2800284080000000,0x2800284480000000,0x2800280402010000,0x2800284080000000,0x2800280000000000,0x2800280400000000,0x2800280402000000,0x2800280000000000,0x2800280000000000,0x2800280400000000,0x2800284402010000,0x2800280000000000,0x2800284000000000,0x2800284400000000,0x2800284402000000,0x2800284000000000,0x2800284000000000,0x2800284400000000,0x2800280402010000,0x2800284000000000,0x2800280000000000,0x2800280400000000,0x2800280402000000,0x2800280000000000,0x2800280000000000,0x2800280400000000,];
-const ATTACK_TABLE_53: [Bitboard; 32] = [0x5000508804020100,0x5000508804000000,0x5000508000000000,0x5000508000000000,0x5000508800000000,0x5000508800000000,0x5000508000000000,0x5000508000000000,0x5000500804020100,0x5000500804000000,0x5000500000000000,0x5000500000000000,0x5000500800000000,0x5000500800000000,0x5000500000000000,0x5000500000000000,0x5000508804020000,0x5000508804000000,0x5000508000000000,0x5000508000000000,0x5000508800000000,0x5000508800000000,0x5000508000000000,0x5000508000000000,0x5000500804020000,0x5000500804000000,0x5000500000000000,0x5000500000000000,0x5000500800000000,0x5000500800000000,0x5000500000000000,0x5000500000000000,];
-const ATTACK_TABLE_54: [Bitboard; 32] = [0xA000A01008040201,0xA000A01008000000,0xA000A01008040200,0xA000A01008000000,0xA000A01000000000,0xA000A01000000000,0xA000A01000000000,0xA000A01000000000,0xA000A00000000000,0xA000A00000000000,0xA000A00000000000,0xA000A00000000000,0xA000A00000000000,0xA000A00000000000,0xA000A00000000000,0xA000A00000000000,0xA000A01008040000,0xA000A01008000000,0xA000A01008040000,0xA000A01008000000,0xA000A01000000000,0xA000A01000000000,0xA000A01000000000,0xA000A01000000000,0xA000A00000000000,0xA000A00000000000,0xA000A00000000000,0xA000A00000000000,0xA000A00000000000,0xA000A00000000000,0xA000A00000000000,0xA000A00000000000,];
-const ATTACK_TABLE_55: [Bitboard; 32] = [0x4000402010080402,0x4000402000000000,0x4000402000000000,0x4000400000000000,0x4000400000000000,0x4000400000000000,0x4000400000000000,0x4000402010080000,0x4000402010080400,0x4000402000000000,0x4000402000000000,0x4000400000000000,0x4000400000000000,0x4000400000000000,0x4000400000000000,0x4000402010080000,0x4000402010000000,0x4000402000000000,0x4000402000000000,0x4000400000000000,0x4000400000000000,0x4000400000000000,0x4000400000000000,0x4000402010000000,0x4000402010000000,0x4000402000000000,0x4000402000000000,0x4000400000000000,0x4000400000000000,0x4000400000000000,0x4000400000000000,0x4000402010000000,];
-const ATTACK_TABLE_56: [Bitboard; 64] = [0x0002040810204080,0x0002000000000000,0x0002040000000000,0x0002040810000000,0x0002040810200000,0x0002040000000000,0x0002040000000000,0x0002040810000000,0x0002000000000000,0x0002040000000000,0x0002000000000000,0x0002000000000000,0x0002000000000000,0x0002000000000000,0x0002000000000000,0x0002000000000000,0x0002040810204000,0x0002000000000000,0x0002040000000000,0x0002040810000000,0x0002040810200000,0x0002040000000000,0x0002040000000000,0x0002040810000000,0x0002000000000000,0x0002040000000000,0x0002000000000000,0x0002000000000000,0x0002000000000000,0x0002000000000000,0x0002000000000000,0x0002000000000000,0x0002040800000000,0x0002000000000000,0x0002040000000000,0x0002040800000000,0x0002040800000000,0x0002040000000000,0x0002040000000000,0x0002040800000000,0x0002000000000000,0x0002040000000000,0x0002000000000000,0x0002000000000000,0x0002000000000000,0x0002000000000000,0x0002000000000000,0x0002000000000000,0x0002040800000000,0x0002000000000000,0x0002040000000000,0x0002040800000000,0x0002040800000000,0x0002040000000000,0x0002040000000000,0x0002040800000000,0x0002000000000000,0x0002040000000000,0x0002000000000000,0x0002000000000000,0x0002000000000000,0x0002000000000000,0x0002000000000000,0x0002000000000000,];
-const ATTACK_TABLE_57: [Bitboard; 32] = [0x0005081020408000,0x0005000000000000,0x0005080000000000,0x0005000000000000,0x0005081000000000,0x0005000000000000,0x0005081020400000,0x0005000000000000,0x0005080000000000,0x0005000000000000,0x0005081000000000,0x0005000000000000,0x0005080000000000,0x0005000000000000,0x0005080000000000,0x0005000000000000,0x0005081020000000,0x0005000000000000,0x0005080000000000,0x0005000000000000,0x0005081000000000,0x0005000000000000,0x0005081020000000,0x0005000000000000,0x0005080000000000,0x0005000000000000,0x0005081000000000,0x0005000000000000,0x0005080000000000,0x0005000000000000,0x0005080000000000,0x0005000000000000,];
-const ATTACK_TABLE_58: [Bitboard; 32] = [0x000A112040800000,0x000A110000000000,0x000A102040800000,0x000A100000000000,0x000A112040000000,0x000A110000000000,0x000A102040000000,0x000A100000000000,0x000A010000000000,0x000A010000000000,0x000A000000000000,0x000A000000000000,0x000A010000000000,0x000A010000000000,0x000A000000000000,0x000A000000000000,0x000A112000000000,0x000A110000000000,0x000A102000000000,0x000A100000000000,0x000A112000000000,0x000A110000000000,0x000A102000000000,0x000A100000000000,0x000A010000000000,0x000A010000000000,0x000A000000000000,0x000A000000000000,0x000A010000000000,0x000A010000000000,0x000A000000000000,0x000A000000000000,];
-const ATTACK_TABLE_59: [Bitboard; 32] = [0x0014224180000000,0x0014224100000000,0x0014220100000000,0x0014220100000000,0x0014204080000000,0x0014204000000000,0x0014200000000000,0x0014200000000000,0x0014224080000000,0x0014224000000000,0x0014220000000000,0x0014220000000000,0x0014204080000000,0x0014204000000000,0x0014200000000000,0x0014200000000000,0x0014020100000000,0x0014020100000000,0x0014020100000000,0x0014020100000000,0x0014000000000000,0x0014000000000000,0x0014000000000000,0x0014000000000000,0x0014020000000000,0x0014020000000000,0x0014020000000000,0x0014020000000000,0x0014000000000000,0x0014000000000000,0x0014000000000000,0x0014000000000000,];
-const ATTACK_TABLE_60: [Bitboard; 32] = [0x0028448201000000,0x0028400000000000,0x0028448000000000,0x0028440201000000,0x0028408000000000,0x0028440000000000,0x0028408000000000,0x0028400000000000,0x0028040200000000,0x0028400000000000,0x0028040000000000,0x0028040200000000,0x0028000000000000,0x0028040000000000,0x0028000000000000,0x0028000000000000,0x0028040201000000,0x0028000000000000,0x0028040000000000,0x0028040201000000,0x0028000000000000,0x0028040000000000,0x0028000000000000,0x0028000000000000,0x0028448200000000,0x0028000000000000,0x0028448000000000,0x0028440200000000,0x0028408000000000,0x0028440000000000,0x0028408000000000,0x0028400000000000,];
-const ATTACK_TABLE_61: [Bitboard; 32] = [0x0050880402010000,0x0050080400000000,0x0050880000000000,0x0050080000000000,0x0050000000000000,0x0050000000000000,0x0050000000000000,0x0050000000000000,0x0050880400000000,0x0050880402000000,0x0050880000000000,0x0050880000000000,0x0050800000000000,0x0050000000000000,0x0050800000000000,0x0050000000000000,0x0050080402010000,0x0050880400000000,0x0050080000000000,0x0050880000000000,0x0050800000000000,0x0050800000000000,0x0050800000000000,0x0050800000000000,0x0050080400000000,0x0050080402000000,0x0050080000000000,0x0050080000000000,0x0050000000000000,0x0050800000000000,0x0050000000000000,0x0050800000000000,];
-const ATTACK_TABLE_62: [Bitboard; 32] = [0x00A0100804020100,0x00A0000000000000,0x00A0100000000000,0x00A0100000000000,0x00A0100000000000,0x00A0100800000000,0x00A0100804000000,0x00A0100804020000,0x00A0000000000000,0x00A0100000000000,0x00A0100000000000,0x00A0100000000000,0x00A0000000000000,0x00A0100804000000,0x00A0000000000000,0x00A0000000000000,0x00A0000000000000,0x00A0100000000000,0x00A0000000000000,0x00A0000000000000,0x00A0000000000000,0x00A0000000000000,0x00A0000000000000,0x00A0000000000000,0x00A0100800000000,0x00A0000000000000,0x00A0000000000000,0x00A0000000000000,0x00A0100000000000,0x00A0000000000000,0x00A0100800000000,0x00A0100800000000,];
-const ATTACK_TABLE_63: [Bitboard; 64] = [0x0040201008040201,0x0040000000000000,0x0040201008040000,0x0040000000000000,0x0040201000000000,0x0040000000000000,0x0040201000000000,0x0040000000000000,0x0040200000000000,0x0040201008000000,0x0040200000000000,0x0040201008000000,0x0040200000000000,0x0040201000000000,0x0040200000000000,0x0040201000000000,0x0040201008040200,0x0040200000000000,0x0040201008040000,0x0040200000000000,0x0040201000000000,0x0040200000000000,0x0040201000000000,0x0040200000000000,0x0040200000000000,0x0040201008000000,0x0040200000000000,0x0040201008000000,0x0040200000000000,0x0040201000000000,0x0040200000000000,0x0040201000000000,0x0040000000000000,0x0040200000000000,0x0040000000000000,0x0040200000000000,0x0040000000000000,0x0040200000000000,0x0040000000000000,0x0040200000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,];
+static ATTACK_TABLE_0: [Bitboard; 64] = [0x8040201008040200,0x0000000000000200,0x0000201008040200,0x0000000000000200,0x0000000008040200,0x0000000000000200,0x0000000008040200,0x0000000000000200,0x0040201008040200,0x0000000000000200,0x0000201008040200,0x0000000000000200,0x0000000008040200,0x0000000000000200,0x0000000008040200,0x0000000000000200,0x0000001008040200,0x0000000000000200,0x0000001008040200,0x0000000000000200,0x0000000008040200,0x0000000000000200,0x0000000008040200,0x0000000000000200,0x0000001008040200,0x0000000000000200,0x0000001008040200,0x0000000000000200,0x0000000008040200,0x0000000000000200,0x0000000008040200,0x0000000000000200,0x0000000000040200,0x0000000000000200,0x0000000000040200,0x0000000000000200,0x0000000000040200,0x0000000000000200,0x0000000000040200,0x0000000000000200,0x0000000000040200,0x0000000000000200,0x0000000000040200,0x0000000000000200,0x0000000000040200,0x0000000000000200,0x0000000000040200,0x0000000000000200,0x0000000000040200,0x0000000000000200,0x0000000000040200,0x0000000000000200,0x0000000000040200,0x0000000000000200,0x0000000000040200,0x0000000000000200,0x0000000000040200,0x0000000000000200,0x0000000000040200,0x0000000000000200,0x0000000000040200,0x0000000000000200,0x0000000000040200,0x0000000000000200,];
+static ATTACK_TABLE_1: [Bitboard; 32] = [0x0080402010080500,0x0000000000080500,0x0000402010080500,0x0000000000080500,0x0000000010080500,0x0000000000080500,0x0000000010080500,0x0000000000080500,0x0000002010080500,0x0000000000080500,0x0000002010080500,0x0000000000080500,0x0000000010080500,0x0000000000080500,0x0000000010080500,0x0000000000080500,0x0000000000000500,0x0000000000000500,0x0000000000000500,0x0000000000000500,0x0000000000000500,0x0000000000000500,0x0000000000000500,0x0000000000000500,0x0000000000000500,0x0000000000000500,0x0000000000000500,0x0000000000000500,0x0000000000000500,0x0000000000000500,0x0000000000000500,0x0000000000000500,];
+static ATTACK_TABLE_2: [Bitboard; 32] = [0x0000804020110A00,0x0000804020100A00,0x0000000000110A00,0x0000000000100A00,0x0000000000010A00,0x0000000000000A00,0x0000000000010A00,0x0000000000000A00,0x0000000020110A00,0x0000000020100A00,0x0000000000110A00,0x0000000000100A00,0x0000000000010A00,0x0000000000000A00,0x0000000000010A00,0x0000000000000A00,0x0000004020110A00,0x0000004020100A00,0x0000000000110A00,0x0000000000100A00,0x0000000000010A00,0x0000000000000A00,0x0000000000010A00,0x0000000000000A00,0x0000000020110A00,0x0000000020100A00,0x0000000000110A00,0x0000000000100A00,0x0000000000010A00,0x0000000000000A00,0x0000000000010A00,0x0000000000000A00,];
+static ATTACK_TABLE_3: [Bitboard; 32] = [0x0000008041221400,0x0000008040221400,0x0000000001021400,0x0000000000021400,0x0000000000001400,0x0000000000001400,0x0000000000201400,0x0000000000201400,0x0000000041221400,0x0000000040221400,0x0000000001021400,0x0000000000021400,0x0000008040201400,0x0000008040201400,0x0000000000001400,0x0000000000001400,0x0000000001021400,0x0000000000021400,0x0000000001221400,0x0000000000221400,0x0000000040201400,0x0000000040201400,0x0000000000001400,0x0000000000001400,0x0000000001021400,0x0000000000021400,0x0000000001221400,0x0000000000221400,0x0000000000001400,0x0000000000001400,0x0000000000201400,0x0000000000201400,];
+static ATTACK_TABLE_4: [Bitboard; 32] = [0x0000000182442800,0x0000000002042800,0x0000000102442800,0x0000000002042800,0x0000000080402800,0x0000000000002800,0x0000000000402800,0x0000000000002800,0x0000000080442800,0x0000000000042800,0x0000000000442800,0x0000000000042800,0x0000000080402800,0x0000000000002800,0x0000000000402800,0x0000000000002800,0x0000000082442800,0x0000000102042800,0x0000000002442800,0x0000000102042800,0x0000000080402800,0x0000000000002800,0x0000000000402800,0x0000000000002800,0x0000000080442800,0x0000000000042800,0x0000000000442800,0x0000000000042800,0x0000000080402800,0x0000000000002800,0x0000000000402800,0x0000000000002800,];
+static ATTACK_TABLE_5: [Bitboard; 32] = [0x0000010204885000,0x0000000000805000,0x0000000000885000,0x0000000000805000,0x0000010204085000,0x0000000000005000,0x0000000000085000,0x0000000000005000,0x0000000204885000,0x0000000000805000,0x0000000000885000,0x0000000000805000,0x0000000204085000,0x0000000000005000,0x0000000000085000,0x0000000000005000,0x0000000000885000,0x0000000000805000,0x0000000004885000,0x0000000000805000,0x0000000000085000,0x0000000000005000,0x0000000004085000,0x0000000000005000,0x0000000000885000,0x0000000000805000,0x0000000004885000,0x0000000000805000,0x0000000000085000,0x0000000000005000,0x0000000004085000,0x0000000000005000,];
+static ATTACK_TABLE_6: [Bitboard; 32] = [0x000102040810A000,0x000000040810A000,0x000000000810A000,0x000000000810A000,0x000000000010A000,0x000000000010A000,0x000000000010A000,0x000000000010A000,0x000000000000A000,0x000000000000A000,0x000000000000A000,0x000000000000A000,0x000000000000A000,0x000000000000A000,0x000000000000A000,0x000000000000A000,0x000002040810A000,0x000000040810A000,0x000000000810A000,0x000000000810A000,0x000000000010A000,0x000000000010A000,0x000000000010A000,0x000000000010A000,0x000000000000A000,0x000000000000A000,0x000000000000A000,0x000000000000A000,0x000000000000A000,0x000000000000A000,0x000000000000A000,0x000000000000A000,];
+static ATTACK_TABLE_7: [Bitboard; 64] = [0x0102040810204000,0x0000000000204000,0x0000040810204000,0x0000000000204000,0x0002040810204000,0x0000000000204000,0x0000040810204000,0x0000000000204000,0x0000000000004000,0x0000000000004000,0x0000000000004000,0x0000000000004000,0x0000000000004000,0x0000000000004000,0x0000000000004000,0x0000000000004000,0x0000000010204000,0x0000000000204000,0x0000000010204000,0x0000000000204000,0x0000000010204000,0x0000000000204000,0x0000000010204000,0x0000000000204000,0x0000000000004000,0x0000000000004000,0x0000000000004000,0x0000000000004000,0x0000000000004000,0x0000000000004000,0x0000000000004000,0x0000000000004000,0x0000000810204000,0x0000000000204000,0x0000000810204000,0x0000000000204000,0x0000000810204000,0x0000000000204000,0x0000000810204000,0x0000000000204000,0x0000000000004000,0x0000000000004000,0x0000000000004000,0x0000000000004000,0x0000000000004000,0x0000000000004000,0x0000000000004000,0x0000000000004000,0x0000000010204000,0x0000000000204000,0x0000000010204000,0x0000000000204000,0x0000000010204000,0x0000000000204000,0x0000000010204000,0x0000000000204000,0x0000000000004000,0x0000000000004000,0x0000000000004000,0x0000000000004000,0x0000000000004000,0x0000000000004000,0x0000000000004000,0x0000000000004000,];
+static ATTACK_TABLE_8: [Bitboard; 32] = [0x4020100804020002,0x0000000000020002,0x0000100804020002,0x0000000000020002,0x0000000804020002,0x0000000000020002,0x0000000804020002,0x0000000000020002,0x0020100804020002,0x0000000000020002,0x0000100804020002,0x0000000000020002,0x0000000804020002,0x0000000000020002,0x0000000804020002,0x0000000000020002,0x0000000004020002,0x0000000000020002,0x0000000004020002,0x0000000000020002,0x0000000004020002,0x0000000000020002,0x0000000004020002,0x0000000000020002,0x0000000004020002,0x0000000000020002,0x0000000004020002,0x0000000000020002,0x0000000004020002,0x0000000000020002,0x0000000004020002,0x0000000000020002,];
+static ATTACK_TABLE_9: [Bitboard; 32] = [0x8040201008050005,0x0040201008050005,0x0000000008050005,0x0000000008050005,0x0000000000050005,0x0000000000050005,0x0000000000050005,0x0000000000050005,0x0000001008050005,0x0000001008050005,0x0000000008050005,0x0000000008050005,0x0000000000050005,0x0000000000050005,0x0000000000050005,0x0000000000050005,0x0000201008050005,0x0000201008050005,0x0000000008050005,0x0000000008050005,0x0000000000050005,0x0000000000050005,0x0000000000050005,0x0000000000050005,0x0000001008050005,0x0000001008050005,0x0000000008050005,0x0000000008050005,0x0000000000050005,0x0000000000050005,0x0000000000050005,0x0000000000050005,];
+static ATTACK_TABLE_10: [Bitboard; 32] = [0x00804020110A000A,0x00000020110A000A,0x00804020100A000A,0x00000020100A000A,0x00000000110A000A,0x00000000110A000A,0x00000000100A000A,0x00000000100A000A,0x00000000010A000A,0x00000000010A000A,0x00000000000A000A,0x00000000000A000A,0x00000000010A000A,0x00000000010A000A,0x00000000000A000A,0x00000000000A000A,0x00004020110A000A,0x00000020110A000A,0x00004020100A000A,0x00000020100A000A,0x00000000110A000A,0x00000000110A000A,0x00000000100A000A,0x00000000100A000A,0x00000000010A000A,0x00000000010A000A,0x00000000000A000A,0x00000000000A000A,0x00000000010A000A,0x00000000010A000A,0x00000000000A000A,0x00000000000A000A,];
+static ATTACK_TABLE_11: [Bitboard; 32] = [0x0000804122140014,0x0000804022140014,0x0000804020140014,0x0000804020140014,0x0000000122140014,0x0000000022140014,0x0000000020140014,0x0000000020140014,0x0000000102140014,0x0000000002140014,0x0000000000140014,0x0000000000140014,0x0000000102140014,0x0000000002140014,0x0000000000140014,0x0000000000140014,0x0000004122140014,0x0000004022140014,0x0000004020140014,0x0000004020140014,0x0000000122140014,0x0000000022140014,0x0000000020140014,0x0000000020140014,0x0000000102140014,0x0000000002140014,0x0000000000140014,0x0000000000140014,0x0000000102140014,0x0000000002140014,0x0000000000140014,0x0000000000140014,];
+static ATTACK_TABLE_12: [Bitboard; 32] = [0x0000018244280028,0x0000010244280028,0x0000010204280028,0x0000010204280028,0x0000008044280028,0x0000000044280028,0x0000000004280028,0x0000000004280028,0x0000008244280028,0x0000000244280028,0x0000000204280028,0x0000000204280028,0x0000008044280028,0x0000000044280028,0x0000000004280028,0x0000000004280028,0x0000008040280028,0x0000000040280028,0x0000000000280028,0x0000000000280028,0x0000008040280028,0x0000000040280028,0x0000000000280028,0x0000000000280028,0x0000008040280028,0x0000000040280028,0x0000000000280028,0x0000000000280028,0x0000008040280028,0x0000000040280028,0x0000000000280028,0x0000000000280028,];
+static ATTACK_TABLE_13: [Bitboard; 32] = [0x0001020488500050,0x0000000080500050,0x0000000488500050,0x0000000080500050,0x0001020408500050,0x0000000000500050,0x0000000408500050,0x0000000000500050,0x0000000088500050,0x0000000080500050,0x0000000088500050,0x0000000080500050,0x0000000008500050,0x0000000000500050,0x0000000008500050,0x0000000000500050,0x0000000488500050,0x0000000080500050,0x0000020488500050,0x0000000080500050,0x0000000408500050,0x0000000000500050,0x0000020408500050,0x0000000000500050,0x0000000088500050,0x0000000080500050,0x0000000088500050,0x0000000080500050,0x0000000008500050,0x0000000000500050,0x0000000008500050,0x0000000000500050,];
+static ATTACK_TABLE_14: [Bitboard; 32] = [0x0102040810A000A0,0x0000040810A000A0,0x0000000000A000A0,0x0000000000A000A0,0x0000000010A000A0,0x0000000010A000A0,0x0000000000A000A0,0x0000000000A000A0,0x0000000810A000A0,0x0000000810A000A0,0x0000000000A000A0,0x0000000000A000A0,0x0000000010A000A0,0x0000000010A000A0,0x0000000000A000A0,0x0000000000A000A0,0x0000000010A000A0,0x0000000010A000A0,0x0000000000A000A0,0x0000000000A000A0,0x0002040810A000A0,0x0000040810A000A0,0x0000000000A000A0,0x0000000000A000A0,0x0000000010A000A0,0x0000000010A000A0,0x0000000000A000A0,0x0000000000A000A0,0x0000000810A000A0,0x0000000810A000A0,0x0000000000A000A0,0x0000000000A000A0,];
+static ATTACK_TABLE_15: [Bitboard; 32] = [0x0204081020400040,0x0000081020400040,0x0000001020400040,0x0000001020400040,0x0000000000400040,0x0000000000400040,0x0000000000400040,0x0000000000400040,0x0000000020400040,0x0000000020400040,0x0000000020400040,0x0000000020400040,0x0000000000400040,0x0000000000400040,0x0000000000400040,0x0000000000400040,0x0004081020400040,0x0000081020400040,0x0000001020400040,0x0000001020400040,0x0000000000400040,0x0000000000400040,0x0000000000400040,0x0000000000400040,0x0000000020400040,0x0000000020400040,0x0000000020400040,0x0000000020400040,0x0000000000400040,0x0000000000400040,0x0000000000400040,0x0000000000400040,];
+static ATTACK_TABLE_16: [Bitboard; 32] = [0x2010080402000204,0x0000000002000200,0x0000000002000200,0x0010080402000204,0x0000000402000204,0x0000000002000200,0x0000000002000200,0x0000000402000204,0x0000080402000204,0x0000000002000200,0x0000000002000200,0x0000080402000204,0x0000000402000204,0x0000000002000200,0x0000000002000204,0x0000000402000204,0x2010080402000200,0x0000000002000204,0x0000000002000204,0x0010080402000200,0x0000000402000200,0x0000000002000204,0x0000000002000204,0x0000000402000200,0x0000080402000200,0x0000000002000204,0x0000000002000204,0x0000080402000200,0x0000000402000200,0x0000000002000204,0x0000000002000200,0x0000000402000200,];
+static ATTACK_TABLE_17: [Bitboard; 32] = [0x4020100805000508,0x0000100805000508,0x0000000005000508,0x0000000005000508,0x0000000805000500,0x0000000805000500,0x0000000005000500,0x0000000005000500,0x0020100805000500,0x0000100805000500,0x0000000005000500,0x0000000005000500,0x0000000805000508,0x0000000805000508,0x0000000005000508,0x0000000005000508,0x0020100805000508,0x0000100805000508,0x0000000005000508,0x0000000005000508,0x0000000805000500,0x0000000805000500,0x0000000005000500,0x0000000005000500,0x4020100805000500,0x0000100805000500,0x0000000005000500,0x0000000005000500,0x0000000805000508,0x0000000805000508,0x0000000005000508,0x0000000005000508,];
+static ATTACK_TABLE_18: [Bitboard; 128] = [0x804020110A000A11,0x000000110A000A11,0x804020110A000A10,0x000000110A000A10,0x000020110A000A11,0x000000110A000A11,0x000020110A000A10,0x000000110A000A10,0x804020110A000A01,0x000000110A000A01,0x804020110A000A00,0x000000110A000A00,0x000020110A000A01,0x000000110A000A01,0x000020110A000A00,0x000000110A000A00,0x804020100A000A11,0x000000100A000A11,0x804020100A000A10,0x000000100A000A10,0x000020100A000A11,0x000000100A000A11,0x000020100A000A10,0x000000100A000A10,0x804020100A000A01,0x000000100A000A01,0x804020100A000A00,0x000000100A000A00,0x000020100A000A01,0x000000100A000A01,0x000020100A000A00,0x000000100A000A00,0x000000010A000A11,0x000000010A000A11,0x000000010A000A10,0x000000010A000A10,0x000000010A000A11,0x000000010A000A11,0x000000010A000A10,0x000000010A000A10,0x000000010A000A01,0x000000010A000A01,0x000000010A000A00,0x000000010A000A00,0x000000010A000A01,0x000000010A000A01,0x000000010A000A00,0x000000010A000A00,0x000000000A000A11,0x000000000A000A11,0x000000000A000A10,0x000000000A000A10,0x000000000A000A11,0x000000000A000A11,0x000000000A000A10,0x000000000A000A10,0x000000000A000A01,0x000000000A000A01,0x000000000A000A00,0x000000000A000A00,0x000000000A000A01,0x000000000A000A01,0x000000000A000A00,0x000000000A000A00,0x000000010A000A11,0x000000010A000A11,0x000000010A000A10,0x000000010A000A10,0x000000010A000A11,0x000000010A000A11,0x000000010A000A10,0x000000010A000A10,0x000000010A000A01,0x000000010A000A01,0x000000010A000A00,0x000000010A000A00,0x000000010A000A01,0x000000010A000A01,0x000000010A000A00,0x000000010A000A00,0x000000000A000A11,0x000000000A000A11,0x000000000A000A10,0x000000000A000A10,0x000000000A000A11,0x000000000A000A11,0x000000000A000A10,0x000000000A000A10,0x000000000A000A01,0x000000000A000A01,0x000000000A000A00,0x000000000A000A00,0x000000000A000A01,0x000000000A000A01,0x000000000A000A00,0x000000000A000A00,0x004020110A000A11,0x000000110A000A11,0x004020110A000A10,0x000000110A000A10,0x000020110A000A11,0x000000110A000A11,0x000020110A000A10,0x000000110A000A10,0x004020110A000A01,0x000000110A000A01,0x004020110A000A00,0x000000110A000A00,0x000020110A000A01,0x000000110A000A01,0x000020110A000A00,0x000000110A000A00,0x004020100A000A11,0x000000100A000A11,0x004020100A000A10,0x000000100A000A10,0x000020100A000A11,0x000000100A000A11,0x000020100A000A10,0x000000100A000A10,0x004020100A000A01,0x000000100A000A01,0x004020100A000A00,0x000000100A000A00,0x000020100A000A01,0x000000100A000A01,0x000020100A000A00,0x000000100A000A00,];
+static ATTACK_TABLE_19: [Bitboard; 128] = [0x0080412214001422,0x0000002014001402,0x0080402214001422,0x0000002014001402,0x0000010214001422,0x0000000014001402,0x0000000214001422,0x0000000014001402,0x0000012214001402,0x0000402014001402,0x0000002214001402,0x0000402014001402,0x0000010214001402,0x0000000014001402,0x0000000214001402,0x0000000014001402,0x0000012214001400,0x0080402014001400,0x0000002214001400,0x0080402014001400,0x0000010214001400,0x0000000014001400,0x0000000214001400,0x0000000014001400,0x0000412214001400,0x0000002014001420,0x0000402214001400,0x0000002014001420,0x0000010214001400,0x0000000014001420,0x0000000214001400,0x0000000014001420,0x0000012214001422,0x0080402014001422,0x0000002214001422,0x0080402014001422,0x0000010214001422,0x0000000014001422,0x0000000214001422,0x0000000014001422,0x0000412214001422,0x0000002014001402,0x0000402214001422,0x0000002014001402,0x0000010214001422,0x0000000014001402,0x0000000214001422,0x0000000014001402,0x0080412214001420,0x0000002014001400,0x0080402214001420,0x0000002014001400,0x0000010214001420,0x0000000014001400,0x0000000214001420,0x0000000014001400,0x0000012214001400,0x0000402014001400,0x0000002214001400,0x0000402014001400,0x0000010214001400,0x0000000014001400,0x0000000214001400,0x0000000014001400,0x0080412214001402,0x0000002014001422,0x0080402214001402,0x0000002014001422,0x0000010214001402,0x0000000014001422,0x0000000214001402,0x0000000014001422,0x0000012214001422,0x0000402014001422,0x0000002214001422,0x0000402014001422,0x0000010214001422,0x0000000014001422,0x0000000214001422,0x0000000014001422,0x0000012214001420,0x0080402014001420,0x0000002214001420,0x0080402014001420,0x0000010214001420,0x0000000014001420,0x0000000214001420,0x0000000014001420,0x0000412214001420,0x0000002014001400,0x0000402214001420,0x0000002014001400,0x0000010214001420,0x0000000014001400,0x0000000214001420,0x0000000014001400,0x0000012214001402,0x0080402014001402,0x0000002214001402,0x0080402014001402,0x0000010214001402,0x0000000014001402,0x0000000214001402,0x0000000014001402,0x0000412214001402,0x0000002014001422,0x0000402214001402,0x0000002014001422,0x0000010214001402,0x0000000014001422,0x0000000214001402,0x0000000014001422,0x0080412214001400,0x0000002014001420,0x0080402214001400,0x0000002014001420,0x0000010214001400,0x0000000014001420,0x0000000214001400,0x0000000014001420,0x0000012214001420,0x0000402014001420,0x0000002214001420,0x0000402014001420,0x0000010214001420,0x0000000014001420,0x0000000214001420,0x0000000014001420,];
+static ATTACK_TABLE_20: [Bitboard; 128] = [0x0001824428002844,0x0001024428002804,0x0000804428002844,0x0000004428002804,0x0000824428002800,0x0000020428002840,0x0000804428002800,0x0000000428002840,0x0000804028002800,0x0000000028002840,0x0000804028002800,0x0000000028002840,0x0000824428002844,0x0000024428002804,0x0000804428002844,0x0000004428002804,0x0000804028002844,0x0000004028002804,0x0000804028002844,0x0000004028002804,0x0000804028002800,0x0000000028002840,0x0000804028002800,0x0000000028002840,0x0001824428002840,0x0001024428002800,0x0000804428002840,0x0000004428002800,0x0000804028002844,0x0000004028002804,0x0000804028002844,0x0000004028002804,0x0001020428002804,0x0001024428002844,0x0000000428002804,0x0000004428002844,0x0000824428002840,0x0000024428002800,0x0000804428002840,0x0000004428002800,0x0000804028002840,0x0000004028002800,0x0000804028002840,0x0000004028002800,0x0000020428002804,0x0000024428002844,0x0000000428002804,0x0000004428002844,0x0000000028002804,0x0000004028002844,0x0000000028002804,0x0000004028002844,0x0000804028002840,0x0000004028002800,0x0000804028002840,0x0000004028002800,0x0001020428002800,0x0001024428002840,0x0000000428002800,0x0000004428002840,0x0000000028002804,0x0000004028002844,0x0000000028002804,0x0000004028002844,0x0001020428002844,0x0001020428002804,0x0000000428002844,0x0000000428002804,0x0000020428002800,0x0000024428002840,0x0000000428002800,0x0000004428002840,0x0000000028002800,0x0000004028002840,0x0000000028002800,0x0000004028002840,0x0000020428002844,0x0000020428002804,0x0000000428002844,0x0000000428002804,0x0000000028002844,0x0000000028002804,0x0000000028002844,0x0000000028002804,0x0000000028002800,0x0000004028002840,0x0000000028002800,0x0000004028002840,0x0001020428002840,0x0001020428002800,0x0000000428002840,0x0000000428002800,0x0000000028002844,0x0000000028002804,0x0000000028002844,0x0000000028002804,0x0001824428002804,0x0001020428002844,0x0000804428002804,0x0000000428002844,0x0000020428002840,0x0000020428002800,0x0000000428002840,0x0000000428002800,0x0000000028002840,0x0000000028002800,0x0000000028002840,0x0000000028002800,0x0000824428002804,0x0000020428002844,0x0000804428002804,0x0000000428002844,0x0000804028002804,0x0000000028002844,0x0000804028002804,0x0000000028002844,0x0000000028002840,0x0000000028002800,0x0000000028002840,0x0000000028002800,0x0001824428002800,0x0001020428002840,0x0000804428002800,0x0000000428002840,0x0000804028002804,0x0000000028002844,0x0000804028002804,0x0000000028002844,];
+static ATTACK_TABLE_21: [Bitboard; 128] = [0x0102048850005088,0x0000000850005088,0x0000048850005088,0x0000000850005088,0x0000008850005080,0x0002040850005080,0x0000008850005080,0x0000040850005080,0x0000008050005088,0x0000000050005088,0x0000008050005088,0x0000000050005088,0x0000008050005080,0x0000000050005080,0x0000008050005080,0x0000000050005080,0x0102048850005008,0x0000000850005008,0x0000048850005008,0x0000000850005008,0x0000008850005000,0x0002040850005000,0x0000008850005000,0x0000040850005000,0x0000008050005008,0x0000000050005008,0x0000008050005008,0x0000000050005008,0x0000008050005000,0x0000000050005000,0x0000008050005000,0x0000000050005000,0x0000008850005088,0x0102040850005088,0x0000008850005088,0x0000040850005088,0x0102048850005080,0x0000000850005080,0x0000048850005080,0x0000000850005080,0x0000008050005088,0x0000000050005088,0x0000008050005088,0x0000000050005088,0x0000008050005080,0x0000000050005080,0x0000008050005080,0x0000000050005080,0x0000008850005008,0x0102040850005008,0x0000008850005008,0x0000040850005008,0x0102048850005000,0x0000000850005000,0x0000048850005000,0x0000000850005000,0x0000008050005008,0x0000000050005008,0x0000008050005008,0x0000000050005008,0x0000008050005000,0x0000000050005000,0x0000008050005000,0x0000000050005000,0x0002048850005088,0x0000000850005088,0x0000048850005088,0x0000000850005088,0x0000008850005080,0x0102040850005080,0x0000008850005080,0x0000040850005080,0x0000008050005088,0x0000000050005088,0x0000008050005088,0x0000000050005088,0x0000008050005080,0x0000000050005080,0x0000008050005080,0x0000000050005080,0x0002048850005008,0x0000000850005008,0x0000048850005008,0x0000000850005008,0x0000008850005000,0x0102040850005000,0x0000008850005000,0x0000040850005000,0x0000008050005008,0x0000000050005008,0x0000008050005008,0x0000000050005008,0x0000008050005000,0x0000000050005000,0x0000008050005000,0x0000000050005000,0x0000008850005088,0x0002040850005088,0x0000008850005088,0x0000040850005088,0x0002048850005080,0x0000000850005080,0x0000048850005080,0x0000000850005080,0x0000008050005088,0x0000000050005088,0x0000008050005088,0x0000000050005088,0x0000008050005080,0x0000000050005080,0x0000008050005080,0x0000000050005080,0x0000008850005008,0x0002040850005008,0x0000008850005008,0x0000040850005008,0x0002048850005000,0x0000000850005000,0x0000048850005000,0x0000000850005000,0x0000008050005008,0x0000000050005008,0x0000008050005008,0x0000000050005008,0x0000008050005000,0x0000000050005000,0x0000008050005000,0x0000000050005000,];
+static ATTACK_TABLE_22: [Bitboard; 32] = [0x02040810A000A010,0x00000010A000A010,0x00000000A000A010,0x00000000A000A010,0x00040810A000A010,0x00000010A000A010,0x00000000A000A010,0x00000000A000A010,0x00000810A000A010,0x00000010A000A010,0x00000000A000A010,0x00000000A000A010,0x00000810A000A010,0x00000010A000A010,0x00000000A000A010,0x00000000A000A010,0x02040810A000A000,0x00000010A000A000,0x00000000A000A000,0x00000000A000A000,0x00040810A000A000,0x00000010A000A000,0x00000000A000A000,0x00000000A000A000,0x00000810A000A000,0x00000010A000A000,0x00000000A000A000,0x00000000A000A000,0x00000810A000A000,0x00000010A000A000,0x00000000A000A000,0x00000000A000A000,];
+static ATTACK_TABLE_23: [Bitboard; 32] = [0x0408102040004020,0x0008102040004020,0x0000102040004020,0x0000102040004020,0x0408102040004000,0x0008102040004000,0x0000102040004000,0x0000102040004000,0x0000002040004020,0x0000002040004020,0x0000002040004020,0x0000002040004020,0x0000002040004000,0x0000002040004000,0x0000002040004000,0x0000002040004000,0x0000000040004020,0x0000000040004020,0x0000000040004020,0x0000000040004020,0x0000000040004000,0x0000000040004000,0x0000000040004000,0x0000000040004000,0x0000000040004020,0x0000000040004020,0x0000000040004020,0x0000000040004020,0x0000000040004000,0x0000000040004000,0x0000000040004000,0x0000000040004000,];
+static ATTACK_TABLE_24: [Bitboard; 32] = [0x1008040200020408,0x1008040200020000,0x1008040200020400,0x1008040200020000,0x0000000200020408,0x0000000200020000,0x0000000200020400,0x0000000200020000,0x0000000200020408,0x0000000200020000,0x0000000200020400,0x0000000200020000,0x0000040200020408,0x0000040200020000,0x0000040200020400,0x0000040200020000,0x0000040200020408,0x0000040200020000,0x0000040200020400,0x0000040200020000,0x0000000200020408,0x0000000200020000,0x0000000200020400,0x0000000200020000,0x0000000200020408,0x0000000200020000,0x0000000200020400,0x0000000200020000,0x0008040200020408,0x0008040200020000,0x0008040200020400,0x0008040200020000,];
+static ATTACK_TABLE_25: [Bitboard; 32] = [0x2010080500050810,0x2010080500050800,0x2010080500050000,0x2010080500050000,0x0010080500050810,0x0010080500050800,0x0010080500050000,0x0010080500050000,0x0000080500050810,0x0000080500050800,0x0000080500050000,0x0000080500050000,0x0000080500050810,0x0000080500050800,0x0000080500050000,0x0000080500050000,0x0000000500050810,0x0000000500050800,0x0000000500050000,0x0000000500050000,0x0000000500050810,0x0000000500050800,0x0000000500050000,0x0000000500050000,0x0000000500050810,0x0000000500050800,0x0000000500050000,0x0000000500050000,0x0000000500050810,0x0000000500050800,0x0000000500050000,0x0000000500050000,];
+static ATTACK_TABLE_26: [Bitboard; 128] = [0x4020110A000A1120,0x4020110A000A1100,0x4020110A000A0100,0x4020110A000A0100,0x0000100A000A1020,0x0000100A000A1000,0x0000100A000A0000,0x0000100A000A0000,0x0020110A000A1120,0x0020110A000A1100,0x0020110A000A0100,0x0020110A000A0100,0x0000100A000A1020,0x0000100A000A1000,0x0000100A000A0000,0x0000100A000A0000,0x0000010A000A1120,0x0000010A000A1100,0x0000010A000A0100,0x0000010A000A0100,0x0000000A000A1020,0x0000000A000A1000,0x0000000A000A0000,0x0000000A000A0000,0x0000010A000A1120,0x0000010A000A1100,0x0000010A000A0100,0x0000010A000A0100,0x0000000A000A1020,0x0000000A000A1000,0x0000000A000A0000,0x0000000A000A0000,0x4020110A000A1020,0x4020110A000A1000,0x4020110A000A0000,0x4020110A000A0000,0x4020100A000A1120,0x4020100A000A1100,0x4020100A000A0100,0x4020100A000A0100,0x0020110A000A1020,0x0020110A000A1000,0x0020110A000A0000,0x0020110A000A0000,0x0020100A000A1120,0x0020100A000A1100,0x0020100A000A0100,0x0020100A000A0100,0x0000010A000A1020,0x0000010A000A1000,0x0000010A000A0000,0x0000010A000A0000,0x0000000A000A1120,0x0000000A000A1100,0x0000000A000A0100,0x0000000A000A0100,0x0000010A000A1020,0x0000010A000A1000,0x0000010A000A0000,0x0000010A000A0000,0x0000000A000A1120,0x0000000A000A1100,0x0000000A000A0100,0x0000000A000A0100,0x0000110A000A1120,0x0000110A000A1100,0x0000110A000A0100,0x0000110A000A0100,0x4020100A000A1020,0x4020100A000A1000,0x4020100A000A0000,0x4020100A000A0000,0x0000110A000A1120,0x0000110A000A1100,0x0000110A000A0100,0x0000110A000A0100,0x0020100A000A1020,0x0020100A000A1000,0x0020100A000A0000,0x0020100A000A0000,0x0000010A000A1120,0x0000010A000A1100,0x0000010A000A0100,0x0000010A000A0100,0x0000000A000A1020,0x0000000A000A1000,0x0000000A000A0000,0x0000000A000A0000,0x0000010A000A1120,0x0000010A000A1100,0x0000010A000A0100,0x0000010A000A0100,0x0000000A000A1020,0x0000000A000A1000,0x0000000A000A0000,0x0000000A000A0000,0x0000110A000A1020,0x0000110A000A1000,0x0000110A000A0000,0x0000110A000A0000,0x0000100A000A1120,0x0000100A000A1100,0x0000100A000A0100,0x0000100A000A0100,0x0000110A000A1020,0x0000110A000A1000,0x0000110A000A0000,0x0000110A000A0000,0x0000100A000A1120,0x0000100A000A1100,0x0000100A000A0100,0x0000100A000A0100,0x0000010A000A1020,0x0000010A000A1000,0x0000010A000A0000,0x0000010A000A0000,0x0000000A000A1120,0x0000000A000A1100,0x0000000A000A0100,0x0000000A000A0100,0x0000010A000A1020,0x0000010A000A1000,0x0000010A000A0000,0x0000010A000A0000,0x0000000A000A1120,0x0000000A000A1100,0x0000000A000A0100,0x0000000A000A0100,];
+static ATTACK_TABLE_27: [Bitboard; 512] = [0x8041221400142241,0x0041221400142241,0x8041221400142040,0x0041221400142040,0x8040221400142241,0x0040221400142241,0x8040221400142040,0x0040221400142040,0x8041221400140201,0x0041221400140201,0x8041221400140000,0x0041221400140000,0x8040221400140201,0x0040221400140201,0x8040221400140000,0x0040221400140000,0x8041221400142200,0x0041221400142200,0x8041221400142000,0x0041221400142000,0x8040221400142200,0x0040221400142200,0x8040221400142000,0x0040221400142000,0x8041221400140200,0x0041221400140200,0x8041221400140000,0x0041221400140000,0x8040221400140200,0x0040221400140200,0x8040221400140000,0x0040221400140000,0x8040201400142241,0x0040201400142241,0x8040201400142040,0x0040201400142040,0x8040201400142241,0x0040201400142241,0x8040201400142040,0x0040201400142040,0x8040201400140201,0x0040201400140201,0x8040201400140000,0x0040201400140000,0x8040201400140201,0x0040201400140201,0x8040201400140000,0x0040201400140000,0x8040201400142200,0x0040201400142200,0x8040201400142000,0x0040201400142000,0x8040201400142200,0x0040201400142200,0x8040201400142000,0x0040201400142000,0x8040201400140200,0x0040201400140200,0x8040201400140000,0x0040201400140000,0x8040201400140200,0x0040201400140200,0x8040201400140000,0x0040201400140000,0x0001221400142241,0x0001221400142241,0x0001221400142040,0x0001221400142040,0x0000221400142241,0x0000221400142241,0x0000221400142040,0x0000221400142040,0x0001221400140201,0x0001221400140201,0x0001221400140000,0x0001221400140000,0x0000221400140201,0x0000221400140201,0x0000221400140000,0x0000221400140000,0x0001221400142200,0x0001221400142200,0x0001221400142000,0x0001221400142000,0x0000221400142200,0x0000221400142200,0x0000221400142000,0x0000221400142000,0x0001221400140200,0x0001221400140200,0x0001221400140000,0x0001221400140000,0x0000221400140200,0x0000221400140200,0x0000221400140000,0x0000221400140000,0x0000201400142241,0x0000201400142241,0x0000201400142040,0x0000201400142040,0x0000201400142241,0x0000201400142241,0x0000201400142040,0x0000201400142040,0x0000201400140201,0x0000201400140201,0x0000201400140000,0x0000201400140000,0x0000201400140201,0x0000201400140201,0x0000201400140000,0x0000201400140000,0x0000201400142200,0x0000201400142200,0x0000201400142000,0x0000201400142000,0x0000201400142200,0x0000201400142200,0x0000201400142000,0x0000201400142000,0x0000201400140200,0x0000201400140200,0x0000201400140000,0x0000201400140000,0x0000201400140200,0x0000201400140200,0x0000201400140000,0x0000201400140000,0x0001021400142241,0x0001021400142241,0x0001021400142040,0x0001021400142040,0x0000021400142241,0x0000021400142241,0x0000021400142040,0x0000021400142040,0x0001021400140201,0x0001021400140201,0x0001021400140000,0x0001021400140000,0x0000021400140201,0x0000021400140201,0x0000021400140000,0x0000021400140000,0x0001021400142200,0x0001021400142200,0x0001021400142000,0x0001021400142000,0x0000021400142200,0x0000021400142200,0x0000021400142000,0x0000021400142000,0x0001021400140200,0x0001021400140200,0x0001021400140000,0x0001021400140000,0x0000021400140200,0x0000021400140200,0x0000021400140000,0x0000021400140000,0x0000001400142241,0x0000001400142241,0x0000001400142040,0x0000001400142040,0x0000001400142241,0x0000001400142241,0x0000001400142040,0x0000001400142040,0x0000001400140201,0x0000001400140201,0x0000001400140000,0x0000001400140000,0x0000001400140201,0x0000001400140201,0x0000001400140000,0x0000001400140000,0x0000001400142200,0x0000001400142200,0x0000001400142000,0x0000001400142000,0x0000001400142200,0x0000001400142200,0x0000001400142000,0x0000001400142000,0x0000001400140200,0x0000001400140200,0x0000001400140000,0x0000001400140000,0x0000001400140200,0x0000001400140200,0x0000001400140000,0x0000001400140000,0x0001021400142241,0x0001021400142241,0x0001021400142040,0x0001021400142040,0x0000021400142241,0x0000021400142241,0x0000021400142040,0x0000021400142040,0x0001021400140201,0x0001021400140201,0x0001021400140000,0x0001021400140000,0x0000021400140201,0x0000021400140201,0x0000021400140000,0x0000021400140000,0x0001021400142200,0x0001021400142200,0x0001021400142000,0x0001021400142000,0x0000021400142200,0x0000021400142200,0x0000021400142000,0x0000021400142000,0x0001021400140200,0x0001021400140200,0x0001021400140000,0x0001021400140000,0x0000021400140200,0x0000021400140200,0x0000021400140000,0x0000021400140000,0x0000001400142241,0x0000001400142241,0x0000001400142040,0x0000001400142040,0x0000001400142241,0x0000001400142241,0x0000001400142040,0x0000001400142040,0x0000001400140201,0x0000001400140201,0x0000001400140000,0x0000001400140000,0x0000001400140201,0x0000001400140201,0x0000001400140000,0x0000001400140000,0x0000001400142200,0x0000001400142200,0x0000001400142000,0x0000001400142000,0x0000001400142200,0x0000001400142200,0x0000001400142000,0x0000001400142000,0x0000001400140200,0x0000001400140200,0x0000001400140000,0x0000001400140000,0x0000001400140200,0x0000001400140200,0x0000001400140000,0x0000001400140000,0x8041221400142201,0x0041221400142201,0x8041221400142000,0x0041221400142000,0x8040221400142201,0x0040221400142201,0x8040221400142000,0x0040221400142000,0x8041221400140201,0x0041221400140201,0x8041221400140000,0x0041221400140000,0x8040221400140201,0x0040221400140201,0x8040221400140000,0x0040221400140000,0x8041221400142240,0x0041221400142240,0x8041221400142040,0x0041221400142040,0x8040221400142240,0x0040221400142240,0x8040221400142040,0x0040221400142040,0x8041221400140200,0x0041221400140200,0x8041221400140000,0x0041221400140000,0x8040221400140200,0x0040221400140200,0x8040221400140000,0x0040221400140000,0x8040201400142201,0x0040201400142201,0x8040201400142000,0x0040201400142000,0x8040201400142201,0x0040201400142201,0x8040201400142000,0x0040201400142000,0x8040201400140201,0x0040201400140201,0x8040201400140000,0x0040201400140000,0x8040201400140201,0x0040201400140201,0x8040201400140000,0x0040201400140000,0x8040201400142240,0x0040201400142240,0x8040201400142040,0x0040201400142040,0x8040201400142240,0x0040201400142240,0x8040201400142040,0x0040201400142040,0x8040201400140200,0x0040201400140200,0x8040201400140000,0x0040201400140000,0x8040201400140200,0x0040201400140200,0x8040201400140000,0x0040201400140000,0x0001221400142201,0x0001221400142201,0x0001221400142000,0x0001221400142000,0x0000221400142201,0x0000221400142201,0x0000221400142000,0x0000221400142000,0x0001221400140201,0x0001221400140201,0x0001221400140000,0x0001221400140000,0x0000221400140201,0x0000221400140201,0x0000221400140000,0x0000221400140000,0x0001221400142240,0x0001221400142240,0x0001221400142040,0x0001221400142040,0x0000221400142240,0x0000221400142240,0x0000221400142040,0x0000221400142040,0x0001221400140200,0x0001221400140200,0x0001221400140000,0x0001221400140000,0x0000221400140200,0x0000221400140200,0x0000221400140000,0x0000221400140000,0x0000201400142201,0x0000201400142201,0x0000201400142000,0x0000201400142000,0x0000201400142201,0x0000201400142201,0x0000201400142000,0x0000201400142000,0x0000201400140201,0x0000201400140201,0x0000201400140000,0x0000201400140000,0x0000201400140201,0x0000201400140201,0x0000201400140000,0x0000201400140000,0x0000201400142240,0x0000201400142240,0x0000201400142040,0x0000201400142040,0x0000201400142240,0x0000201400142240,0x0000201400142040,0x0000201400142040,0x0000201400140200,0x0000201400140200,0x0000201400140000,0x0000201400140000,0x0000201400140200,0x0000201400140200,0x0000201400140000,0x0000201400140000,0x0001021400142201,0x0001021400142201,0x0001021400142000,0x0001021400142000,0x0000021400142201,0x0000021400142201,0x0000021400142000,0x0000021400142000,0x0001021400140201,0x0001021400140201,0x0001021400140000,0x0001021400140000,0x0000021400140201,0x0000021400140201,0x0000021400140000,0x0000021400140000,0x0001021400142240,0x0001021400142240,0x0001021400142040,0x0001021400142040,0x0000021400142240,0x0000021400142240,0x0000021400142040,0x0000021400142040,0x0001021400140200,0x0001021400140200,0x0001021400140000,0x0001021400140000,0x0000021400140200,0x0000021400140200,0x0000021400140000,0x0000021400140000,0x0000001400142201,0x0000001400142201,0x0000001400142000,0x0000001400142000,0x0000001400142201,0x0000001400142201,0x0000001400142000,0x0000001400142000,0x0000001400140201,0x0000001400140201,0x0000001400140000,0x0000001400140000,0x0000001400140201,0x0000001400140201,0x0000001400140000,0x0000001400140000,0x0000001400142240,0x0000001400142240,0x0000001400142040,0x0000001400142040,0x0000001400142240,0x0000001400142240,0x0000001400142040,0x0000001400142040,0x0000001400140200,0x0000001400140200,0x0000001400140000,0x0000001400140000,0x0000001400140200,0x0000001400140200,0x0000001400140000,0x0000001400140000,0x0001021400142201,0x0001021400142201,0x0001021400142000,0x0001021400142000,0x0000021400142201,0x0000021400142201,0x0000021400142000,0x0000021400142000,0x0001021400140201,0x0001021400140201,0x0001021400140000,0x0001021400140000,0x0000021400140201,0x0000021400140201,0x0000021400140000,0x0000021400140000,0x0001021400142240,0x0001021400142240,0x0001021400142040,0x0001021400142040,0x0000021400142240,0x0000021400142240,0x0000021400142040,0x0000021400142040,0x0001021400140200,0x0001021400140200,0x0001021400140000,0x0001021400140000,0x0000021400140200,0x0000021400140200,0x0000021400140000,0x0000021400140000,0x0000001400142201,0x0000001400142201,0x0000001400142000,0x0000001400142000,0x0000001400142201,0x0000001400142201,0x0000001400142000,0x0000001400142000,0x0000001400140201,0x0000001400140201,0x0000001400140000,0x0000001400140000,0x0000001400140201,0x0000001400140201,0x0000001400140000,0x0000001400140000,0x0000001400142240,0x0000001400142240,0x0000001400142040,0x0000001400142040,0x0000001400142240,0x0000001400142240,0x0000001400142040,0x0000001400142040,0x0000001400140200,0x0000001400140200,0x0000001400140000,0x0000001400140000,0x0000001400140200,0x0000001400140200,0x0000001400140000,0x0000001400140000,];
+static ATTACK_TABLE_28: [Bitboard; 512] = [0x0182442800284482,0x0080442800284482,0x0082442800280000,0x0080442800280000,0x0182442800280400,0x0080442800280400,0x0082442800284000,0x0080442800284000,0x0080402800284482,0x0080402800284482,0x0080402800280000,0x0080402800280000,0x0080402800280400,0x0080402800280400,0x0080402800284000,0x0080402800284000,0x0102442800284482,0x0000442800284482,0x0002442800280000,0x0000442800280000,0x0102442800280400,0x0000442800280400,0x0002442800284000,0x0000442800284000,0x0000402800284482,0x0000402800284482,0x0000402800280000,0x0000402800280000,0x0000402800280400,0x0000402800280400,0x0000402800284000,0x0000402800284000,0x0102042800284482,0x0000042800284482,0x0002042800280000,0x0000042800280000,0x0102042800280400,0x0000042800280400,0x0002042800284000,0x0000042800284000,0x0000002800284482,0x0000002800284482,0x0000002800280000,0x0000002800280000,0x0000002800280400,0x0000002800280400,0x0000002800284000,0x0000002800284000,0x0102042800284482,0x0000042800284482,0x0002042800280000,0x0000042800280000,0x0102042800280400,0x0000042800280400,0x0002042800284000,0x0000042800284000,0x0000002800284482,0x0000002800284482,0x0000002800280000,0x0000002800280000,0x0000002800280400,0x0000002800280400,0x0000002800284000,0x0000002800284000,0x0182442800284080,0x0080442800284080,0x0182442800284402,0x0080442800284402,0x0182442800280000,0x0080442800280000,0x0182442800280400,0x0080442800280400,0x0080402800284080,0x0080402800284080,0x0080402800284402,0x0080402800284402,0x0080402800280000,0x0080402800280000,0x0080402800280400,0x0080402800280400,0x0102442800284080,0x0000442800284080,0x0102442800284402,0x0000442800284402,0x0102442800280000,0x0000442800280000,0x0102442800280400,0x0000442800280400,0x0000402800284080,0x0000402800284080,0x0000402800284402,0x0000402800284402,0x0000402800280000,0x0000402800280000,0x0000402800280400,0x0000402800280400,0x0102042800284080,0x0000042800284080,0x0102042800284402,0x0000042800284402,0x0102042800280000,0x0000042800280000,0x0102042800280400,0x0000042800280400,0x0000002800284080,0x0000002800284080,0x0000002800284402,0x0000002800284402,0x0000002800280000,0x0000002800280000,0x0000002800280400,0x0000002800280400,0x0102042800284080,0x0000042800284080,0x0102042800284402,0x0000042800284402,0x0102042800280000,0x0000042800280000,0x0102042800280400,0x0000042800280400,0x0000002800284080,0x0000002800284080,0x0000002800284402,0x0000002800284402,0x0000002800280000,0x0000002800280000,0x0000002800280400,0x0000002800280400,0x0082442800284482,0x0080442800284482,0x0182442800284000,0x0080442800284000,0x0082442800280400,0x0080442800280400,0x0182442800280000,0x0080442800280000,0x0080402800284482,0x0080402800284482,0x0080402800284000,0x0080402800284000,0x0080402800280400,0x0080402800280400,0x0080402800280000,0x0080402800280000,0x0002442800284482,0x0000442800284482,0x0102442800284000,0x0000442800284000,0x0002442800280400,0x0000442800280400,0x0102442800280000,0x0000442800280000,0x0000402800284482,0x0000402800284482,0x0000402800284000,0x0000402800284000,0x0000402800280400,0x0000402800280400,0x0000402800280000,0x0000402800280000,0x0002042800284482,0x0000042800284482,0x0102042800284000,0x0000042800284000,0x0002042800280400,0x0000042800280400,0x0102042800280000,0x0000042800280000,0x0000002800284482,0x0000002800284482,0x0000002800284000,0x0000002800284000,0x0000002800280400,0x0000002800280400,0x0000002800280000,0x0000002800280000,0x0002042800284482,0x0000042800284482,0x0102042800284000,0x0000042800284000,0x0002042800280400,0x0000042800280400,0x0102042800280000,0x0000042800280000,0x0000002800284482,0x0000002800284482,0x0000002800284000,0x0000002800284000,0x0000002800280400,0x0000002800280400,0x0000002800280000,0x0000002800280000,0x0082442800284080,0x0080442800284080,0x0082442800284402,0x0080442800284402,0x0082442800280000,0x0080442800280000,0x0082442800280400,0x0080442800280400,0x0080402800284080,0x0080402800284080,0x0080402800284402,0x0080402800284402,0x0080402800280000,0x0080402800280000,0x0080402800280400,0x0080402800280400,0x0002442800284080,0x0000442800284080,0x0002442800284402,0x0000442800284402,0x0002442800280000,0x0000442800280000,0x0002442800280400,0x0000442800280400,0x0000402800284080,0x0000402800284080,0x0000402800284402,0x0000402800284402,0x0000402800280000,0x0000402800280000,0x0000402800280400,0x0000402800280400,0x0002042800284080,0x0000042800284080,0x0002042800284402,0x0000042800284402,0x0002042800280000,0x0000042800280000,0x0002042800280400,0x0000042800280400,0x0000002800284080,0x0000002800284080,0x0000002800284402,0x0000002800284402,0x0000002800280000,0x0000002800280000,0x0000002800280400,0x0000002800280400,0x0002042800284080,0x0000042800284080,0x0002042800284402,0x0000042800284402,0x0002042800280000,0x0000042800280000,0x0002042800280400,0x0000042800280400,0x0000002800284080,0x0000002800284080,0x0000002800284402,0x0000002800284402,0x0000002800280000,0x0000002800280000,0x0000002800280400,0x0000002800280400,0x0182442800280402,0x0080442800280402,0x0082442800284000,0x0080442800284000,0x0182442800284480,0x0080442800284480,0x0082442800280000,0x0080442800280000,0x0080402800280402,0x0080402800280402,0x0080402800284000,0x0080402800284000,0x0080402800284480,0x0080402800284480,0x0080402800280000,0x0080402800280000,0x0102442800280402,0x0000442800280402,0x0002442800284000,0x0000442800284000,0x0102442800284480,0x0000442800284480,0x0002442800280000,0x0000442800280000,0x0000402800280402,0x0000402800280402,0x0000402800284000,0x0000402800284000,0x0000402800284480,0x0000402800284480,0x0000402800280000,0x0000402800280000,0x0102042800280402,0x0000042800280402,0x0002042800284000,0x0000042800284000,0x0102042800284480,0x0000042800284480,0x0002042800280000,0x0000042800280000,0x0000002800280402,0x0000002800280402,0x0000002800284000,0x0000002800284000,0x0000002800284480,0x0000002800284480,0x0000002800280000,0x0000002800280000,0x0102042800280402,0x0000042800280402,0x0002042800284000,0x0000042800284000,0x0102042800284480,0x0000042800284480,0x0002042800280000,0x0000042800280000,0x0000002800280402,0x0000002800280402,0x0000002800284000,0x0000002800284000,0x0000002800284480,0x0000002800284480,0x0000002800280000,0x0000002800280000,0x0182442800280000,0x0080442800280000,0x0182442800280402,0x0080442800280402,0x0182442800284080,0x0080442800284080,0x0182442800284400,0x0080442800284400,0x0080402800280000,0x0080402800280000,0x0080402800280402,0x0080402800280402,0x0080402800284080,0x0080402800284080,0x0080402800284400,0x0080402800284400,0x0102442800280000,0x0000442800280000,0x0102442800280402,0x0000442800280402,0x0102442800284080,0x0000442800284080,0x0102442800284400,0x0000442800284400,0x0000402800280000,0x0000402800280000,0x0000402800280402,0x0000402800280402,0x0000402800284080,0x0000402800284080,0x0000402800284400,0x0000402800284400,0x0102042800280000,0x0000042800280000,0x0102042800280402,0x0000042800280402,0x0102042800284080,0x0000042800284080,0x0102042800284400,0x0000042800284400,0x0000002800280000,0x0000002800280000,0x0000002800280402,0x0000002800280402,0x0000002800284080,0x0000002800284080,0x0000002800284400,0x0000002800284400,0x0102042800280000,0x0000042800280000,0x0102042800280402,0x0000042800280402,0x0102042800284080,0x0000042800284080,0x0102042800284400,0x0000042800284400,0x0000002800280000,0x0000002800280000,0x0000002800280402,0x0000002800280402,0x0000002800284080,0x0000002800284080,0x0000002800284400,0x0000002800284400,0x0082442800280402,0x0080442800280402,0x0182442800280000,0x0080442800280000,0x0082442800284480,0x0080442800284480,0x0182442800284000,0x0080442800284000,0x0080402800280402,0x0080402800280402,0x0080402800280000,0x0080402800280000,0x0080402800284480,0x0080402800284480,0x0080402800284000,0x0080402800284000,0x0002442800280402,0x0000442800280402,0x0102442800280000,0x0000442800280000,0x0002442800284480,0x0000442800284480,0x0102442800284000,0x0000442800284000,0x0000402800280402,0x0000402800280402,0x0000402800280000,0x0000402800280000,0x0000402800284480,0x0000402800284480,0x0000402800284000,0x0000402800284000,0x0002042800280402,0x0000042800280402,0x0102042800280000,0x0000042800280000,0x0002042800284480,0x0000042800284480,0x0102042800284000,0x0000042800284000,0x0000002800280402,0x0000002800280402,0x0000002800280000,0x0000002800280000,0x0000002800284480,0x0000002800284480,0x0000002800284000,0x0000002800284000,0x0002042800280402,0x0000042800280402,0x0102042800280000,0x0000042800280000,0x0002042800284480,0x0000042800284480,0x0102042800284000,0x0000042800284000,0x0000002800280402,0x0000002800280402,0x0000002800280000,0x0000002800280000,0x0000002800284480,0x0000002800284480,0x0000002800284000,0x0000002800284000,0x0082442800280000,0x0080442800280000,0x0082442800280402,0x0080442800280402,0x0082442800284080,0x0080442800284080,0x0082442800284400,0x0080442800284400,0x0080402800280000,0x0080402800280000,0x0080402800280402,0x0080402800280402,0x0080402800284080,0x0080402800284080,0x0080402800284400,0x0080402800284400,0x0002442800280000,0x0000442800280000,0x0002442800280402,0x0000442800280402,0x0002442800284080,0x0000442800284080,0x0002442800284400,0x0000442800284400,0x0000402800280000,0x0000402800280000,0x0000402800280402,0x0000402800280402,0x0000402800284080,0x0000402800284080,0x0000402800284400,0x0000402800284400,0x0002042800280000,0x0000042800280000,0x0002042800280402,0x0000042800280402,0x0002042800284080,0x0000042800284080,0x0002042800284400,0x0000042800284400,0x0000002800280000,0x0000002800280000,0x0000002800280402,0x0000002800280402,0x0000002800284080,0x0000002800284080,0x0000002800284400,0x0000002800284400,0x0002042800280000,0x0000042800280000,0x0002042800280402,0x0000042800280402,0x0002042800284080,0x0000042800284080,0x0002042800284400,0x0000042800284400,0x0000002800280000,0x0000002800280000,0x0000002800280402,0x0000002800280402,0x0000002800284080,0x0000002800284080,0x0000002800284400,0x0000002800284400,];
+static ATTACK_TABLE_29: [Bitboard; 128] = [0x0204885000508804,0x0204885000508000,0x0000885000508804,0x0000885000508000,0x0204885000500804,0x0204885000500000,0x0000885000500804,0x0000885000500000,0x0004885000508804,0x0004885000508000,0x0000885000508804,0x0000885000508000,0x0004885000500804,0x0004885000500000,0x0000885000500804,0x0000885000500000,0x0000805000508804,0x0000805000508000,0x0000805000508804,0x0000805000508000,0x0000805000500804,0x0000805000500000,0x0000805000500804,0x0000805000500000,0x0000805000508804,0x0000805000508000,0x0000805000508804,0x0000805000508000,0x0000805000500804,0x0000805000500000,0x0000805000500804,0x0000805000500000,0x0204885000508800,0x0204885000508000,0x0000885000508800,0x0000885000508000,0x0204885000500800,0x0204885000500000,0x0000885000500800,0x0000885000500000,0x0004885000508800,0x0004885000508000,0x0000885000508800,0x0000885000508000,0x0004885000500800,0x0004885000500000,0x0000885000500800,0x0000885000500000,0x0000805000508800,0x0000805000508000,0x0000805000508800,0x0000805000508000,0x0000805000500800,0x0000805000500000,0x0000805000500800,0x0000805000500000,0x0000805000508800,0x0000805000508000,0x0000805000508800,0x0000805000508000,0x0000805000500800,0x0000805000500000,0x0000805000500800,0x0000805000500000,0x0204085000508804,0x0204085000508000,0x0000085000508804,0x0000085000508000,0x0204085000500804,0x0204085000500000,0x0000085000500804,0x0000085000500000,0x0004085000508804,0x0004085000508000,0x0000085000508804,0x0000085000508000,0x0004085000500804,0x0004085000500000,0x0000085000500804,0x0000085000500000,0x0000005000508804,0x0000005000508000,0x0000005000508804,0x0000005000508000,0x0000005000500804,0x0000005000500000,0x0000005000500804,0x0000005000500000,0x0000005000508804,0x0000005000508000,0x0000005000508804,0x0000005000508000,0x0000005000500804,0x0000005000500000,0x0000005000500804,0x0000005000500000,0x0204085000508800,0x0204085000508000,0x0000085000508800,0x0000085000508000,0x0204085000500800,0x0204085000500000,0x0000085000500800,0x0000085000500000,0x0004085000508800,0x0004085000508000,0x0000085000508800,0x0000085000508000,0x0004085000500800,0x0004085000500000,0x0000085000500800,0x0000085000500000,0x0000005000508800,0x0000005000508000,0x0000005000508800,0x0000005000508000,0x0000005000500800,0x0000005000500000,0x0000005000500800,0x0000005000500000,0x0000005000508800,0x0000005000508000,0x0000005000508800,0x0000005000508000,0x0000005000500800,0x0000005000500000,0x0000005000500800,0x0000005000500000,];
+static ATTACK_TABLE_30: [Bitboard; 32] = [0x040810A000A01008,0x040810A000A00000,0x000000A000A01008,0x000000A000A00000,0x040810A000A01000,0x040810A000A00000,0x000000A000A01000,0x000000A000A00000,0x000810A000A01008,0x000810A000A00000,0x000010A000A01008,0x000010A000A00000,0x000810A000A01000,0x000810A000A00000,0x000010A000A01000,0x000010A000A00000,0x000000A000A01008,0x000000A000A00000,0x000010A000A01008,0x000010A000A00000,0x000000A000A01000,0x000000A000A00000,0x000010A000A01000,0x000010A000A00000,0x000000A000A01008,0x000000A000A00000,0x000000A000A01008,0x000000A000A00000,0x000000A000A01000,0x000000A000A00000,0x000000A000A01000,0x000000A000A00000,];
+static ATTACK_TABLE_31: [Bitboard; 32] = [0x0810204000402010,0x0810204000400000,0x0010204000402010,0x0010204000400000,0x0000004000402010,0x0000004000400000,0x0000004000402010,0x0000004000400000,0x0000204000402010,0x0000204000400000,0x0000204000402010,0x0000204000400000,0x0000004000402010,0x0000004000400000,0x0000004000402010,0x0000004000400000,0x0810204000402000,0x0810204000400000,0x0010204000402000,0x0010204000400000,0x0000004000402000,0x0000004000400000,0x0000004000402000,0x0000004000400000,0x0000204000402000,0x0000204000400000,0x0000204000402000,0x0000204000400000,0x0000004000402000,0x0000004000400000,0x0000004000402000,0x0000004000400000,];
+static ATTACK_TABLE_32: [Bitboard; 32] = [0x0804020002040810,0x0000020002040810,0x0004020002040810,0x0000020002040810,0x0804020002040800,0x0000020002040800,0x0004020002040800,0x0000020002040800,0x0804020002000000,0x0000020002000000,0x0004020002000000,0x0000020002000000,0x0804020002000000,0x0000020002000000,0x0004020002000000,0x0000020002000000,0x0804020002040000,0x0000020002040000,0x0004020002040000,0x0000020002040000,0x0804020002040000,0x0000020002040000,0x0004020002040000,0x0000020002040000,0x0804020002000000,0x0000020002000000,0x0004020002000000,0x0000020002000000,0x0804020002000000,0x0000020002000000,0x0004020002000000,0x0000020002000000,];
+static ATTACK_TABLE_33: [Bitboard; 32] = [0x1008050005081020,0x1008050005080000,0x1008050005000000,0x1008050005000000,0x0000050005081020,0x0000050005080000,0x0000050005000000,0x0000050005000000,0x0008050005081020,0x0008050005080000,0x0008050005000000,0x0008050005000000,0x0000050005081020,0x0000050005080000,0x0000050005000000,0x0000050005000000,0x1008050005081000,0x1008050005080000,0x1008050005000000,0x1008050005000000,0x0000050005081000,0x0000050005080000,0x0000050005000000,0x0000050005000000,0x0008050005081000,0x0008050005080000,0x0008050005000000,0x0008050005000000,0x0000050005081000,0x0000050005080000,0x0000050005000000,0x0000050005000000,];
+static ATTACK_TABLE_34: [Bitboard; 128] = [0x20110A000A112040,0x00010A000A112000,0x20110A000A102040,0x00010A000A102000,0x00010A000A110000,0x00010A000A110000,0x00010A000A100000,0x00010A000A100000,0x20110A000A010000,0x00010A000A010000,0x20110A000A000000,0x00010A000A000000,0x00010A000A010000,0x00010A000A010000,0x00010A000A000000,0x00010A000A000000,0x20100A000A112040,0x00000A000A112000,0x20100A000A102040,0x00000A000A102000,0x00000A000A110000,0x00000A000A110000,0x00000A000A100000,0x00000A000A100000,0x20100A000A010000,0x00000A000A010000,0x20100A000A000000,0x00000A000A000000,0x00000A000A010000,0x00000A000A010000,0x00000A000A000000,0x00000A000A000000,0x20110A000A112000,0x00110A000A112040,0x20110A000A102000,0x00110A000A102040,0x20110A000A110000,0x00010A000A110000,0x20110A000A100000,0x00010A000A100000,0x20110A000A010000,0x00110A000A010000,0x20110A000A000000,0x00110A000A000000,0x20110A000A010000,0x00010A000A010000,0x20110A000A000000,0x00010A000A000000,0x20100A000A112000,0x00100A000A112040,0x20100A000A102000,0x00100A000A102040,0x20100A000A110000,0x00000A000A110000,0x20100A000A100000,0x00000A000A100000,0x20100A000A010000,0x00100A000A010000,0x20100A000A000000,0x00100A000A000000,0x20100A000A010000,0x00000A000A010000,0x20100A000A000000,0x00000A000A000000,0x00010A000A112040,0x00110A000A112000,0x00010A000A102040,0x00110A000A102000,0x20110A000A110000,0x00110A000A110000,0x20110A000A100000,0x00110A000A100000,0x00010A000A010000,0x00110A000A010000,0x00010A000A000000,0x00110A000A000000,0x20110A000A010000,0x00110A000A010000,0x20110A000A000000,0x00110A000A000000,0x00000A000A112040,0x00100A000A112000,0x00000A000A102040,0x00100A000A102000,0x20100A000A110000,0x00100A000A110000,0x20100A000A100000,0x00100A000A100000,0x00000A000A010000,0x00100A000A010000,0x00000A000A000000,0x00100A000A000000,0x20100A000A010000,0x00100A000A010000,0x20100A000A000000,0x00100A000A000000,0x00010A000A112000,0x00010A000A112040,0x00010A000A102000,0x00010A000A102040,0x00010A000A110000,0x00110A000A110000,0x00010A000A100000,0x00110A000A100000,0x00010A000A010000,0x00010A000A010000,0x00010A000A000000,0x00010A000A000000,0x00010A000A010000,0x00110A000A010000,0x00010A000A000000,0x00110A000A000000,0x00000A000A112000,0x00000A000A112040,0x00000A000A102000,0x00000A000A102040,0x00000A000A110000,0x00100A000A110000,0x00000A000A100000,0x00100A000A100000,0x00000A000A010000,0x00000A000A010000,0x00000A000A000000,0x00000A000A000000,0x00000A000A010000,0x00100A000A010000,0x00000A000A000000,0x00100A000A000000,];
+static ATTACK_TABLE_35: [Bitboard; 512] = [0x4122140014224180,0x0020140014020100,0x0000140014224000,0x4122140014020000,0x4022140014224180,0x0020140014020100,0x0000140014224000,0x4022140014020000,0x4122140014204080,0x0020140014000000,0x0000140014204000,0x4122140014000000,0x4022140014204080,0x0020140014000000,0x0000140014204000,0x4022140014000000,0x0102140014220000,0x0000140014020000,0x0102140014220100,0x4020140014020100,0x0002140014220000,0x0000140014020000,0x0002140014220100,0x4020140014020100,0x0102140014200000,0x0000140014000000,0x0102140014200000,0x4020140014000000,0x0002140014200000,0x0000140014000000,0x0002140014200000,0x4020140014000000,0x0000140014220100,0x4122140014020100,0x0122140014220000,0x0000140014020000,0x0000140014220100,0x4022140014020100,0x0022140014220000,0x0000140014020000,0x0000140014200000,0x4122140014000000,0x0122140014200000,0x0000140014000000,0x0000140014200000,0x4022140014000000,0x0022140014200000,0x0000140014000000,0x4020140014220000,0x0102140014020000,0x0020140014220100,0x0102140014020100,0x4020140014220000,0x0002140014020000,0x0020140014220100,0x0002140014020100,0x4020140014200000,0x0102140014000000,0x0020140014200000,0x0102140014000000,0x4020140014200000,0x0002140014000000,0x0020140014200000,0x0002140014000000,0x0122140014224180,0x0000140014020100,0x0000140014224000,0x0122140014020000,0x0022140014224180,0x0000140014020100,0x0000140014224000,0x0022140014020000,0x0122140014204080,0x0000140014000000,0x0000140014204000,0x0122140014000000,0x0022140014204080,0x0000140014000000,0x0000140014204000,0x0022140014000000,0x0102140014220000,0x4020140014020000,0x4122140014224100,0x0020140014020100,0x0002140014220000,0x4020140014020000,0x4022140014224100,0x0020140014020100,0x0102140014200000,0x4020140014000000,0x4122140014204000,0x0020140014000000,0x0002140014200000,0x4020140014000000,0x4022140014204000,0x0020140014000000,0x0000140014220100,0x0122140014020100,0x0102140014220000,0x0000140014020000,0x0000140014220100,0x0022140014020100,0x0002140014220000,0x0000140014020000,0x0000140014200000,0x0122140014000000,0x0102140014200000,0x0000140014000000,0x0000140014200000,0x0022140014000000,0x0002140014200000,0x0000140014000000,0x0020140014220000,0x0102140014020000,0x0000140014220100,0x4122140014020100,0x0020140014220000,0x0002140014020000,0x0000140014220100,0x4022140014020100,0x0020140014200000,0x0102140014000000,0x0000140014200000,0x4122140014000000,0x0020140014200000,0x0002140014000000,0x0000140014200000,0x4022140014000000,0x0102140014224180,0x0000140014020100,0x4020140014220000,0x0102140014020000,0x0002140014224180,0x0000140014020100,0x4020140014220000,0x0002140014020000,0x0102140014204080,0x0000140014000000,0x4020140014200000,0x0102140014000000,0x0002140014204080,0x0000140014000000,0x4020140014200000,0x0002140014000000,0x4122140014224080,0x0020140014020000,0x0122140014224100,0x0000140014020100,0x4022140014224080,0x0020140014020000,0x0022140014224100,0x0000140014020100,0x4122140014204080,0x0020140014000000,0x0122140014204000,0x0000140014000000,0x4022140014204080,0x0020140014000000,0x0022140014204000,0x0000140014000000,0x4020140014224180,0x0102140014020100,0x0102140014220000,0x4020140014020000,0x4020140014224180,0x0002140014020100,0x0002140014220000,0x4020140014020000,0x4020140014204080,0x0102140014000000,0x0102140014200000,0x4020140014000000,0x4020140014204080,0x0002140014000000,0x0002140014200000,0x4020140014000000,0x0000140014220000,0x4122140014020000,0x0000140014220100,0x0122140014020100,0x0000140014220000,0x4022140014020000,0x0000140014220100,0x0022140014020100,0x0000140014200000,0x4122140014000000,0x0000140014200000,0x0122140014000000,0x0000140014200000,0x4022140014000000,0x0000140014200000,0x0022140014000000,0x0102140014224180,0x4020140014020100,0x0020140014220000,0x0102140014020000,0x0002140014224180,0x4020140014020100,0x0020140014220000,0x0002140014020000,0x0102140014204080,0x4020140014000000,0x0020140014200000,0x0102140014000000,0x0002140014204080,0x4020140014000000,0x0020140014200000,0x0002140014000000,0x0122140014224080,0x0000140014020000,0x0102140014224100,0x0000140014020100,0x0022140014224080,0x0000140014020000,0x0002140014224100,0x0000140014020100,0x0122140014204080,0x0000140014000000,0x0102140014204000,0x0000140014000000,0x0022140014204080,0x0000140014000000,0x0002140014204000,0x0000140014000000,0x0020140014224180,0x0102140014020100,0x4122140014224000,0x0020140014020000,0x0020140014224180,0x0002140014020100,0x4022140014224000,0x0020140014020000,0x0020140014204080,0x0102140014000000,0x4122140014204000,0x0020140014000000,0x0020140014204080,0x0002140014000000,0x4022140014204000,0x0020140014000000,0x0000140014220000,0x0122140014020000,0x4020140014224100,0x0102140014020100,0x0000140014220000,0x0022140014020000,0x4020140014224100,0x0002140014020100,0x0000140014200000,0x0122140014000000,0x4020140014204000,0x0102140014000000,0x0000140014200000,0x0022140014000000,0x4020140014204000,0x0002140014000000,0x4122140014220100,0x0020140014020100,0x0000140014220000,0x4122140014020000,0x4022140014220100,0x0020140014020100,0x0000140014220000,0x4022140014020000,0x4122140014200000,0x0020140014000000,0x0000140014200000,0x4122140014000000,0x4022140014200000,0x0020140014000000,0x0000140014200000,0x4022140014000000,0x0102140014224080,0x0000140014020000,0x0102140014224100,0x4020140014020100,0x0002140014224080,0x0000140014020000,0x0002140014224100,0x4020140014020100,0x0102140014204080,0x0000140014000000,0x0102140014204000,0x4020140014000000,0x0002140014204080,0x0000140014000000,0x0002140014204000,0x4020140014000000,0x0000140014224180,0x4122140014020100,0x0122140014224000,0x0000140014020000,0x0000140014224180,0x4022140014020100,0x0022140014224000,0x0000140014020000,0x0000140014204080,0x4122140014000000,0x0122140014204000,0x0000140014000000,0x0000140014204080,0x4022140014000000,0x0022140014204000,0x0000140014000000,0x4020140014224080,0x0102140014020000,0x0020140014224100,0x0102140014020100,0x4020140014224080,0x0002140014020000,0x0020140014224100,0x0002140014020100,0x4020140014204080,0x0102140014000000,0x0020140014204000,0x0102140014000000,0x4020140014204080,0x0002140014000000,0x0020140014204000,0x0002140014000000,0x0122140014220100,0x0000140014020100,0x0000140014220000,0x0122140014020000,0x0022140014220100,0x0000140014020100,0x0000140014220000,0x0022140014020000,0x0122140014200000,0x0000140014000000,0x0000140014200000,0x0122140014000000,0x0022140014200000,0x0000140014000000,0x0000140014200000,0x0022140014000000,0x0102140014224080,0x4020140014020000,0x4122140014220100,0x0020140014020100,0x0002140014224080,0x4020140014020000,0x4022140014220100,0x0020140014020100,0x0102140014204080,0x4020140014000000,0x4122140014200000,0x0020140014000000,0x0002140014204080,0x4020140014000000,0x4022140014200000,0x0020140014000000,0x0000140014224180,0x0122140014020100,0x0102140014224000,0x0000140014020000,0x0000140014224180,0x0022140014020100,0x0002140014224000,0x0000140014020000,0x0000140014204080,0x0122140014000000,0x0102140014204000,0x0000140014000000,0x0000140014204080,0x0022140014000000,0x0002140014204000,0x0000140014000000,0x0020140014224080,0x0102140014020000,0x0000140014224100,0x4122140014020100,0x0020140014224080,0x0002140014020000,0x0000140014224100,0x4022140014020100,0x0020140014204080,0x0102140014000000,0x0000140014204000,0x4122140014000000,0x0020140014204080,0x0002140014000000,0x0000140014204000,0x4022140014000000,0x0102140014220100,0x0000140014020100,0x4020140014224000,0x0102140014020000,0x0002140014220100,0x0000140014020100,0x4020140014224000,0x0002140014020000,0x0102140014200000,0x0000140014000000,0x4020140014204000,0x0102140014000000,0x0002140014200000,0x0000140014000000,0x4020140014204000,0x0002140014000000,0x4122140014220000,0x0020140014020000,0x0122140014220100,0x0000140014020100,0x4022140014220000,0x0020140014020000,0x0022140014220100,0x0000140014020100,0x4122140014200000,0x0020140014000000,0x0122140014200000,0x0000140014000000,0x4022140014200000,0x0020140014000000,0x0022140014200000,0x0000140014000000,0x4020140014220100,0x0102140014020100,0x0102140014224000,0x4020140014020000,0x4020140014220100,0x0002140014020100,0x0002140014224000,0x4020140014020000,0x4020140014200000,0x0102140014000000,0x0102140014204000,0x4020140014000000,0x4020140014200000,0x0002140014000000,0x0002140014204000,0x4020140014000000,0x0000140014224080,0x4122140014020000,0x0000140014224100,0x0122140014020100,0x0000140014224080,0x4022140014020000,0x0000140014224100,0x0022140014020100,0x0000140014204080,0x4122140014000000,0x0000140014204000,0x0122140014000000,0x0000140014204080,0x4022140014000000,0x0000140014204000,0x0022140014000000,0x0102140014220100,0x4020140014020100,0x0020140014224000,0x0102140014020000,0x0002140014220100,0x4020140014020100,0x0020140014224000,0x0002140014020000,0x0102140014200000,0x4020140014000000,0x0020140014204000,0x0102140014000000,0x0002140014200000,0x4020140014000000,0x0020140014204000,0x0002140014000000,0x0122140014220000,0x0000140014020000,0x0102140014220100,0x0000140014020100,0x0022140014220000,0x0000140014020000,0x0002140014220100,0x0000140014020100,0x0122140014200000,0x0000140014000000,0x0102140014200000,0x0000140014000000,0x0022140014200000,0x0000140014000000,0x0002140014200000,0x0000140014000000,0x0020140014220100,0x0102140014020100,0x4122140014220000,0x0020140014020000,0x0020140014220100,0x0002140014020100,0x4022140014220000,0x0020140014020000,0x0020140014200000,0x0102140014000000,0x4122140014200000,0x0020140014000000,0x0020140014200000,0x0002140014000000,0x4022140014200000,0x0020140014000000,0x0000140014224080,0x0122140014020000,0x4020140014220100,0x0102140014020100,0x0000140014224080,0x0022140014020000,0x4020140014220100,0x0002140014020100,0x0000140014204080,0x0122140014000000,0x4020140014200000,0x0102140014000000,0x0000140014204080,0x0022140014000000,0x4020140014200000,0x0002140014000000,];
+static ATTACK_TABLE_36: [Bitboard; 512] = [0x8244280028448201,0x0040280028000000,0x0244280028000000,0x0040280028040000,0x0204280028040200,0x0000280028400000,0x0204280028400000,0x0000280028440000,0x0044280028448200,0x8040280028000000,0x8044280028000000,0x8040280028040000,0x0004280028040200,0x0000280028400000,0x0004280028400000,0x0000280028440000,0x8244280028408000,0x8040280028448201,0x8244280028448000,0x0040280028000000,0x0204280028000000,0x0000280028040200,0x0204280028040000,0x0000280028400000,0x0044280028408000,0x0040280028448200,0x0044280028448000,0x8040280028000000,0x0004280028000000,0x0000280028040200,0x0004280028040000,0x0000280028400000,0x8244280028440200,0x8040280028408000,0x8244280028408000,0x8040280028448000,0x0204280028040201,0x0000280028000000,0x0204280028000000,0x0000280028040000,0x0044280028440201,0x0040280028408000,0x0044280028408000,0x0040280028448000,0x0004280028040201,0x0000280028000000,0x0004280028000000,0x0000280028040000,0x8244280028400000,0x8040280028440200,0x8244280028440000,0x8040280028408000,0x0204280028000000,0x0000280028040201,0x0204280028040000,0x0000280028000000,0x0044280028400000,0x0040280028440201,0x0044280028440000,0x0040280028408000,0x0004280028000000,0x0000280028040201,0x0004280028040000,0x0000280028000000,0x8244280028040201,0x8040280028400000,0x8244280028400000,0x8040280028440000,0x0204280028448201,0x0000280028000000,0x0204280028000000,0x0000280028040000,0x0044280028040200,0x0040280028400000,0x0044280028400000,0x0040280028440000,0x0004280028448200,0x0000280028000000,0x0004280028000000,0x0000280028040000,0x8244280028000000,0x8040280028040201,0x8244280028040000,0x8040280028400000,0x0204280028408000,0x0000280028448201,0x0204280028448000,0x0000280028000000,0x0044280028000000,0x0040280028040200,0x0044280028040000,0x0040280028400000,0x0004280028408000,0x0000280028448200,0x0004280028448000,0x0000280028000000,0x8244280028040200,0x8040280028000000,0x8244280028000000,0x8040280028040000,0x0204280028440200,0x0000280028408000,0x0204280028408000,0x0000280028448000,0x0044280028040201,0x0040280028000000,0x0044280028000000,0x0040280028040000,0x0004280028440201,0x0000280028408000,0x0004280028408000,0x0000280028448000,0x8244280028000000,0x8040280028040200,0x8244280028040000,0x8040280028000000,0x0204280028400000,0x0000280028440200,0x0204280028440000,0x0000280028408000,0x0044280028000000,0x0040280028040201,0x0044280028040000,0x0040280028000000,0x0004280028400000,0x0000280028440201,0x0004280028440000,0x0000280028408000,0x0244280028448201,0x8040280028000000,0x8244280028000000,0x8040280028040000,0x0204280028040201,0x0000280028400000,0x0204280028400000,0x0000280028440000,0x8044280028448201,0x0040280028000000,0x0044280028000000,0x0040280028040000,0x0004280028040200,0x0000280028400000,0x0004280028400000,0x0000280028440000,0x0244280028408000,0x0040280028448201,0x0244280028448000,0x8040280028000000,0x0204280028000000,0x0000280028040201,0x0204280028040000,0x0000280028400000,0x8044280028408000,0x8040280028448201,0x8044280028448000,0x0040280028000000,0x0004280028000000,0x0000280028040200,0x0004280028040000,0x0000280028400000,0x0244280028440200,0x0040280028408000,0x0244280028408000,0x0040280028448000,0x0204280028040200,0x0000280028000000,0x0204280028000000,0x0000280028040000,0x8044280028440200,0x8040280028408000,0x8044280028408000,0x8040280028448000,0x0004280028040201,0x0000280028000000,0x0004280028000000,0x0000280028040000,0x0244280028400000,0x0040280028440200,0x0244280028440000,0x0040280028408000,0x0204280028000000,0x0000280028040200,0x0204280028040000,0x0000280028000000,0x8044280028400000,0x8040280028440200,0x8044280028440000,0x8040280028408000,0x0004280028000000,0x0000280028040201,0x0004280028040000,0x0000280028000000,0x0244280028040201,0x0040280028400000,0x0244280028400000,0x0040280028440000,0x0204280028448201,0x0000280028000000,0x0204280028000000,0x0000280028040000,0x8044280028040201,0x8040280028400000,0x8044280028400000,0x8040280028440000,0x0004280028448201,0x0000280028000000,0x0004280028000000,0x0000280028040000,0x0244280028000000,0x0040280028040201,0x0244280028040000,0x0040280028400000,0x0204280028408000,0x0000280028448201,0x0204280028448000,0x0000280028000000,0x8044280028000000,0x8040280028040201,0x8044280028040000,0x8040280028400000,0x0004280028408000,0x0000280028448201,0x0004280028448000,0x0000280028000000,0x0244280028040200,0x0040280028000000,0x0244280028000000,0x0040280028040000,0x0204280028440200,0x0000280028408000,0x0204280028408000,0x0000280028448000,0x8044280028040200,0x8040280028000000,0x8044280028000000,0x8040280028040000,0x0004280028440200,0x0000280028408000,0x0004280028408000,0x0000280028448000,0x0244280028000000,0x0040280028040200,0x0244280028040000,0x0040280028000000,0x0204280028400000,0x0000280028440200,0x0204280028440000,0x0000280028408000,0x8044280028000000,0x8040280028040200,0x8044280028040000,0x8040280028000000,0x0004280028400000,0x0000280028440200,0x0004280028440000,0x0000280028408000,0x8244280028448200,0x0040280028000000,0x0244280028000000,0x0040280028040000,0x0204280028040201,0x0000280028400000,0x0204280028400000,0x0000280028440000,0x0044280028448201,0x8040280028000000,0x8044280028000000,0x8040280028040000,0x0004280028040201,0x0000280028400000,0x0004280028400000,0x0000280028440000,0x8244280028408000,0x8040280028448200,0x8244280028448000,0x0040280028000000,0x0204280028000000,0x0000280028040201,0x0204280028040000,0x0000280028400000,0x0044280028408000,0x0040280028448201,0x0044280028448000,0x8040280028000000,0x0004280028000000,0x0000280028040201,0x0004280028040000,0x0000280028400000,0x8244280028440201,0x8040280028408000,0x8244280028408000,0x8040280028448000,0x0204280028040200,0x0000280028000000,0x0204280028000000,0x0000280028040000,0x0044280028440200,0x0040280028408000,0x0044280028408000,0x0040280028448000,0x0004280028040200,0x0000280028000000,0x0004280028000000,0x0000280028040000,0x8244280028400000,0x8040280028440201,0x8244280028440000,0x8040280028408000,0x0204280028000000,0x0000280028040200,0x0204280028040000,0x0000280028000000,0x0044280028400000,0x0040280028440200,0x0044280028440000,0x0040280028408000,0x0004280028000000,0x0000280028040200,0x0004280028040000,0x0000280028000000,0x8244280028040200,0x8040280028400000,0x8244280028400000,0x8040280028440000,0x0204280028448200,0x0000280028000000,0x0204280028000000,0x0000280028040000,0x0044280028040201,0x0040280028400000,0x0044280028400000,0x0040280028440000,0x0004280028448201,0x0000280028000000,0x0004280028000000,0x0000280028040000,0x8244280028000000,0x8040280028040200,0x8244280028040000,0x8040280028400000,0x0204280028408000,0x0000280028448200,0x0204280028448000,0x0000280028000000,0x0044280028000000,0x0040280028040201,0x0044280028040000,0x0040280028400000,0x0004280028408000,0x0000280028448201,0x0004280028448000,0x0000280028000000,0x8244280028040201,0x8040280028000000,0x8244280028000000,0x8040280028040000,0x0204280028440201,0x0000280028408000,0x0204280028408000,0x0000280028448000,0x0044280028040200,0x0040280028000000,0x0044280028000000,0x0040280028040000,0x0004280028440200,0x0000280028408000,0x0004280028408000,0x0000280028448000,0x8244280028000000,0x8040280028040201,0x8244280028040000,0x8040280028000000,0x0204280028400000,0x0000280028440201,0x0204280028440000,0x0000280028408000,0x0044280028000000,0x0040280028040200,0x0044280028040000,0x0040280028000000,0x0004280028400000,0x0000280028440200,0x0004280028440000,0x0000280028408000,0x0244280028448200,0x8040280028000000,0x8244280028000000,0x8040280028040000,0x0204280028040200,0x0000280028400000,0x0204280028400000,0x0000280028440000,0x8044280028448200,0x0040280028000000,0x0044280028000000,0x0040280028040000,0x0004280028040201,0x0000280028400000,0x0004280028400000,0x0000280028440000,0x0244280028408000,0x0040280028448200,0x0244280028448000,0x8040280028000000,0x0204280028000000,0x0000280028040200,0x0204280028040000,0x0000280028400000,0x8044280028408000,0x8040280028448200,0x8044280028448000,0x0040280028000000,0x0004280028000000,0x0000280028040201,0x0004280028040000,0x0000280028400000,0x0244280028440201,0x0040280028408000,0x0244280028408000,0x0040280028448000,0x0204280028040201,0x0000280028000000,0x0204280028000000,0x0000280028040000,0x8044280028440201,0x8040280028408000,0x8044280028408000,0x8040280028448000,0x0004280028040200,0x0000280028000000,0x0004280028000000,0x0000280028040000,0x0244280028400000,0x0040280028440201,0x0244280028440000,0x0040280028408000,0x0204280028000000,0x0000280028040201,0x0204280028040000,0x0000280028000000,0x8044280028400000,0x8040280028440201,0x8044280028440000,0x8040280028408000,0x0004280028000000,0x0000280028040200,0x0004280028040000,0x0000280028000000,0x0244280028040200,0x0040280028400000,0x0244280028400000,0x0040280028440000,0x0204280028448200,0x0000280028000000,0x0204280028000000,0x0000280028040000,0x8044280028040200,0x8040280028400000,0x8044280028400000,0x8040280028440000,0x0004280028448200,0x0000280028000000,0x0004280028000000,0x0000280028040000,0x0244280028000000,0x0040280028040200,0x0244280028040000,0x0040280028400000,0x0204280028408000,0x0000280028448200,0x0204280028448000,0x0000280028000000,0x8044280028000000,0x8040280028040200,0x8044280028040000,0x8040280028400000,0x0004280028408000,0x0000280028448200,0x0004280028448000,0x0000280028000000,0x0244280028040201,0x0040280028000000,0x0244280028000000,0x0040280028040000,0x0204280028440201,0x0000280028408000,0x0204280028408000,0x0000280028448000,0x8044280028040201,0x8040280028000000,0x8044280028000000,0x8040280028040000,0x0004280028440201,0x0000280028408000,0x0004280028408000,0x0000280028448000,0x0244280028000000,0x0040280028040201,0x0244280028040000,0x0040280028000000,0x0204280028400000,0x0000280028440201,0x0204280028440000,0x0000280028408000,0x8044280028000000,0x8040280028040201,0x8044280028040000,0x8040280028000000,0x0004280028400000,0x0000280028440201,0x0004280028440000,0x0000280028408000,];
+static ATTACK_TABLE_37: [Bitboard; 128] = [0x0488500050880402,0x0408500050880402,0x0488500050880000,0x0408500050880000,0x0080500050880402,0x0000500050880402,0x0080500050880000,0x0000500050880000,0x0488500050880400,0x0408500050880400,0x0488500050880000,0x0408500050880000,0x0080500050880400,0x0000500050880400,0x0080500050880000,0x0000500050880000,0x0488500050800000,0x0408500050800000,0x0488500050800000,0x0408500050800000,0x0080500050800000,0x0000500050800000,0x0080500050800000,0x0000500050800000,0x0488500050800000,0x0408500050800000,0x0488500050800000,0x0408500050800000,0x0080500050800000,0x0000500050800000,0x0080500050800000,0x0000500050800000,0x0080500050080402,0x0000500050080402,0x0080500050080000,0x0000500050080000,0x0088500050880402,0x0008500050880402,0x0088500050880000,0x0008500050880000,0x0080500050080400,0x0000500050080400,0x0080500050080000,0x0000500050080000,0x0088500050880400,0x0008500050880400,0x0088500050880000,0x0008500050880000,0x0080500050000000,0x0000500050000000,0x0080500050000000,0x0000500050000000,0x0088500050800000,0x0008500050800000,0x0088500050800000,0x0008500050800000,0x0080500050000000,0x0000500050000000,0x0080500050000000,0x0000500050000000,0x0088500050800000,0x0008500050800000,0x0088500050800000,0x0008500050800000,0x0488500050080402,0x0408500050080402,0x0488500050080000,0x0408500050080000,0x0080500050080402,0x0000500050080402,0x0080500050080000,0x0000500050080000,0x0488500050080400,0x0408500050080400,0x0488500050080000,0x0408500050080000,0x0080500050080400,0x0000500050080400,0x0080500050080000,0x0000500050080000,0x0488500050000000,0x0408500050000000,0x0488500050000000,0x0408500050000000,0x0080500050000000,0x0000500050000000,0x0080500050000000,0x0000500050000000,0x0488500050000000,0x0408500050000000,0x0488500050000000,0x0408500050000000,0x0080500050000000,0x0000500050000000,0x0080500050000000,0x0000500050000000,0x0080500050880402,0x0000500050880402,0x0080500050880000,0x0000500050880000,0x0088500050080402,0x0008500050080402,0x0088500050080000,0x0008500050080000,0x0080500050880400,0x0000500050880400,0x0080500050880000,0x0000500050880000,0x0088500050080400,0x0008500050080400,0x0088500050080000,0x0008500050080000,0x0080500050800000,0x0000500050800000,0x0080500050800000,0x0000500050800000,0x0088500050000000,0x0008500050000000,0x0088500050000000,0x0008500050000000,0x0080500050800000,0x0000500050800000,0x0080500050800000,0x0000500050800000,0x0088500050000000,0x0008500050000000,0x0088500050000000,0x0008500050000000,];
+static ATTACK_TABLE_38: [Bitboard; 32] = [0x0810A000A0100804,0x0810A000A0100800,0x0010A000A0100804,0x0010A000A0100800,0x0810A000A0100000,0x0810A000A0100000,0x0010A000A0100000,0x0010A000A0100000,0x0000A000A0100804,0x0000A000A0100800,0x0000A000A0100804,0x0000A000A0100800,0x0000A000A0100000,0x0000A000A0100000,0x0000A000A0100000,0x0000A000A0100000,0x0810A000A0000000,0x0810A000A0000000,0x0010A000A0000000,0x0010A000A0000000,0x0810A000A0000000,0x0810A000A0000000,0x0010A000A0000000,0x0010A000A0000000,0x0000A000A0000000,0x0000A000A0000000,0x0000A000A0000000,0x0000A000A0000000,0x0000A000A0000000,0x0000A000A0000000,0x0000A000A0000000,0x0000A000A0000000,];
+static ATTACK_TABLE_39: [Bitboard; 32] = [0x1020400040201008,0x0020400040201008,0x1020400040200000,0x0020400040200000,0x1020400040000000,0x0020400040000000,0x1020400040000000,0x0020400040000000,0x0000400040201008,0x0000400040201008,0x0000400040200000,0x0000400040200000,0x0000400040000000,0x0000400040000000,0x0000400040000000,0x0000400040000000,0x1020400040200000,0x0020400040200000,0x1020400040201000,0x0020400040201000,0x1020400040000000,0x0020400040000000,0x1020400040000000,0x0020400040000000,0x0000400040200000,0x0000400040200000,0x0000400040201000,0x0000400040201000,0x0000400040000000,0x0000400040000000,0x0000400040000000,0x0000400040000000,];
+static ATTACK_TABLE_40: [Bitboard; 32] = [0x0402000204081020,0x0402000200000000,0x0002000204081020,0x0002000200000000,0x0402000204000000,0x0402000200000000,0x0002000204000000,0x0002000200000000,0x0402000204080000,0x0402000200000000,0x0002000204080000,0x0002000200000000,0x0402000204000000,0x0402000200000000,0x0002000204000000,0x0002000200000000,0x0402000204081000,0x0402000200000000,0x0002000204081000,0x0002000200000000,0x0402000204000000,0x0402000200000000,0x0002000204000000,0x0002000200000000,0x0402000204080000,0x0402000200000000,0x0002000204080000,0x0002000200000000,0x0402000204000000,0x0402000200000000,0x0002000204000000,0x0002000200000000,];
+static ATTACK_TABLE_41: [Bitboard; 32] = [0x0805000508102040,0x0805000500000000,0x0805000508100000,0x0805000500000000,0x0005000508102040,0x0005000500000000,0x0005000508100000,0x0005000500000000,0x0805000500000000,0x0805000508102000,0x0805000500000000,0x0805000508100000,0x0005000500000000,0x0005000508102000,0x0005000500000000,0x0005000508100000,0x0805000508000000,0x0805000500000000,0x0805000508000000,0x0805000500000000,0x0005000508000000,0x0005000500000000,0x0005000508000000,0x0005000500000000,0x0805000500000000,0x0805000508000000,0x0805000500000000,0x0805000508000000,0x0005000500000000,0x0005000508000000,0x0005000500000000,0x0005000508000000,];
+static ATTACK_TABLE_42: [Bitboard; 128] = [0x110A000A11204080,0x110A000A01000000,0x110A000A11000000,0x110A000A01000000,0x100A000A11204080,0x100A000A01000000,0x100A000A11000000,0x100A000A01000000,0x110A000A11200000,0x110A000A01000000,0x110A000A11000000,0x110A000A01000000,0x100A000A11200000,0x100A000A01000000,0x100A000A11000000,0x100A000A01000000,0x010A000A11204080,0x010A000A01000000,0x010A000A11000000,0x010A000A01000000,0x000A000A11204080,0x000A000A01000000,0x000A000A11000000,0x000A000A01000000,0x010A000A11200000,0x010A000A01000000,0x010A000A11000000,0x010A000A01000000,0x000A000A11200000,0x000A000A01000000,0x000A000A11000000,0x000A000A01000000,0x110A000A11204000,0x110A000A01000000,0x110A000A11000000,0x110A000A01000000,0x100A000A11204000,0x100A000A01000000,0x100A000A11000000,0x100A000A01000000,0x110A000A11200000,0x110A000A01000000,0x110A000A11000000,0x110A000A01000000,0x100A000A11200000,0x100A000A01000000,0x100A000A11000000,0x100A000A01000000,0x010A000A11204000,0x010A000A01000000,0x010A000A11000000,0x010A000A01000000,0x000A000A11204000,0x000A000A01000000,0x000A000A11000000,0x000A000A01000000,0x010A000A11200000,0x010A000A01000000,0x010A000A11000000,0x010A000A01000000,0x000A000A11200000,0x000A000A01000000,0x000A000A11000000,0x000A000A01000000,0x110A000A10204080,0x110A000A00000000,0x110A000A10000000,0x110A000A00000000,0x100A000A10204080,0x100A000A00000000,0x100A000A10000000,0x100A000A00000000,0x110A000A10200000,0x110A000A00000000,0x110A000A10000000,0x110A000A00000000,0x100A000A10200000,0x100A000A00000000,0x100A000A10000000,0x100A000A00000000,0x010A000A10204080,0x010A000A00000000,0x010A000A10000000,0x010A000A00000000,0x000A000A10204080,0x000A000A00000000,0x000A000A10000000,0x000A000A00000000,0x010A000A10200000,0x010A000A00000000,0x010A000A10000000,0x010A000A00000000,0x000A000A10200000,0x000A000A00000000,0x000A000A10000000,0x000A000A00000000,0x110A000A10204000,0x110A000A00000000,0x110A000A10000000,0x110A000A00000000,0x100A000A10204000,0x100A000A00000000,0x100A000A10000000,0x100A000A00000000,0x110A000A10200000,0x110A000A00000000,0x110A000A10000000,0x110A000A00000000,0x100A000A10200000,0x100A000A00000000,0x100A000A10000000,0x100A000A00000000,0x010A000A10204000,0x010A000A00000000,0x010A000A10000000,0x010A000A00000000,0x000A000A10204000,0x000A000A00000000,0x000A000A10000000,0x000A000A00000000,0x010A000A10200000,0x010A000A00000000,0x010A000A10000000,0x010A000A00000000,0x000A000A10200000,0x000A000A00000000,0x000A000A10000000,0x000A000A00000000,];
+static ATTACK_TABLE_43: [Bitboard; 128] = [0x2214001422418000,0x2214001422010000,0x2014001422418000,0x2014001422010000,0x2214001422410000,0x2214001422010000,0x2014001422410000,0x2014001422010000,0x0214001422418000,0x0214001422010000,0x0014001422418000,0x0014001422010000,0x0214001422410000,0x0214001422010000,0x0014001422410000,0x0014001422010000,0x2214001400000000,0x2214001400000000,0x2014001400000000,0x2014001400000000,0x2214001400000000,0x2214001400000000,0x2014001400000000,0x2014001400000000,0x0214001400000000,0x0214001400000000,0x0014001400000000,0x0014001400000000,0x0214001400000000,0x0214001400000000,0x0014001400000000,0x0014001400000000,0x2214001422408000,0x2214001422000000,0x2014001422408000,0x2014001422000000,0x2214001422400000,0x2214001422000000,0x2014001422400000,0x2014001422000000,0x0214001422408000,0x0214001422000000,0x0014001422408000,0x0014001422000000,0x0214001422400000,0x0214001422000000,0x0014001422400000,0x0014001422000000,0x2214001420408000,0x2214001420000000,0x2014001420408000,0x2014001420000000,0x2214001420400000,0x2214001420000000,0x2014001420400000,0x2014001420000000,0x0214001420408000,0x0214001420000000,0x0014001420408000,0x0014001420000000,0x0214001420400000,0x0214001420000000,0x0014001420400000,0x0014001420000000,0x2214001402010000,0x2214001402010000,0x2014001402010000,0x2014001402010000,0x2214001402010000,0x2214001402010000,0x2014001402010000,0x2014001402010000,0x0214001402010000,0x0214001402010000,0x0014001402010000,0x0014001402010000,0x0214001402010000,0x0214001402010000,0x0014001402010000,0x0014001402010000,0x2214001420408000,0x2214001420000000,0x2014001420408000,0x2014001420000000,0x2214001420400000,0x2214001420000000,0x2014001420400000,0x2014001420000000,0x0214001420408000,0x0214001420000000,0x0014001420408000,0x0014001420000000,0x0214001420400000,0x0214001420000000,0x0014001420400000,0x0014001420000000,0x2214001402000000,0x2214001402000000,0x2014001402000000,0x2014001402000000,0x2214001402000000,0x2214001402000000,0x2014001402000000,0x2014001402000000,0x0214001402000000,0x0214001402000000,0x0014001402000000,0x0014001402000000,0x0214001402000000,0x0214001402000000,0x0014001402000000,0x0014001402000000,0x2214001400000000,0x2214001400000000,0x2014001400000000,0x2014001400000000,0x2214001400000000,0x2214001400000000,0x2014001400000000,0x2014001400000000,0x0214001400000000,0x0214001400000000,0x0014001400000000,0x0014001400000000,0x0214001400000000,0x0214001400000000,0x0014001400000000,0x0014001400000000,];
+static ATTACK_TABLE_44: [Bitboard; 128] = [0x4428002844820100,0x4428002844820000,0x4428002844800000,0x4428002844800000,0x0428002804020100,0x0428002804020000,0x0428002804000000,0x0428002804000000,0x4428002840800000,0x4428002840800000,0x4428002840800000,0x4428002840800000,0x0428002800000000,0x0428002800000000,0x0428002800000000,0x0428002800000000,0x4028002844820100,0x4028002844820000,0x4028002844800000,0x4028002844800000,0x0028002804020100,0x0028002804020000,0x0028002804000000,0x0028002804000000,0x4028002840800000,0x4028002840800000,0x4028002840800000,0x4028002840800000,0x0028002800000000,0x0028002800000000,0x0028002800000000,0x0028002800000000,0x4428002804020100,0x4428002804020000,0x4428002804000000,0x4428002804000000,0x4428002844020100,0x4428002844020000,0x4428002844000000,0x4428002844000000,0x4428002800000000,0x4428002800000000,0x4428002800000000,0x4428002800000000,0x4428002840000000,0x4428002840000000,0x4428002840000000,0x4428002840000000,0x4028002804020100,0x4028002804020000,0x4028002804000000,0x4028002804000000,0x4028002844020100,0x4028002844020000,0x4028002844000000,0x4028002844000000,0x4028002800000000,0x4028002800000000,0x4028002800000000,0x4028002800000000,0x4028002840000000,0x4028002840000000,0x4028002840000000,0x4028002840000000,0x0428002844820100,0x0428002844820000,0x0428002844800000,0x0428002844800000,0x4428002804020100,0x4428002804020000,0x4428002804000000,0x4428002804000000,0x0428002840800000,0x0428002840800000,0x0428002840800000,0x0428002840800000,0x4428002800000000,0x4428002800000000,0x4428002800000000,0x4428002800000000,0x0028002844820100,0x0028002844820000,0x0028002844800000,0x0028002844800000,0x4028002804020100,0x4028002804020000,0x4028002804000000,0x4028002804000000,0x0028002840800000,0x0028002840800000,0x0028002840800000,0x0028002840800000,0x4028002800000000,0x4028002800000000,0x4028002800000000,0x4028002800000000,0x0428002804020100,0x0428002804020000,0x0428002804000000,0x0428002804000000,0x0428002844020100,0x0428002844020000,0x0428002844000000,0x0428002844000000,0x0428002800000000,0x0428002800000000,0x0428002800000000,0x0428002800000000,0x0428002840000000,0x0428002840000000,0x0428002840000000,0x0428002840000000,0x0028002804020100,0x0028002804020000,0x0028002804000000,0x0028002804000000,0x0028002844020100,0x0028002844020000,0x0028002844000000,0x0028002844000000,0x0028002800000000,0x0028002800000000,0x0028002800000000,0x0028002800000000,0x0028002840000000,0x0028002840000000,0x0028002840000000,0x0028002840000000,];
+static ATTACK_TABLE_45: [Bitboard; 128] = [0x8850005088040201,0x8850005080000000,0x8050005088000000,0x8050005080000000,0x8850005008040201,0x8850005000000000,0x8050005008000000,0x8050005000000000,0x0850005088040000,0x0850005080000000,0x0050005088000000,0x0050005080000000,0x0850005008040000,0x0850005000000000,0x0050005008000000,0x0050005000000000,0x8850005088000000,0x8850005080000000,0x8050005088040000,0x8050005080000000,0x8850005008000000,0x8850005000000000,0x8050005008040000,0x8050005000000000,0x0850005088000000,0x0850005080000000,0x0050005088040201,0x0050005080000000,0x0850005008000000,0x0850005000000000,0x0050005008040201,0x0050005000000000,0x8850005088040200,0x8850005080000000,0x8050005088000000,0x8050005080000000,0x8850005008040200,0x8850005000000000,0x8050005008000000,0x8050005000000000,0x0850005088040000,0x0850005080000000,0x0050005088000000,0x0050005080000000,0x0850005008040000,0x0850005000000000,0x0050005008000000,0x0050005000000000,0x8850005088000000,0x8850005080000000,0x8050005088040000,0x8050005080000000,0x8850005008000000,0x8850005000000000,0x8050005008040000,0x8050005000000000,0x0850005088000000,0x0850005080000000,0x0050005088040200,0x0050005080000000,0x0850005008000000,0x0850005000000000,0x0050005008040200,0x0050005000000000,0x8850005088040000,0x8850005080000000,0x8050005088000000,0x8050005080000000,0x8850005008040000,0x8850005000000000,0x8050005008000000,0x8050005000000000,0x0850005088040201,0x0850005080000000,0x0050005088000000,0x0050005080000000,0x0850005008040201,0x0850005000000000,0x0050005008000000,0x0050005000000000,0x8850005088000000,0x8850005080000000,0x8050005088040201,0x8050005080000000,0x8850005008000000,0x8850005000000000,0x8050005008040201,0x8050005000000000,0x0850005088000000,0x0850005080000000,0x0050005088040000,0x0050005080000000,0x0850005008000000,0x0850005000000000,0x0050005008040000,0x0050005000000000,0x8850005088040000,0x8850005080000000,0x8050005088000000,0x8050005080000000,0x8850005008040000,0x8850005000000000,0x8050005008000000,0x8050005000000000,0x0850005088040200,0x0850005080000000,0x0050005088000000,0x0050005080000000,0x0850005008040200,0x0850005000000000,0x0050005008000000,0x0050005000000000,0x8850005088000000,0x8850005080000000,0x8050005088040200,0x8050005080000000,0x8850005008000000,0x8850005000000000,0x8050005008040200,0x8050005000000000,0x0850005088000000,0x0850005080000000,0x0050005088040000,0x0050005080000000,0x0850005008000000,0x0850005000000000,0x0050005008040000,0x0050005000000000,];
+static ATTACK_TABLE_46: [Bitboard; 32] = [0x10A000A010080402,0x10A000A010080400,0x10A000A000000000,0x10A000A000000000,0x10A000A010000000,0x10A000A010000000,0x10A000A000000000,0x10A000A000000000,0x00A000A010080402,0x00A000A010080400,0x00A000A000000000,0x00A000A000000000,0x00A000A010000000,0x00A000A010000000,0x00A000A000000000,0x00A000A000000000,0x10A000A010080000,0x10A000A010080000,0x10A000A000000000,0x10A000A000000000,0x10A000A010000000,0x10A000A010000000,0x10A000A000000000,0x10A000A000000000,0x00A000A010080000,0x00A000A010080000,0x00A000A000000000,0x00A000A000000000,0x00A000A010000000,0x00A000A010000000,0x00A000A000000000,0x00A000A000000000,];
+static ATTACK_TABLE_47: [Bitboard; 32] = [0x2040004020100804,0x2040004000000000,0x2040004020000000,0x2040004000000000,0x0040004020000000,0x0040004000000000,0x0040004020100000,0x0040004000000000,0x2040004020100000,0x2040004000000000,0x2040004020100800,0x2040004000000000,0x0040004020000000,0x0040004000000000,0x0040004020000000,0x0040004000000000,0x2040004020000000,0x2040004000000000,0x2040004020100000,0x2040004000000000,0x0040004020100804,0x0040004000000000,0x0040004020000000,0x0040004000000000,0x2040004020000000,0x2040004000000000,0x2040004020000000,0x2040004000000000,0x0040004020100000,0x0040004000000000,0x0040004020100800,0x0040004000000000,];
+static ATTACK_TABLE_48: [Bitboard; 32] = [0x0200020408102040,0x0200020400000000,0x0200020000000000,0x0200020000000000,0x0200020408102000,0x0200020400000000,0x0200020000000000,0x0200020000000000,0x0200020408100000,0x0200020400000000,0x0200020000000000,0x0200020000000000,0x0200020408100000,0x0200020400000000,0x0200020000000000,0x0200020000000000,0x0200020408000000,0x0200020400000000,0x0200020000000000,0x0200020000000000,0x0200020408000000,0x0200020400000000,0x0200020000000000,0x0200020000000000,0x0200020408000000,0x0200020400000000,0x0200020000000000,0x0200020000000000,0x0200020408000000,0x0200020400000000,0x0200020000000000,0x0200020000000000,];
+static ATTACK_TABLE_49: [Bitboard; 32] = [0x0500050810204080,0x0500050810000000,0x0500050000000000,0x0500050000000000,0x0500050800000000,0x0500050800000000,0x0500050000000000,0x0500050000000000,0x0500050810200000,0x0500050810000000,0x0500050000000000,0x0500050000000000,0x0500050800000000,0x0500050800000000,0x0500050000000000,0x0500050000000000,0x0500050810204000,0x0500050810000000,0x0500050000000000,0x0500050000000000,0x0500050800000000,0x0500050800000000,0x0500050000000000,0x0500050000000000,0x0500050810200000,0x0500050810000000,0x0500050000000000,0x0500050000000000,0x0500050800000000,0x0500050800000000,0x0500050000000000,0x0500050000000000,];
+static ATTACK_TABLE_50: [Bitboard; 32] = [0x0A000A1120408000,0x0A000A1120400000,0x0A000A1120000000,0x0A000A1120000000,0x0A000A0000000000,0x0A000A0000000000,0x0A000A0000000000,0x0A000A0000000000,0x0A000A1100000000,0x0A000A1100000000,0x0A000A1100000000,0x0A000A1100000000,0x0A000A0000000000,0x0A000A0000000000,0x0A000A0000000000,0x0A000A0000000000,0x0A000A0100000000,0x0A000A0100000000,0x0A000A0100000000,0x0A000A0100000000,0x0A000A1020408000,0x0A000A1020400000,0x0A000A1020000000,0x0A000A1020000000,0x0A000A0100000000,0x0A000A0100000000,0x0A000A0100000000,0x0A000A0100000000,0x0A000A1000000000,0x0A000A1000000000,0x0A000A1000000000,0x0A000A1000000000,];
+static ATTACK_TABLE_51: [Bitboard; 32] = [0x1400142241800000,0x1400140201000000,0x1400140200000000,0x1400142200000000,0x1400142040800000,0x1400140000000000,0x1400140000000000,0x1400142000000000,0x1400142240800000,0x1400140200000000,0x1400142201000000,0x1400140201000000,0x1400142040800000,0x1400140000000000,0x1400142000000000,0x1400140000000000,0x1400140201000000,0x1400142241000000,0x1400142200000000,0x1400140200000000,0x1400140000000000,0x1400142040000000,0x1400142000000000,0x1400140000000000,0x1400140200000000,0x1400142240000000,0x1400140201000000,0x1400142201000000,0x1400140000000000,0x1400142040000000,0x1400140000000000,0x1400142000000000,];
+static ATTACK_TABLE_52: [Bitboard; 32] = [0x2800284482010000,0x2800284480000000,0x2800284080000000,0x2800284080000000,0x2800280402000000,0x2800280400000000,0x2800280000000000,0x2800280000000000,0x2800280402010000,0x2800280400000000,0x2800280000000000,0x2800280000000000,0x2800284482000000,0x2800284480000000,0x2800284080000000,0x2800284080000000,0x2800284402010000,0x2800284400000000,0x2800284000000000,0x2800284000000000,0x2800280402000000,0x2800280400000000,0x2800280000000000,0x2800280000000000,0x2800280402010000,0x2800280400000000,0x2800280000000000,0x2800280000000000,0x2800284402000000,0x2800284400000000,0x2800284000000000,0x2800284000000000,];
+static ATTACK_TABLE_53: [Bitboard; 32] = [0x5000508804020100,0x5000500800000000,0x5000508000000000,0x5000500000000000,0x5000508804000000,0x5000500800000000,0x5000508000000000,0x5000500000000000,0x5000508800000000,0x5000500804020100,0x5000508000000000,0x5000500000000000,0x5000508800000000,0x5000500804000000,0x5000508000000000,0x5000500000000000,0x5000508804020000,0x5000500800000000,0x5000508000000000,0x5000500000000000,0x5000508804000000,0x5000500800000000,0x5000508000000000,0x5000500000000000,0x5000508800000000,0x5000500804020000,0x5000508000000000,0x5000500000000000,0x5000508800000000,0x5000500804000000,0x5000508000000000,0x5000500000000000,];
+static ATTACK_TABLE_54: [Bitboard; 32] = [0xA000A01008040201,0xA000A01008000000,0xA000A01008040200,0xA000A01008000000,0xA000A00000000000,0xA000A00000000000,0xA000A00000000000,0xA000A00000000000,0xA000A01008040000,0xA000A01008000000,0xA000A01008040000,0xA000A01008000000,0xA000A00000000000,0xA000A00000000000,0xA000A00000000000,0xA000A00000000000,0xA000A01000000000,0xA000A01000000000,0xA000A01000000000,0xA000A01000000000,0xA000A00000000000,0xA000A00000000000,0xA000A00000000000,0xA000A00000000000,0xA000A01000000000,0xA000A01000000000,0xA000A01000000000,0xA000A01000000000,0xA000A00000000000,0xA000A00000000000,0xA000A00000000000,0xA000A00000000000,];
+static ATTACK_TABLE_55: [Bitboard; 32] = [0x4000402010080402,0x4000402010080000,0x4000402000000000,0x4000402000000000,0x4000402010000000,0x4000402010000000,0x4000402000000000,0x4000402000000000,0x4000400000000000,0x4000400000000000,0x4000400000000000,0x4000400000000000,0x4000400000000000,0x4000400000000000,0x4000400000000000,0x4000400000000000,0x4000402010080400,0x4000402010080000,0x4000402000000000,0x4000402000000000,0x4000402010000000,0x4000402010000000,0x4000402000000000,0x4000402000000000,0x4000400000000000,0x4000400000000000,0x4000400000000000,0x4000400000000000,0x4000400000000000,0x4000400000000000,0x4000400000000000,0x4000400000000000,];
+static ATTACK_TABLE_56: [Bitboard; 64] = [0x0002040810204080,0x0002000000000000,0x0002040800000000,0x0002000000000000,0x0002040810200000,0x0002000000000000,0x0002040800000000,0x0002000000000000,0x0002040000000000,0x0002000000000000,0x0002040000000000,0x0002000000000000,0x0002040000000000,0x0002000000000000,0x0002040000000000,0x0002000000000000,0x0002040810000000,0x0002000000000000,0x0002040800000000,0x0002000000000000,0x0002040810000000,0x0002000000000000,0x0002040800000000,0x0002000000000000,0x0002040000000000,0x0002000000000000,0x0002040000000000,0x0002000000000000,0x0002040000000000,0x0002000000000000,0x0002040000000000,0x0002000000000000,0x0002040810204000,0x0002000000000000,0x0002040800000000,0x0002000000000000,0x0002040810200000,0x0002000000000000,0x0002040800000000,0x0002000000000000,0x0002040000000000,0x0002000000000000,0x0002040000000000,0x0002000000000000,0x0002040000000000,0x0002000000000000,0x0002040000000000,0x0002000000000000,0x0002040810000000,0x0002000000000000,0x0002040800000000,0x0002000000000000,0x0002040810000000,0x0002000000000000,0x0002040800000000,0x0002000000000000,0x0002040000000000,0x0002000000000000,0x0002040000000000,0x0002000000000000,0x0002040000000000,0x0002000000000000,0x0002040000000000,0x0002000000000000,];
+static ATTACK_TABLE_57: [Bitboard; 32] = [0x0005081020408000,0x0005000000000000,0x0005081020000000,0x0005000000000000,0x0005081000000000,0x0005000000000000,0x0005081000000000,0x0005000000000000,0x0005081020400000,0x0005000000000000,0x0005081020000000,0x0005000000000000,0x0005081000000000,0x0005000000000000,0x0005081000000000,0x0005000000000000,0x0005080000000000,0x0005000000000000,0x0005080000000000,0x0005000000000000,0x0005080000000000,0x0005000000000000,0x0005080000000000,0x0005000000000000,0x0005080000000000,0x0005000000000000,0x0005080000000000,0x0005000000000000,0x0005080000000000,0x0005000000000000,0x0005080000000000,0x0005000000000000,];
+static ATTACK_TABLE_58: [Bitboard; 32] = [0x000A112040800000,0x000A112040000000,0x000A102040800000,0x000A102040000000,0x000A110000000000,0x000A110000000000,0x000A100000000000,0x000A100000000000,0x000A010000000000,0x000A010000000000,0x000A000000000000,0x000A000000000000,0x000A010000000000,0x000A010000000000,0x000A000000000000,0x000A000000000000,0x000A112000000000,0x000A112000000000,0x000A102000000000,0x000A102000000000,0x000A110000000000,0x000A110000000000,0x000A100000000000,0x000A100000000000,0x000A010000000000,0x000A010000000000,0x000A000000000000,0x000A000000000000,0x000A010000000000,0x000A010000000000,0x000A000000000000,0x000A000000000000,];
+static ATTACK_TABLE_59: [Bitboard; 32] = [0x0014224180000000,0x0014224080000000,0x0014000000000000,0x0014000000000000,0x0014220100000000,0x0014220000000000,0x0014000000000000,0x0014000000000000,0x0014020100000000,0x0014020000000000,0x0014204080000000,0x0014204080000000,0x0014020100000000,0x0014020000000000,0x0014200000000000,0x0014200000000000,0x0014220100000000,0x0014220000000000,0x0014000000000000,0x0014000000000000,0x0014224100000000,0x0014224000000000,0x0014000000000000,0x0014000000000000,0x0014020100000000,0x0014020000000000,0x0014200000000000,0x0014200000000000,0x0014020100000000,0x0014020000000000,0x0014204000000000,0x0014204000000000,];
+static ATTACK_TABLE_60: [Bitboard; 32] = [0x0028448201000000,0x0028400000000000,0x0028040201000000,0x0028000000000000,0x0028408000000000,0x0028440000000000,0x0028000000000000,0x0028040000000000,0x0028448200000000,0x0028400000000000,0x0028040200000000,0x0028000000000000,0x0028408000000000,0x0028448000000000,0x0028000000000000,0x0028040000000000,0x0028440201000000,0x0028408000000000,0x0028040201000000,0x0028000000000000,0x0028400000000000,0x0028448000000000,0x0028000000000000,0x0028040000000000,0x0028440200000000,0x0028408000000000,0x0028040200000000,0x0028000000000000,0x0028400000000000,0x0028440000000000,0x0028000000000000,0x0028040000000000,];
+static ATTACK_TABLE_61: [Bitboard; 32] = [0x0050880402010000,0x0050880400000000,0x0050800000000000,0x0050800000000000,0x0050880402000000,0x0050880400000000,0x0050800000000000,0x0050800000000000,0x0050080402010000,0x0050080400000000,0x0050000000000000,0x0050000000000000,0x0050080402000000,0x0050080400000000,0x0050000000000000,0x0050000000000000,0x0050880000000000,0x0050880000000000,0x0050800000000000,0x0050800000000000,0x0050880000000000,0x0050880000000000,0x0050800000000000,0x0050800000000000,0x0050080000000000,0x0050080000000000,0x0050000000000000,0x0050000000000000,0x0050080000000000,0x0050080000000000,0x0050000000000000,0x0050000000000000,];
+static ATTACK_TABLE_62: [Bitboard; 32] = [0x00A0100804020100,0x00A0100804020000,0x00A0100800000000,0x00A0100800000000,0x00A0100000000000,0x00A0100000000000,0x00A0100000000000,0x00A0100000000000,0x00A0100804000000,0x00A0100804000000,0x00A0100800000000,0x00A0100800000000,0x00A0100000000000,0x00A0100000000000,0x00A0100000000000,0x00A0100000000000,0x00A0000000000000,0x00A0000000000000,0x00A0000000000000,0x00A0000000000000,0x00A0000000000000,0x00A0000000000000,0x00A0000000000000,0x00A0000000000000,0x00A0000000000000,0x00A0000000000000,0x00A0000000000000,0x00A0000000000000,0x00A0000000000000,0x00A0000000000000,0x00A0000000000000,0x00A0000000000000,];
+static ATTACK_TABLE_63: [Bitboard; 64] = [0x0040201008040201,0x0040201000000000,0x0040200000000000,0x0040200000000000,0x0040201008000000,0x0040201000000000,0x0040200000000000,0x0040200000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040201008040200,0x0040201000000000,0x0040200000000000,0x0040200000000000,0x0040201008000000,0x0040201000000000,0x0040200000000000,0x0040200000000000,0x0040201008040000,0x0040201000000000,0x0040200000000000,0x0040200000000000,0x0040201008000000,0x0040201000000000,0x0040200000000000,0x0040200000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040000000000000,0x0040201008040000,0x0040201000000000,0x0040200000000000,0x0040200000000000,0x0040201008000000,0x0040201000000000,0x0040200000000000,0x0040200000000000,];
@@ -1,10 +1,22 @@
 use super::{Color, Bitboard, utils};
 
 mod magic;
-use magic::Magic;
+pub use magic::Magic;
 
 pub mod pawn;
 pub mod knight;
+pub mod king;
+
+#[cfg(not(feature = "generate-magics"))]
 pub mod bishop;
+#[cfg(not(feature = "generate-magics"))]
 pub mod rook;
-pub mod king;
+
+// Written by build.rs, see tools/magics_finders
+#[cfg(feature = "generate-magics")]
+pub mod bishop { include!(concat!(env!("OUT_DIR"), "/bishop.rs")); }
+#[cfg(feature = "generate-magics")]
+pub mod rook { include!(concat!(env!("OUT_DIR"), "/rook.rs")); }
+
+#[cfg(any(use_pext, test))]
+pub mod pext;
@@ -0,0 +1,87 @@
+use super::{Bitboard, Magic, bishop, rook};
+
+/*
+ * PEXT attack tables
+ *
+ * With BMI2, _pext_u64(occupied, mask) packs the occupancy of the mask squares
+ * into a dense index, no magic number needed. The tables are laid out in that
+ * order, each square's subtable starting at its offset, and are built at
+ * compile time from the magic ones.
+ */
+
+pub struct PextTable<const N: usize> {
+    masks: [Bitboard; 64],
+    offsets: [usize; 64],
+    attacks: [Bitboard; N],
+}
+
+impl<const N: usize> PextTable<N> {
+    #[inline(always)]
+    pub fn attacks(&self, square: usize, occupied: Bitboard) -> Bitboard {
+        self.attacks[self.offsets[square] + pext(occupied, self.masks[square]) as usize]
+    }
+}
+
+pub static BISHOP: PextTable<{ table_size(&bishop::MAGICS) }> = build(&bishop::MAGICS);
+pub static ROOK: PextTable<{ table_size(&rook::MAGICS) }> = build(&rook::MAGICS);
+
+const fn table_size(magics: &[Magic; 64]) -> usize {
+    let mut size = 0;
+    let mut square = 0;
+    while square < 64 {
+        size += 1 << magics[square].mask.count_ones();
+        square += 1;
+    }
+    size
+}
+
+const fn build<const N: usize>(magics: &[Magic; 64]) -> PextTable<N> {
+    let mut table = PextTable { masks: [0; 64], offsets: [0; 64], attacks: [0; N] };
+    let mut offset = 0;
+
+    let mut square = 0;
+    while square < 64 {
+        let magic = &magics[square];
+        table.masks[square] = magic.mask;
+        table.offsets[square] = offset;
+
+        // Carry-Rippler goes through the subsets of the mask in pext order
+        let mut subset: Bitboard = 0;
+        loop {
+            let index = (subset.wrapping_mul(magic.magic) >> magic.shift) as usize;
+            table.attacks[offset] = magic.attacks[index];
+            offset += 1;
+
+            subset = subset.wrapping_sub(magic.mask) & magic.mask;
+            if subset == 0 {
+                break;
+            }
+        }
+
+        square += 1;
+    }
+
+    table
+}
+
+#[cfg(use_pext)]
+#[inline(always)]
+fn pext(value: Bitboard, mask: Bitboard) -> u64 {
+    // SAFETY: use_pext is only set when compiling for BMI2
+    unsafe { std::arch::x86_64::_pext_u64(value, mask) }
+}
+
+// Without BMI2 the tables are only used by the tests
+#[cfg(not(use_pext))]
+fn pext(value: Bitboard, mut mask: Bitboard) -> u64 {
+    let mut result = 0;
+    let mut bit = 0;
+    while mask != 0 {
+        if value & mask & mask.wrapping_neg() != 0 {
+            result |= 1 << bit;
+        }
+        mask &= mask - 1;
+        bit += 1;
+    }
+    result
+}
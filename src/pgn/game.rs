@@ -0,0 +1,259 @@
+use super::reader::{self, PgnError};
+use super::{Board, Color, FenError, Move};
+
+/// Export format lines are at most 79 characters
+const LINE_WIDTH: usize = 79;
+
+/// Result of a game, as in the `Result` tag
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GameResult {
+    /// 1-0
+    WhiteWins,
+    /// 0-1
+    BlackWins,
+    /// 1/2-1/2
+    Draw,
+    /// *, unfinished or unknown
+    Unknown,
+}
+
+impl GameResult {
+    /// Parse a result such as "1-0"
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "1-0"     => Some(Self::WhiteWins),
+            "0-1"     => Some(Self::BlackWins),
+            "1/2-1/2" => Some(Self::Draw),
+            "*"       => Some(Self::Unknown),
+            _ => None,
+        }
+    }
+
+    /// The result as written in PGN
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::WhiteWins => "1-0",
+            Self::BlackWins => "0-1",
+            Self::Draw      => "1/2-1/2",
+            Self::Unknown   => "*",
+        }
+    }
+}
+
+/// A move with its annotations and the alternatives to it
+#[derive(Clone, Debug)]
+pub struct Node {
+    /// The move
+    pub mv: Move,
+    /// Numeric annotation glyphs ($1 for "!", ...)
+    pub nags: Vec<u8>,
+    /// Comment after the move
+    pub comment: Option<String>,
+    /// Lines played instead of this move
+    pub variations: Vec<Vec<Node>>,
+}
+
+impl Node {
+    /// A move without annotations
+    pub fn new(mv: Move) -> Self {
+        Self { mv, nags: Vec::new(), comment: None, variations: Vec::new() }
+    }
+}
+
+/// A game: its tags, moves with their annotations, and result
+#[derive(Clone, Debug)]
+pub struct Game {
+    /// Tag pairs, in file order
+    pub tags: Vec<(String, String)>,
+    /// Comment before the first move
+    pub comment: Option<String>,
+    /// Main line
+    pub moves: Vec<Node>,
+    /// Result after the moves
+    pub result: GameResult,
+}
+
+impl Game {
+    /// Read every game in a PGN file
+    pub fn from_file(path: &str) -> Result<Vec<Self>, PgnError> {
+        Self::parse_all(&std::fs::read_to_string(path)?)
+    }
+
+    /// Read every game in PGN text. Moves are checked against the position
+    /// they are played in, so the games can be replayed on a `Board`.
+    pub fn parse_all(text: &str) -> Result<Vec<Self>, PgnError> {
+        reader::parse(text)
+    }
+
+    /// Value of a tag, by name
+    pub fn tag(&self, name: &str) -> Option<&str> {
+        self.tags
+            .iter()
+            .find(|(tag, _)| tag == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// The position given by the FEN tag, or the starting position
+    pub fn start_board(&self) -> Result<Board, FenError> {
+        match self.tag("FEN") {
+            Some(fen) => Board::from_fen(fen),
+            None => Ok(Board::startpos()),
+        }
+    }
+
+    /// FENs of every position of the main line, starting position included
+    pub fn mainline_fens(&self) -> Result<Vec<String>, FenError> {
+        let mut board = self.start_board()?;
+        let mut fens = vec![board.to_fen()];
+
+        for node in &self.moves {
+            board.make_move(&node.mv);
+            fens.push(board.to_fen());
+        }
+
+        Ok(fens)
+    }
+
+    /// The game in PGN export format
+    pub fn to_pgn(&self) -> Result<String, FenError> {
+        let mut pgn = String::new();
+
+        for (name, value) in &self.tags {
+            let value = value.replace('\\', "\\\\").replace('"', "\\\"");
+            pgn.push_str(&format!("[{name} \"{value}\"]\n"));
+        }
+        pgn.push('\n');
+
+        let mut board = self.start_board()?;
+        let ply = (board.get_fullmove_number() as usize - 1) * 2
+            + if *board.color_to_move() == Color::Black { 1 } else { 0 };
+
+        let mut words = Vec::new();
+        if let Some(comment) = &self.comment {
+            words.push(format!("{{{comment}}}"));
+        }
+        write_line(&mut board, &self.moves, ply, &mut words);
+        words.push(self.result.as_str().to_string());
+
+        // Fill lines up to the width
+        let mut line = String::new();
+        for word in words {
+            if !line.is_empty() && line.len() + 1 + word.len() > LINE_WIDTH {
+                pgn.push_str(&line);
+                pgn.push('\n');
+                line.clear();
+            }
+
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(&word);
+        }
+        pgn.push_str(&line);
+        pgn.push('\n');
+
+        Ok(pgn)
+    }
+}
+
+/// Movetext of a line played from `board`, which is left unchanged
+fn write_line(board: &mut Board, nodes: &[Node], mut ply: usize, words: &mut Vec<String>) {
+    // Black moves need their number after anything interrupting the move list
+    let mut show_number = true;
+
+    for node in nodes {
+        let number = ply / 2 + 1;
+        let san = board.move_to_san(&node.mv);
+
+        if ply.is_multiple_of(2) {
+            words.push(format!("{number}. {san}"));
+        } else if show_number {
+            words.push(format!("{number}... {san}"));
+        } else {
+            words.push(san);
+        }
+        show_number = false;
+
+        for nag in &node.nags {
+            words.push(format!("${nag}"));
+        }
+
+        if let Some(comment) = &node.comment {
+            words.push(format!("{{{comment}}}"));
+            show_number = true;
+        }
+
+        for variation in &node.variations {
+            let start = words.len();
+            write_line(board, variation, ply, words);
+
+            if start == words.len() {
+                continue;
+            }
+            words[start].insert(0, '(');
+            words.last_mut().unwrap().push(')');
+            show_number = true;
+        }
+
+        board.make_move(&node.mv);
+        ply += 1;
+    }
+
+    for _ in nodes {
+        board.revert_state();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Morphy's opera game, with a variation, annotations and comments
+    const OPERA_GAME: &str = r#"[Event "Paris \"Opera\" game"]
+[Site "Paris FRA"]
+[Date "1858.??.??"]
+[White "Paul Morphy"]
+[Black "Duke Karl / Count Isouard"]
+[Result "1-0"]
+
+{Played during a performance of The Barber of Seville} 1. e4 e5 2. Nf3 d6 3. d4 Bg4
+(3... exd4 4. Qxd4 {Centralised}) 4. dxe5 Bxf3 5. Qxf3 dxe5 6. Bc4 Nf6 7. Qb3 Qe7
+8. Nc3 c6 9. Bg5 b5 10. Nxb5! cxb5 11. Bxb5+ Nbd7 12. O-O-O Rd8 13. Rxd7 Rxd7 14.
+Rd1 Qe6 15. Bxd7+ Nxd7 16. Qb8+ $3 Nxb8 17. Rd8# 1-0
+"#;
+
+    #[test]
+    fn round_trip() {
+        let game = &Game::parse_all(OPERA_GAME).unwrap()[0];
+
+        assert_eq!(game.tag("Event"), Some("Paris \"Opera\" game"));
+        assert_eq!(game.comment.as_deref(), Some("Played during a performance of The Barber of Seville"));
+        assert_eq!(game.result, GameResult::WhiteWins);
+        assert_eq!(game.moves.len(), 33);
+        assert_eq!(game.moves[5].variations.len(), 1);
+        assert_eq!(game.moves[18].nags, [1]);
+        assert_eq!(game.moves[30].nags, [3]);
+
+        let pgn = game.to_pgn().unwrap();
+        assert!(pgn.lines().all(|line| line.len() <= LINE_WIDTH), "{pgn}");
+        let movetext = pgn.split_whitespace().collect::<Vec<_>>().join(" ");
+        assert!(movetext.contains("3. d4 Bg4 (3... exd4 4. Qxd4 {Centralised}) 4. dxe5"), "{pgn}");
+        assert!(movetext.contains("10. Nxb5 $1 cxb5"), "{pgn}");
+        assert!(movetext.ends_with("17. Rd8# 1-0"), "{pgn}");
+
+        let again = &Game::parse_all(&pgn).unwrap()[0];
+        assert_eq!(again.to_pgn().unwrap(), pgn);
+        assert_eq!(again.tags, game.tags);
+        assert_eq!(again.mainline_fens().unwrap(), game.mainline_fens().unwrap());
+    }
+
+    #[test]
+    fn black_to_move_start() {
+        let text = "[FEN \"rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1\"]\n\n1... e5 2. Nf3 *";
+        let game = &Game::parse_all(text).unwrap()[0];
+        let pgn = game.to_pgn().unwrap();
+
+        assert!(pgn.ends_with("\n1... e5 2. Nf3 *\n"), "{pgn}");
+        assert_eq!(Game::parse_all(&pgn).unwrap()[0].to_pgn().unwrap(), pgn);
+    }
+}
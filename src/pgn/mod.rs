@@ -0,0 +1,9 @@
+//! PGN games with comments and variations
+
+use crate::board::{Board, Color, FenError, Move, SanError};
+
+mod game;
+mod reader;
+
+pub use game::{Game, GameResult, Node};
+pub use reader::PgnError;
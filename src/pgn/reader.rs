@@ -0,0 +1,389 @@
+use std::iter::Peekable;
+use std::str::Chars;
+
+use super::game::{Game, GameResult, Node};
+use super::{Board, FenError, SanError};
+
+/// Why a PGN file could not be read, with the line of the problem
+#[derive(Debug)]
+pub enum PgnError {
+    /// The file could not be read
+    Io(std::io::Error),
+    /// Not PGN
+    Syntax(usize, String),
+    /// An invalid `FEN` tag
+    Fen(usize, FenError),
+    /// A move that isn't legal SAN in its position
+    San(usize, SanError),
+}
+
+impl std::fmt::Display for PgnError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Io(err)            => write!(f, "{err}"),
+            Self::Syntax(line, msg)  => write!(f, "line {line}: {msg}"),
+            Self::Fen(line, err)     => write!(f, "line {line}: invalid FEN tag: {err}"),
+            Self::San(line, err)     => write!(f, "line {line}: {err}"),
+        }
+    }
+}
+
+impl From<std::io::Error> for PgnError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+enum Token {
+    Tag(String, String),
+    Comment(String),
+    Nag(u8),
+    Open,
+    Close,
+    Result(GameResult),
+    Move(String, Option<u8>), // With the NAG of a "!?" style suffix
+}
+
+struct Lexer<'a> {
+    chars: Peekable<Chars<'a>>,
+    line: usize,
+    line_start: bool,
+}
+
+impl<'a> Lexer<'a> {
+    fn new(text: &'a str) -> Self {
+        Self { chars: text.chars().peekable(), line: 1, line_start: true }
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        self.line_start = c == '\n';
+        if c == '\n' {
+            self.line += 1;
+        }
+        Some(c)
+    }
+
+    fn read_while(&mut self, f: impl Fn(char) -> bool) -> String {
+        let mut s = String::new();
+        while let Some(&c) = self.chars.peek() {
+            if !f(c) {
+                break;
+            }
+            s.push(c);
+            self.bump();
+        }
+        s
+    }
+
+    fn error(&self, msg: impl Into<String>) -> PgnError {
+        PgnError::Syntax(self.line, msg.into())
+    }
+
+    /// Next token and the line it starts on
+    fn next_token(&mut self) -> Result<Option<(Token, usize)>, PgnError> {
+        loop {
+            let Some(&c) = self.chars.peek() else { return Ok(None) };
+            let line = self.line;
+
+            // Escaped lines are ignored
+            if c == '%' && self.line_start {
+                self.read_while(|c| c != '\n');
+                continue;
+            }
+
+            if c.is_whitespace() {
+                self.bump();
+                continue;
+            }
+
+            self.bump();
+            let token = match c {
+                '[' => self.read_tag()?,
+
+                '{' => {
+                    let comment = self.read_while(|c| c != '}');
+                    if self.bump().is_none() {
+                        return Err(PgnError::Syntax(line, "unterminated comment".to_string()));
+                    }
+                    Token::Comment(comment.split_whitespace().collect::<Vec<_>>().join(" "))
+                }
+
+                ';' => Token::Comment(self.read_while(|c| c != '\n').trim().to_string()),
+
+                '(' => Token::Open,
+                ')' => Token::Close,
+
+                '$' => {
+                    let nag = self.read_while(|c| c.is_ascii_digit());
+                    Token::Nag(nag.parse().map_err(|_| self.error(format!("invalid NAG '${nag}'")))?)
+                }
+
+                _ => {
+                    let mut symbol = c.to_string();
+                    symbol.push_str(&self.read_while(|c| !c.is_whitespace() && !"{}()[];$".contains(c)));
+
+                    if let Some(result) = GameResult::parse(&symbol) {
+                        Token::Result(result)
+                    } else {
+                        // Move numbers may be glued to the move ("12.e4", "12...Nf6")
+                        let digits = symbol.len() - symbol.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+                        let rest = &symbol[digits..];
+                        let san = if rest.starts_with('.') { rest.trim_start_matches('.') } else { &symbol };
+
+                        if san.is_empty() {
+                            continue;
+                        }
+
+                        let bare = san.trim_end_matches(['!', '?']);
+                        let nag = match &san[bare.len()..] {
+                            ""   => None,
+                            "!"  => Some(1),
+                            "?"  => Some(2),
+                            "!!" => Some(3),
+                            "??" => Some(4),
+                            "!?" => Some(5),
+                            "?!" => Some(6),
+                            other => return Err(self.error(format!("invalid annotation '{other}'"))),
+                        };
+
+                        Token::Move(bare.to_string(), nag)
+                    }
+                }
+            };
+
+            return Ok(Some((token, line)));
+        }
+    }
+
+    /// Tag pair after the '[': Name "value"]
+    fn read_tag(&mut self) -> Result<Token, PgnError> {
+        self.read_while(char::is_whitespace);
+        let name = self.read_while(|c| c.is_alphanumeric() || c == '_');
+        self.read_while(char::is_whitespace);
+
+        if name.is_empty() || self.bump() != Some('"') {
+            return Err(self.error("malformed tag pair"));
+        }
+
+        let mut value = String::new();
+        loop {
+            match self.bump() {
+                Some('"') => break,
+                Some('\\') => match self.bump() {
+                    Some(c) => value.push(c),
+                    None => return Err(self.error("unterminated tag value")),
+                },
+                Some(c) => value.push(c),
+                None => return Err(self.error("unterminated tag value")),
+            }
+        }
+
+        self.read_while(char::is_whitespace);
+        if self.bump() != Some(']') {
+            return Err(self.error("malformed tag pair"));
+        }
+
+        Ok(Token::Tag(name, value))
+    }
+}
+
+struct Parser<'a> {
+    lexer: Lexer<'a>,
+    peeked: Option<(Token, usize)>,
+}
+
+impl Parser<'_> {
+    fn next(&mut self) -> Result<Option<(Token, usize)>, PgnError> {
+        match self.peeked.take() {
+            Some(token) => Ok(Some(token)),
+            None => self.lexer.next_token(),
+        }
+    }
+
+    fn peek(&mut self) -> Result<Option<&Token>, PgnError> {
+        if self.peeked.is_none() {
+            self.peeked = self.lexer.next_token()?;
+        }
+        Ok(self.peeked.as_ref().map(|(token, _)| token))
+    }
+
+    fn game(&mut self) -> Result<Option<Game>, PgnError> {
+        let mut tags = Vec::new();
+        let mut line = self.lexer.line;
+
+        while let Some(Token::Tag(..)) = self.peek()? {
+            let Some((Token::Tag(name, value), tag_line)) = self.next()? else { unreachable!() };
+            if name == "FEN" {
+                line = tag_line;
+            }
+            tags.push((name, value));
+        }
+
+        if tags.is_empty() && self.peek()?.is_none() {
+            return Ok(None);
+        }
+
+        let mut game = Game { tags, comment: None, moves: Vec::new(), result: GameResult::Unknown };
+        let mut board = game.start_board().map_err(|err| PgnError::Fen(line, err))?;
+
+        while let Some(Token::Comment(_)) = self.peek()? {
+            let Some((Token::Comment(comment), _)) = self.next()? else { unreachable!() };
+            append_comment(&mut game.comment, comment);
+        }
+
+        game.moves = self.line(&mut board, false)?;
+
+        if let Some(Token::Result(_)) = self.peek()? {
+            let Some((Token::Result(result), _)) = self.next()? else { unreachable!() };
+            game.result = result;
+        }
+
+        Ok(Some(game))
+    }
+
+    /*
+     * Moves until the end of the game (or of the variation), played on
+     * `board` and taken back before returning. Comments before the first
+     * move of a variation are dropped.
+     */
+    fn line(&mut self, board: &mut Board, variation: bool) -> Result<Vec<Node>, PgnError> {
+        let mut nodes: Vec<Node> = Vec::new();
+
+        loop {
+            match self.peek()? {
+                // The next game, or the end of this one
+                None | Some(Token::Tag(..)) | Some(Token::Result(_)) if !variation => break,
+                Some(Token::Close) if variation => {
+                    self.next()?;
+                    break;
+                }
+                _ => {}
+            }
+
+            let Some((token, line)) = self.next()? else {
+                return Err(self.lexer.error("unterminated variation"));
+            };
+
+            match token {
+                Token::Move(san, nag) => {
+                    let mv = board.parse_san(&san).map_err(|err| PgnError::San(line, err))?;
+                    board.make_move(&mv);
+
+                    let mut node = Node::new(mv);
+                    node.nags.extend(nag);
+                    nodes.push(node);
+                }
+
+                Token::Nag(nag) => match nodes.last_mut() {
+                    Some(node) => node.nags.push(nag),
+                    None => return Err(PgnError::Syntax(line, "NAG before any move".to_string())),
+                },
+
+                Token::Comment(comment) => {
+                    if let Some(node) = nodes.last_mut() {
+                        append_comment(&mut node.comment, comment);
+                    }
+                }
+
+                Token::Open => {
+                    let Some(node) = nodes.last_mut() else {
+                        return Err(PgnError::Syntax(line, "variation before any move".to_string()));
+                    };
+
+                    // The variation replaces the last move
+                    board.revert_state();
+                    let variation = self.line(board, true)?;
+                    board.make_move(&node.mv);
+
+                    node.variations.push(variation);
+                }
+
+                Token::Close => return Err(PgnError::Syntax(line, "unmatched ')'".to_string())),
+                Token::Result(_) => return Err(PgnError::Syntax(line, "result inside a variation".to_string())),
+                Token::Tag(..) => return Err(PgnError::Syntax(line, "tag inside a variation".to_string())),
+            }
+        }
+
+        for _ in &nodes {
+            board.revert_state();
+        }
+
+        Ok(nodes)
+    }
+}
+
+fn append_comment(target: &mut Option<String>, comment: String) {
+    match target {
+        Some(existing) => {
+            existing.push(' ');
+            existing.push_str(&comment);
+        }
+        None => *target = Some(comment),
+    }
+}
+
+pub fn parse(text: &str) -> Result<Vec<Game>, PgnError> {
+    let mut parser = Parser { lexer: Lexer::new(text), peeked: None };
+
+    let mut games = Vec::new();
+    while let Some(game) = parser.game()? {
+        games.push(game);
+    }
+
+    Ok(games)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(text: &str) -> PgnError {
+        match parse(text) {
+            Ok(_) => panic!("{text} parsed"),
+            Err(err) => err,
+        }
+    }
+
+    #[test]
+    fn nested_variations() {
+        let text = "1. e4 e5 (1... c5 2. Nf3 (2. c3 d5 (2... Nf6)) 2... d6) (1... e6) 2. Nf3 *";
+        let games = parse(text).unwrap();
+        let moves = &games[0].moves;
+
+        assert_eq!(moves.len(), 3);
+        assert_eq!(moves[1].variations.len(), 2);
+
+        let sicilian = &moves[1].variations[0];
+        assert_eq!(sicilian.len(), 3);
+        assert_eq!(sicilian[1].variations.len(), 1);
+        assert_eq!(sicilian[1].variations[0].len(), 2);
+        assert_eq!(sicilian[1].variations[0][1].variations[0].len(), 1);
+        assert_eq!(moves[1].variations[1].len(), 1);
+    }
+
+    #[test]
+    fn several_games() {
+        let text = "[Event \"a\"]\n\n1. e4 1-0\n\n[Event \"b\"]\n\n1. d4 d5 0-1\n";
+        let games = parse(text).unwrap();
+
+        assert_eq!(games.len(), 2);
+        assert_eq!(games[1].tag("Event"), Some("b"));
+        assert_eq!(games[1].moves.len(), 2);
+        assert_eq!(games[1].result, GameResult::BlackWins);
+    }
+
+    #[test]
+    fn malformed_movetext() {
+        assert!(matches!(error("1. e4 e5 2. Ke3 *"), PgnError::San(1, SanError::IllegalMove(_))));
+        assert!(matches!(error("1. e4\n2. Nf9 *"), PgnError::San(2, SanError::InvalidSyntax(_))));
+        assert!(matches!(error("1. e4 e5 (1... c5 *"), PgnError::Syntax(..)));
+        assert!(matches!(error("1. e4 ) e5 *"), PgnError::Syntax(1, _)));
+        assert!(matches!(error("( 1. e4 ) *"), PgnError::Syntax(1, _)));
+        assert!(matches!(error("$1 1. e4 *"), PgnError::Syntax(1, _)));
+        assert!(matches!(error("1. e4 {unterminated"), PgnError::Syntax(1, _)));
+        assert!(matches!(error("1. e4?!! *"), PgnError::Syntax(1, _)));
+        assert!(matches!(error("[Event \"a\" 1. e4 *"), PgnError::Syntax(1, _)));
+        assert!(matches!(error("[FEN \"8/8/8 w - - 0 1\"]\n1. e4 *"), PgnError::Fen(1, _)));
+    }
+}
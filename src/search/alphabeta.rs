@@ -0,0 +1,435 @@
+use std::fmt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use rand::Rng;
+use super::{
+    Board,
+    Move,
+    MoveGen,
+    Network,
+    SearchThread,
+    Wdl,
+    TTBound, TranspositionTable,
+    Tablebases,
+    MAX_PLY,
+    score::{self, DRAW, INFINITY, MATE, TB_WIN}
+};
+
+/// Outcome of the last completed iteration of a search
+#[derive(Clone, Copy)]
+pub struct SearchResult {
+    /// `None` without legal moves
+    pub best_move: Option<Move>,
+    /// Centipawns for the side to move, a mate in n plies is ±(32000 - n)
+    pub score: i16,
+    /// Depth of the iteration
+    pub depth: u8,
+    /// Nodes searched by all threads, set once the search is over
+    pub nodes: u64,
+}
+
+/// Progress of a search: every iteration the main thread completes, then
+/// the final result with the nodes of all threads
+#[derive(Clone, Copy)]
+pub struct SearchInfo {
+    /// Depth of the iteration
+    pub depth: u8,
+    /// Centipawns for the side to move, a mate in n plies is ±(32000 - n)
+    pub score: i16,
+    /// Nodes searched so far
+    pub nodes: u64,
+    /// Time since the search started
+    pub time: Duration,
+    /// Permille of the transposition table in use
+    pub hashfull: usize,
+    /// Tablebase probes that found a result
+    pub tb_hits: u64,
+    /// `None` without legal moves
+    pub best_move: Option<Move>,
+}
+
+impl fmt::Display for SearchInfo {
+    /// The UCI `info` line
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let nps = (self.nodes as f64 / self.time.as_secs_f64().max(1e-9)) as u64;
+
+        write!(
+            f,
+            "info depth {} score {} nodes {} nps {nps} time {} hashfull {} tbhits {} pv {}",
+            self.depth,
+            score::to_uci(self.score),
+            self.nodes,
+            self.time.as_millis(),
+            self.hashfull,
+            self.tb_hits,
+            self.best_move.map_or(String::from("0000"), |mv| mv.to_uci())
+        )
+    }
+}
+
+/// Called with the progress of a search, from the thread running it
+pub type InfoCallback = Arc<dyn Fn(&SearchInfo) + Send + Sync>;
+
+/// How far to search: up to `depth`, or until `movetime` runs out
+#[derive(Clone, Copy)]
+pub struct SearchLimits {
+    /// Deepest iteration
+    pub depth: u8,
+    /// Time after which the search stops, keeping the last completed iteration
+    pub movetime: Option<Duration>,
+}
+
+impl SearchLimits {
+    /// Deepest iteration of a search only limited by time, and the deepest one can ask for
+    pub const MAX_DEPTH: u8 = 64;
+
+    /// Search to a fixed depth
+    pub const fn depth(depth: u8) -> Self {
+        Self { depth, movetime: None }
+    }
+
+    /// Search for a fixed time, up to `MAX_DEPTH`
+    pub const fn movetime(movetime: Duration) -> Self {
+        Self { depth: Self::MAX_DEPTH, movetime: Some(movetime) }
+    }
+}
+
+/// What a search runs with besides its limits
+#[derive(Clone)]
+pub struct SearchOptions {
+    /// Lazy SMP threads, at least 1
+    pub threads: usize,
+    /// `None` for the handcrafted evaluation
+    pub network: Option<Arc<Network>>,
+    /// Syzygy tables probed at the root and in the tree
+    pub tablebases: Option<Arc<Tablebases>>,
+    /// Where to report progress, `None` to search silently
+    pub info: Option<InfoCallback>,
+}
+
+impl Default for SearchOptions {
+    fn default() -> Self {
+        Self { threads: 1, network: None, tablebases: None, info: None }
+    }
+}
+
+/// Alpha-beta search, see `Search::lazy_smp`
+pub struct Search;
+
+#[allow(unused)]
+impl Search {
+    /// A legal move picked at random, the position must have one
+    pub fn random(board: &mut Board, rng: &mut rand::rngs::ThreadRng) -> Move {
+        let mut legal_moves = Vec::new();
+
+        let moves = MoveGen::get_pseudolegal_moves(board);
+        for mv in moves {
+            board.make_move(&mv);
+
+            if board.was_illegal_move() {
+                board.revert_state();
+                continue;
+            }
+
+            legal_moves.push(mv);
+
+            board.revert_state();
+        }
+
+        legal_moves[rng.random_range(0..legal_moves.len())]
+    }
+
+    fn _alphabeta(
+        thread: &mut SearchThread,
+        tt: &TranspositionTable,
+        stop: &AtomicBool,
+        depth: u8,
+        ply: usize,
+        mut alpha: i16,
+        beta: i16
+    ) -> i16 {
+        // The result is thrown away anyway
+        if stop.load(Ordering::Relaxed) {
+            return 0;
+        }
+
+        thread.nodes += 1;
+
+        if thread.nodes.is_multiple_of(2048)
+            && thread.deadline.is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            stop.store(true, Ordering::Relaxed);
+            return 0;
+        }
+
+        if depth == 0 || ply >= MAX_PLY {
+            return thread.board.evaluate(&mut thread.pawn_table);
+        }
+
+        let original_alpha = alpha;
+        let key = thread.board.get_zobrist_hash();
+
+        if let Some(entry) = tt.retrieve(key, ply)
+            && entry.depth >= depth
+        {
+            match entry.bound {
+                TTBound::Exact => return entry.score,
+                TTBound::Lower => alpha = if alpha > entry.score { alpha } else { entry.score },
+                TTBound::Upper => {
+                    if entry.score <= alpha {
+                        return entry.score;
+                    }
+                }
+            }
+
+            if alpha >= beta {
+                return entry.score;
+            }
+        }
+
+        // Tablebase results are exact, the 50-move rule makes cursed wins draws. Only probe
+        // right after a capture or pawn move, the WDL tables assume a fresh 50-move counter.
+        if let Some(tablebases) = &thread.tablebases
+            && thread.board.get_halfmove_clock() == 0
+            && let Some(wdl) = tablebases.probe_wdl(&mut thread.board)
+        {
+            thread.tb_hits += 1;
+
+            let score = match wdl {
+                Wdl::Win  => TB_WIN - ply as i16,
+                Wdl::Loss => -TB_WIN + ply as i16,
+                _         => DRAW,
+            };
+
+            tt.store(key, depth, TTBound::Exact, score, ply);
+            return score;
+        }
+
+        let mut best_score = -INFINITY;
+        let mut legal_moves = 0;
+
+        let mut moves = MoveGen::get_pseudolegal_moves(&thread.board);
+        thread.order_moves(&mut moves, ply, None);
+
+        for mv in moves {
+            thread.board.make_move(&mv);
+
+            if thread.board.was_illegal_move() {
+                thread.board.revert_state();
+                continue;
+            }
+
+            legal_moves += 1;
+
+            let score = -Self::_alphabeta(thread, tt, stop, depth - 1, ply + 1, -beta, -alpha);
+
+            thread.board.revert_state();
+
+            if score > best_score {
+                best_score = score;
+                if score > alpha {
+                    alpha = score;
+                }
+            }
+
+            if score >= beta {
+                if SearchThread::is_quiet(&mv) {
+                    thread.update_quiet_cutoff(&mv, ply, depth);
+                }
+                break;
+            }
+        }
+
+        if stop.load(Ordering::Relaxed) {
+            return 0;
+        }
+
+        // Checkmate (the sooner the better) or stalemate
+        if legal_moves == 0 {
+            return if thread.board.in_check() { -MATE + ply as i16 } else { DRAW };
+        }
+
+        let bound = if best_score <= original_alpha {
+            TTBound::Upper
+        } else if best_score >= beta {
+            TTBound::Lower
+        } else {
+            TTBound::Exact
+        };
+
+        tt.store(key, depth, bound, best_score, ply);
+
+        best_score
+    }
+
+    fn search_root(
+        thread: &mut SearchThread,
+        tt: &TranspositionTable,
+        stop: &AtomicBool,
+        depth: u8,
+        previous_best: Option<Move>
+    ) -> SearchResult {
+        let mut alpha = -INFINITY;
+        let mut best_move: Option<Move> = None;
+
+        let mut moves = MoveGen::get_pseudolegal_moves(&thread.board);
+        thread.order_moves(&mut moves, 0, previous_best);
+
+        for mv in moves {
+            thread.board.make_move(&mv);
+
+            if thread.board.was_illegal_move() {
+                thread.board.revert_state();
+                continue;
+            }
+
+            let score = -Self::_alphabeta(thread, tt, stop, depth - 1, 1, -INFINITY, -alpha);
+
+            thread.board.revert_state();
+
+            if best_move.is_none() || score > alpha {
+                alpha = score;
+                best_move = Some(mv);
+            }
+        }
+
+        SearchResult { best_move, score: alpha, depth, nodes: 0 }
+    }
+
+    /// Search depth 1, 2, ... up to `max_depth`, keeping the last fully searched result.
+    /// Helper threads (`id > 0`) skip every other depth so they don't all search the same tree.
+    fn iterative_deepening(
+        thread: &mut SearchThread,
+        tt: &TranspositionTable,
+        stop: &AtomicBool,
+        id: usize,
+        max_depth: u8,
+        start: &Instant,
+        info: Option<&InfoCallback>
+    ) -> SearchResult {
+        let mut result = SearchResult { best_move: None, score: 0, depth: 0, nodes: 0 };
+
+        for depth in 1..=max_depth {
+            if id > 0 && (depth as usize + id).is_multiple_of(2) {
+                continue;
+            }
+
+            let iteration = Self::search_root(thread, tt, stop, depth, result.best_move);
+            if stop.load(Ordering::Relaxed) {
+                break;
+            }
+
+            result = iteration;
+
+            if let Some(info) = info {
+                info(&SearchInfo {
+                    depth,
+                    score: result.score,
+                    nodes: thread.nodes,
+                    time: start.elapsed(),
+                    hashfull: tt.hashfull(),
+                    tb_hits: thread.tb_hits,
+                    best_move: result.best_move,
+                });
+            }
+        }
+
+        result
+    }
+
+    /// Lazy SMP: every thread searches its own copy of the board, sharing only the
+    /// transposition table. The main thread stops the helpers once it reaches the depth
+    /// limit or runs out of time, and the deepest completed result wins.
+    pub fn lazy_smp(board: &Board, tt: &TranspositionTable, limits: SearchLimits, options: &SearchOptions) -> SearchResult {
+        if let Some(result) = Self::tablebase_root(board, tt, options) {
+            return result;
+        }
+
+        let stop = AtomicBool::new(false);
+        let start = Instant::now();
+        let depth = limits.depth;
+
+        let (mut best, nodes, tb_hits) = thread::scope(|scope| {
+            let stop = &stop;
+            let start = &start;
+
+            let helpers: Vec<_> = (1..options.threads)
+                .map(|id| {
+                    let board = board.clone();
+                    scope.spawn(move || {
+                        let mut thread = SearchThread::new(board, options);
+                        let result = Self::iterative_deepening(&mut thread, tt, stop, id, depth.saturating_add(1), start, None);
+                        (result, thread.nodes, thread.tb_hits)
+                    })
+                })
+                .collect();
+
+            // Only the main thread watches the clock
+            let mut main = SearchThread::new(board.clone(), options);
+            main.deadline = limits.movetime.map(|movetime| *start + movetime);
+            let mut best = Self::iterative_deepening(&mut main, tt, stop, 0, depth, start, options.info.as_ref());
+            let mut nodes = main.nodes;
+            let mut tb_hits = main.tb_hits;
+
+            stop.store(true, Ordering::Relaxed);
+
+            for helper in helpers {
+                let (result, helper_nodes, helper_tb_hits) = helper.join().unwrap();
+                nodes += helper_nodes;
+                tb_hits += helper_tb_hits;
+
+                if result.best_move.is_some()
+                    && (result.depth > best.depth || (result.depth == best.depth && result.score > best.score))
+                {
+                    best = result;
+                }
+            }
+
+            (best, nodes, tb_hits)
+        });
+
+        if let Some(info) = &options.info {
+            info(&SearchInfo {
+                depth: best.depth,
+                score: best.score,
+                nodes,
+                time: start.elapsed(),
+                hashfull: tt.hashfull(),
+                tb_hits,
+                best_move: best.best_move,
+            });
+        }
+
+        best.nodes = nodes;
+        best
+    }
+
+    /// Play the move that keeps the tablebase result with the best DTZ, if the root is in the tablebases
+    fn tablebase_root(board: &Board, tt: &TranspositionTable, options: &SearchOptions) -> Option<SearchResult> {
+        let tablebases = options.tablebases.as_ref()?;
+        let (mv, wdl, dtz) = tablebases.probe_root(&mut board.clone())?;
+
+        let score = match wdl {
+            Wdl::Win  => TB_WIN - dtz.min(MAX_PLY as i32) as i16,
+            Wdl::Loss => -TB_WIN - dtz.max(-(MAX_PLY as i32)) as i16,
+            _         => DRAW,
+        };
+
+        if let Some(info) = &options.info {
+            info(&SearchInfo {
+                depth: 1,
+                score,
+                nodes: 0,
+                time: Duration::ZERO,
+                hashfull: tt.hashfull(),
+                tb_hits: 1,
+                best_move: Some(mv),
+            });
+        }
+
+        Some(SearchResult { best_move: Some(mv), score, depth: 1, nodes: 0 })
+    }
+}
@@ -1,8 +1,17 @@
-use crate::board::{Board, Move};
+//! Alpha-beta search with lazy SMP and the transposition table
+
+use crate::board::{Board, Move, MoveType, PieceType};
+use crate::eval::{Network, PawnTable};
 use crate::movegen::MoveGen;
+use crate::syzygy::{Tablebases, Wdl};
 
 mod transposition_table;
-mod search;
+mod thread;
+mod score;
+mod alphabeta;
 
-pub use transposition_table::{TTBound, TranspositionTable};
-pub use search::Search;
+pub use transposition_table::{TranspositionTable, TTFileError};
+pub(crate) use transposition_table::TTBound;
+pub(crate) use thread::{SearchThread, MAX_PLY};
+pub(crate) use score::clamp_eval;
+pub use alphabeta::{InfoCallback, Search, SearchInfo, SearchLimits, SearchOptions, SearchResult};
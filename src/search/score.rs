@@ -0,0 +1,100 @@
+use super::MAX_PLY;
+
+/*
+ * Search scores
+ *
+ * Mate scores are stored as `MATE - ply` from the root (a mate found at a
+ * deeper ply is worth less), everything in `MATE_IN_MAX_PLY..=MATE` is a
+ * mate. Tablebase wins sit just below mates the same way, and the
+ * evaluation is clamped to stay below both.
+ */
+
+pub const INFINITY: i16 = 32001;
+pub const MATE: i16 = 32000;
+pub const MATE_IN_MAX_PLY: i16 = MATE - MAX_PLY as i16;
+pub const TB_WIN: i16 = MATE_IN_MAX_PLY - 1;
+pub const TB_WIN_IN_MAX_PLY: i16 = TB_WIN - MAX_PLY as i16;
+pub const DRAW: i16 = 0;
+
+/// Keep a static evaluation out of the mate and tablebase ranges
+#[inline(always)]
+pub fn clamp_eval(score: i16) -> i16 {
+    score.clamp(-TB_WIN_IN_MAX_PLY + 1, TB_WIN_IN_MAX_PLY - 1)
+}
+
+/// Convert a root-relative mate (or tablebase) score into "distance from this node" before storing it
+#[inline(always)]
+pub const fn score_to_tt(score: i16, ply: usize) -> i16 {
+    if score >= TB_WIN_IN_MAX_PLY {
+        score + ply as i16
+    } else if score <= -TB_WIN_IN_MAX_PLY {
+        score - ply as i16
+    } else {
+        score
+    }
+}
+
+/// Convert a stored node-relative mate score back to root-relative at the current ply
+#[inline(always)]
+pub const fn score_from_tt(score: i16, ply: usize) -> i16 {
+    if score >= TB_WIN_IN_MAX_PLY {
+        score - ply as i16
+    } else if score <= -TB_WIN_IN_MAX_PLY {
+        score + ply as i16
+    } else {
+        score
+    }
+}
+
+/// Centipawns a tablebase win is reported as, minus its distance in plies
+pub const TB_WIN_CP: i16 = 20000;
+
+/// UCI `score` field: `cp <centipawns>` or `mate <moves>` (negative when getting mated).
+/// Tablebase wins have no mate distance, they are reported as `cp` just under [`TB_WIN_CP`]
+pub fn to_uci(score: i16) -> String {
+    if score >= MATE_IN_MAX_PLY {
+        format!("mate {}", (MATE - score + 1) / 2)
+    } else if score <= -MATE_IN_MAX_PLY {
+        format!("mate -{}", (MATE + score) / 2)
+    } else if score >= TB_WIN_IN_MAX_PLY {
+        format!("cp {}", TB_WIN_CP - (TB_WIN - score))
+    } else if score <= -TB_WIN_IN_MAX_PLY {
+        format!("cp {}", -TB_WIN_CP + (TB_WIN + score))
+    } else {
+        format!("cp {score}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uci_scores() {
+        assert_eq!(to_uci(35), "cp 35");
+        assert_eq!(to_uci(MATE - 1), "mate 1");
+        assert_eq!(to_uci(-MATE + 2), "mate -1");
+        assert_eq!(to_uci(TB_WIN - 3), "cp 19997");
+        assert_eq!(to_uci(-TB_WIN + 3), "cp -19997");
+    }
+
+    #[test]
+    fn tt_scores() {
+        for ply in [0, 1, 17, MAX_PLY - 1] {
+            for score in [0, 250, -250, MATE - 40, -MATE + 40, TB_WIN - 30, -TB_WIN + 30] {
+                assert_eq!(score_from_tt(score_to_tt(score, ply), ply), score, "{score} at ply {ply}");
+            }
+        }
+
+        // Mates and tablebase results are stored as distances from the node
+        assert_eq!(score_to_tt(MATE - 10, 4), MATE - 6);
+        assert_eq!(score_to_tt(-MATE + 10, 4), -MATE + 6);
+        assert_eq!(score_to_tt(TB_WIN - 10, 4), TB_WIN - 6);
+        assert_eq!(score_from_tt(MATE - 6, 8), MATE - 14);
+        assert_eq!(score_from_tt(-TB_WIN + 6, 8), -TB_WIN + 14);
+
+        // Everything else is stored as is
+        assert_eq!(score_to_tt(TB_WIN_IN_MAX_PLY - 1, 30), TB_WIN_IN_MAX_PLY - 1);
+        assert_eq!(score_from_tt(-300, 30), -300);
+    }
+}
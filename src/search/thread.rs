@@ -0,0 +1,91 @@
+use std::sync::Arc;
+use std::time::Instant;
+
+use super::{Board, Move, MoveType, PawnTable, PieceType, SearchOptions, Tablebases};
+
+pub const MAX_PLY: usize = 128;
+
+/// Piece values for MVV-LVA capture ordering
+const ORDER_VALUE: [i32; 6] = [1, 3, 3, 5, 9, 20];
+
+const CAPTURE_SCORE: i32   = 1_000_000;
+const PROMOTION_SCORE: i32 = 900_000;
+const KILLER_SCORE: i32    = 800_000;
+
+/// State owned by one search thread: its own board, pawn cache and move-ordering tables
+pub struct SearchThread {
+    pub board: Board,
+    pub pawn_table: PawnTable,
+    pub nodes: u64,
+    pub tb_hits: u64,
+    pub tablebases: Option<Arc<Tablebases>>,
+    pub deadline: Option<Instant>,
+    killers: [[Option<Move>; 2]; MAX_PLY],
+    history: [[[i32; 64]; 64]; 2], // [color][from][to]
+}
+
+impl SearchThread {
+    /// A thread searching `board` with the evaluation and tablebases of `options`
+    pub fn new(mut board: Board, options: &SearchOptions) -> Self {
+        board.set_network(options.network.clone());
+
+        Self {
+            board,
+            pawn_table: PawnTable::new(1),
+            nodes: 0,
+            tb_hits: 0,
+            tablebases: options.tablebases.clone(),
+            deadline: None,
+            killers: [[None; 2]; MAX_PLY],
+            history: [[[0; 64]; 64]; 2],
+        }
+    }
+
+    #[inline(always)]
+    pub const fn is_quiet(mv: &Move) -> bool {
+        matches!(mv.move_type, MoveType::Quiet | MoveType::CastleKingside | MoveType::CastleQueenside)
+    }
+
+    /// Sort moves best-first: hash / previous best move, captures (MVV-LVA), promotions, killers, history
+    pub fn order_moves(&self, moves: &mut [Move], ply: usize, best_move: Option<Move>) {
+        let color = self.board.color_to_move().index();
+
+        moves.sort_by_cached_key(|mv| {
+            if Some(*mv) == best_move {
+                return i32::MIN;
+            }
+
+            let score = match mv.move_type {
+                MoveType::Capture | MoveType::EnPassant => {
+                    let victim = match self.board.get_piece(mv.to) {
+                        Some(piece) => piece.piece_type,
+                        None        => PieceType::Pawn,
+                    };
+                    let attacker = self.board.get_piece(mv.from).unwrap().piece_type;
+                    CAPTURE_SCORE + ORDER_VALUE[victim as usize] * 10 - ORDER_VALUE[attacker as usize]
+                }
+
+                MoveType::Promotion(piece_type) => PROMOTION_SCORE + ORDER_VALUE[piece_type as usize],
+
+                _ if self.killers[ply][0] == Some(*mv) => KILLER_SCORE,
+                _ if self.killers[ply][1] == Some(*mv) => KILLER_SCORE - 1,
+
+                _ => self.history[color][mv.from as usize][mv.to as usize],
+            };
+
+            -score
+        });
+    }
+
+    /// Remember a quiet move that caused a beta cutoff
+    pub fn update_quiet_cutoff(&mut self, mv: &Move, ply: usize, depth: u8) {
+        if self.killers[ply][0] != Some(*mv) {
+            self.killers[ply][1] = self.killers[ply][0];
+            self.killers[ply][0] = Some(*mv);
+        }
+
+        let color = self.board.color_to_move().index();
+        let entry = &mut self.history[color][mv.from as usize][mv.to as usize];
+        *entry = (*entry + depth as i32 * depth as i32).min(KILLER_SCORE / 2);
+    }
+}
@@ -1,6 +1,12 @@
-use crate::board::Move;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicU8, AtomicU64, Ordering};
 
-#[derive(Clone, Copy)]
+use crate::board::Zobrist;
+use super::score::{score_from_tt, score_to_tt};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum TTBound {
     Exact, // All positions searched, this is the score
     Upper, // The move never improved alpha
@@ -9,63 +15,479 @@ pub enum TTBound {
 
 #[derive(Clone, Copy)]
 pub struct TTEntry {
-    pub key: u64,   // Zobrist key
-    pub depth: u8,  // Search depth
+    pub key: u16,        // Low 16 bits of the Zobrist key (the index comes from the high bits)
+    pub score: i16,      // Mate scores are relative to this node, not the root
+    pub depth: u8,       // Search depth, 0 marks an empty slot
+    pub generation: u8,  // Search the entry was written in
     pub bound: TTBound,
-    pub score: i16,
     // pub best_move: Option<Move>,
 }
 
+/*
+ * Entries are packed into a single u64 so they can be read and written with
+ * one atomic operation. Threads never lock the table: a racing write can
+ * replace an entry, but never leave half of one behind.
+ *
+ *   bits  0..16  key
+ *   bits 16..32  score
+ *   bits 32..40  depth
+ *   bits 40..48  generation
+ *   bits 48..50  bound
+ */
+impl TTEntry {
+    #[inline(always)]
+    const fn pack(&self) -> u64 {
+        let bound = match self.bound {
+            TTBound::Exact => 0,
+            TTBound::Upper => 1,
+            TTBound::Lower => 2,
+        };
+
+        self.key as u64
+            | (self.score as u16 as u64) << 16
+            | (self.depth as u64) << 32
+            | (self.generation as u64) << 40
+            | bound << 48
+    }
+
+    #[inline(always)]
+    const fn unpack(data: u64) -> Self {
+        let bound = match (data >> 48) & 0b11 {
+            0 => TTBound::Exact,
+            1 => TTBound::Upper,
+            _ => TTBound::Lower,
+        };
+
+        Self {
+            key: data as u16,
+            score: (data >> 16) as u16 as i16,
+            depth: (data >> 32) as u8,
+            generation: (data >> 40) as u8,
+            bound,
+        }
+    }
+}
+
+pub const CLUSTER_SIZE: usize = 4;
+
+/// A bucket of entries sharing one index, sized to fit in half a cache line
+#[repr(align(32))]
+struct Cluster {
+    entries: [AtomicU64; CLUSTER_SIZE],
+}
+
+impl Cluster {
+    fn new() -> Self {
+        Self { entries: std::array::from_fn(|_| AtomicU64::new(0)) }
+    }
+}
+
+/*
+ * Saved table format (all values little-endian)
+ *
+ *   offset  size            field
+ *   0       8               magic: b"EPSTT\0\0\0"
+ *   8       4               version: u32 (currently 1)
+ *   12      8               Zobrist seed: u64 (must match Zobrist::SEED)
+ *   20      8               cluster count: u64 (must match the current table)
+ *   28      1               generation: u8
+ *   29      8 * 4 * count   packed entries, cluster by cluster
+ */
+
+pub const MAGIC: &[u8; 8] = b"EPSTT\0\0\0";
+pub const VERSION: u32 = 1;
+
+/// Why a transposition table could not be saved or loaded
+#[derive(Debug)]
+pub enum TTFileError {
+    /// The file could not be read or written
+    Io(std::io::Error),
+    /// Not a transposition table file
+    BadMagic,
+    /// A version of the format this build can't read
+    UnsupportedVersion(u32),
+    /// Saved with other Zobrist keys, see `Zobrist::SEED`
+    SeedMismatch(u64),
+    /// Saved from a table of another size
+    SizeMismatch {
+        /// Clusters in this table
+        expected: usize,
+        /// Clusters in the file
+        found: u64,
+    },
+}
+
+impl std::fmt::Display for TTFileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Io(err)               => write!(f, "{err}"),
+            Self::BadMagic              => write!(f, "not an Epsilon transposition table file"),
+            Self::UnsupportedVersion(v) => write!(f, "unsupported table version {v}"),
+            Self::SeedMismatch(seed)    => write!(f, "Zobrist seed {seed:#x} does not match {:#x}", Zobrist::SEED),
+            Self::SizeMismatch { expected, found } => {
+                write!(f, "table has {found} clusters, expected {expected}")
+            }
+        }
+    }
+}
+
+impl From<std::io::Error> for TTFileError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// Shared between search threads by reference, every method takes `&self`
 pub struct TranspositionTable {
-    table: Vec<Option<TTEntry>>,
-    size: usize,
+    table: Vec<Cluster>,
+    generation: AtomicU8,
 }
 
 impl TranspositionTable {
+    /// A table taking about `size_mb` megabytes
     pub fn new(size_mb: usize) -> Self {
-        let entry_size = std::mem::size_of::<TTEntry>();
-        let size = (size_mb * 1_000_000) / entry_size;
+        let cluster_size = std::mem::size_of::<Cluster>();
+        let size = ((size_mb * 1_000_000) / cluster_size).max(1);
         Self {
-            table: vec![None; size],
-            size
+            table: (0..size).map(|_| Cluster::new()).collect(),
+            generation: AtomicU8::new(0),
         }
     }
 
-    pub fn store(&mut self, key: u64, depth: u8, bound: TTBound, score: i16) {
-        let index = (key as usize) % self.size;
+    /// Map the key onto the table with a multiply-shift (works for any table size)
+    #[inline(always)]
+    fn index(&self, key: u64) -> usize {
+        ((key as u128 * self.table.len() as u128) >> 64) as usize
+    }
+
+    #[inline(always)]
+    fn generation(&self) -> u8 {
+        self.generation.load(Ordering::Relaxed)
+    }
 
-        let new_entry = Some(TTEntry {
-            key,
+    /// Number of searches since this entry was written
+    #[inline(always)]
+    fn age(&self, entry: &TTEntry) -> u8 {
+        self.generation().wrapping_sub(entry.generation)
+    }
+
+    /// Empty every slot, as if newly allocated
+    pub fn clear(&self) {
+        for cluster in &self.table {
+            for entry in &cluster.entries {
+                entry.store(0, Ordering::Relaxed);
+            }
+        }
+        self.generation.store(0, Ordering::Relaxed);
+    }
+
+    /// Call before every new search so entries from previous moves get replaced first
+    pub fn new_search(&self) {
+        self.generation.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn store(&self, key: u64, depth: u8, bound: TTBound, score: i16, ply: usize) {
+        let cluster = &self.table[self.index(key)];
+        let key16 = key as u16;
+
+        let new_entry = TTEntry {
+            key: key16,
+            score: score_to_tt(score, ply),
             depth,
+            generation: self.generation(),
             bound,
-            score,
             // best_move
-        });
+        };
 
-        match &self.table[index] {
-            Some(entry) => {
-                if depth > entry.depth {
-                    self.table[index] = new_entry;
-                }
+        // Reuse the slot of this position if it's already in the cluster,
+        // otherwise evict the shallowest, oldest entry
+        let mut replace = 0;
+        let mut replace_value = i32::MAX;
+        let mut old = TTEntry::unpack(0);
+        for (i, slot) in cluster.entries.iter().enumerate() {
+            let entry = TTEntry::unpack(slot.load(Ordering::Relaxed));
+
+            if entry.depth == 0 || entry.key == key16 {
+                replace = i;
+                old = entry;
+                break;
             }
 
-            None => self.table[index] = new_entry,
+            let value = entry.depth as i32 - 8 * self.age(&entry) as i32;
+            if value < replace_value {
+                replace = i;
+                replace_value = value;
+                old = entry;
+            }
         }
+
+        // Don't overwrite a deeper result for the same position from this search
+        if old.depth != 0
+            && old.key == key16
+            && bound != TTBound::Exact
+            && self.age(&old) == 0
+            && depth + 2 < old.depth
+        {
+            return;
+        }
+
+        cluster.entries[replace].store(new_entry.pack(), Ordering::Relaxed);
     }
 
+    /// Look up a position, with mate scores converted back to be relative to the root
     #[inline(always)]
-    pub fn retrieve(&self, key: u64) -> Option<TTEntry> {
-        match self.table[(key as usize) % self.size] {
-            // TODO: See if this is wrong (no if)
-            Some(e) if e.key == key => Some(e),
-            _ => None,
+    pub(crate) fn retrieve(&self, key: u64, ply: usize) -> Option<TTEntry> {
+        let key16 = key as u16;
+        self.table[self.index(key)]
+            .entries
+            .iter()
+            .map(|slot| TTEntry::unpack(slot.load(Ordering::Relaxed)))
+            .find(|e| e.depth != 0 && e.key == key16)
+            .map(|e| TTEntry { score: score_from_tt(e.score, ply), ..e })
+    }
+
+    /// Permille of sampled entries written during the current search
+    pub fn hashfull(&self) -> usize {
+        let generation = self.generation();
+        let sample = self.table.len().min(1000);
+        let used = self.table[..sample]
+            .iter()
+            .flat_map(|cluster| cluster.entries.iter())
+            .map(|slot| TTEntry::unpack(slot.load(Ordering::Relaxed)))
+            .filter(|e| e.depth != 0 && e.generation == generation)
+            .count();
+
+        used * 1000 / (sample * CLUSTER_SIZE)
+    }
+
+    /// Dump the table to disk, must not be called while a search is running
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), TTFileError> {
+        let mut writer = BufWriter::new(File::create(path)?);
+
+        writer.write_all(MAGIC)?;
+        writer.write_all(&VERSION.to_le_bytes())?;
+        writer.write_all(&Zobrist::SEED.to_le_bytes())?;
+        writer.write_all(&(self.table.len() as u64).to_le_bytes())?;
+        writer.write_all(&[self.generation()])?;
+
+        for slot in self.table.iter().flat_map(|cluster| cluster.entries.iter()) {
+            writer.write_all(&slot.load(Ordering::Relaxed).to_le_bytes())?;
         }
+
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Replace the contents of the table with a saved one of the same size.
+    /// The table is left untouched if the header doesn't match.
+    pub fn load(&self, path: impl AsRef<Path>) -> Result<(), TTFileError> {
+        let mut reader = BufReader::new(File::open(path)?);
+
+        let mut magic = [0; 8];
+        reader.read_exact(&mut magic).map_err(|_| TTFileError::BadMagic)?;
+        if &magic != MAGIC {
+            return Err(TTFileError::BadMagic);
+        }
+
+        let mut buf = [0; 8];
+
+        reader.read_exact(&mut buf[..4])?;
+        let version = u32::from_le_bytes(buf[..4].try_into().unwrap());
+        if version != VERSION {
+            return Err(TTFileError::UnsupportedVersion(version));
+        }
+
+        reader.read_exact(&mut buf)?;
+        let seed = u64::from_le_bytes(buf);
+        if seed != Zobrist::SEED {
+            return Err(TTFileError::SeedMismatch(seed));
+        }
+
+        reader.read_exact(&mut buf)?;
+        let clusters = u64::from_le_bytes(buf);
+        if clusters != self.table.len() as u64 {
+            return Err(TTFileError::SizeMismatch { expected: self.table.len(), found: clusters });
+        }
+
+        let mut generation = [0; 1];
+        reader.read_exact(&mut generation)?;
+
+        // Read everything first so a truncated file doesn't leave a half-loaded table
+        let mut entries = vec![0; self.table.len() * CLUSTER_SIZE * 8];
+        reader.read_exact(&mut entries)?;
+
+        let slots = self.table.iter().flat_map(|cluster| cluster.entries.iter());
+        for (slot, bytes) in slots.zip(entries.chunks_exact(8)) {
+            slot.store(u64::from_le_bytes(bytes.try_into().unwrap()), Ordering::Relaxed);
+        }
+        self.generation.store(generation[0], Ordering::Relaxed);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::score::MATE;
+
+    // A single cluster, so every key competes for the same slots
+    fn one_cluster() -> TranspositionTable {
+        let tt = TranspositionTable::new(0);
+        assert_eq!(tt.table.len(), 1);
+        tt
     }
 
-    // #[inline(always)]
-    // pub fn clear(&mut self) {
-    //     for i in 0..self.table.len() {
-    //         self.table[i] = None;
-    //     }
-    // }
+    #[test]
+    fn pack_round_trip() {
+        for score in [0, 1, -1, 1234, -1234, MATE - 3, -MATE + 3, i16::MAX, i16::MIN] {
+            for bound in [TTBound::Exact, TTBound::Upper, TTBound::Lower] {
+                let entry = TTEntry { key: 0xBEEF, score, depth: 255, generation: 200, bound };
+                let unpacked = TTEntry::unpack(entry.pack());
+
+                assert_eq!(unpacked.key, entry.key);
+                assert_eq!(unpacked.score, score);
+                assert_eq!(unpacked.depth, 255);
+                assert_eq!(unpacked.generation, 200);
+                assert_eq!(unpacked.bound, bound);
+            }
+        }
+    }
+
+    #[test]
+    fn store_and_retrieve() {
+        let tt = TranspositionTable::new(1);
+        let key = 0x0123_4567_89AB_CDEF;
+
+        assert!(tt.retrieve(key, 0).is_none());
+
+        tt.store(key, 7, TTBound::Lower, -150, 3);
+        let entry = tt.retrieve(key, 5).unwrap();
+        assert_eq!((entry.depth, entry.bound, entry.score), (7, TTBound::Lower, -150));
+
+        // Same index, other key
+        assert!(tt.retrieve(key ^ 1, 0).is_none());
+
+        // A mate in 2 from a node at ply 3 is a mate in 4 seen from ply 5
+        tt.store(key, 7, TTBound::Exact, MATE - 5, 3);
+        assert_eq!(tt.retrieve(key, 5).unwrap().score, MATE - 7);
+        tt.store(key, 7, TTBound::Exact, -MATE + 5, 3);
+        assert_eq!(tt.retrieve(key, 5).unwrap().score, -MATE + 7);
+
+        tt.clear();
+        assert!(tt.retrieve(key, 0).is_none());
+    }
+
+    #[test]
+    fn keeps_deeper_entry_of_same_search() {
+        let tt = one_cluster();
+
+        tt.store(1, 10, TTBound::Lower, 50, 0);
+        tt.store(1, 3, TTBound::Upper, 20, 0);
+        assert_eq!(tt.retrieve(1, 0).unwrap().depth, 10);
+
+        // Exact scores always replace
+        tt.store(1, 3, TTBound::Exact, 20, 0);
+        assert_eq!(tt.retrieve(1, 0).unwrap().depth, 3);
+
+        // So does anything from a later search
+        tt.store(1, 10, TTBound::Lower, 50, 0);
+        tt.new_search();
+        tt.store(1, 3, TTBound::Upper, 20, 0);
+        assert_eq!(tt.retrieve(1, 0).unwrap().depth, 3);
+    }
+
+    #[test]
+    fn evicts_older_generations_first() {
+        let tt = one_cluster();
+
+        // Deeper, but from the previous search
+        tt.store(1, 10, TTBound::Exact, 0, 0);
+        tt.store(2, 10, TTBound::Exact, 0, 0);
+        tt.new_search();
+        tt.store(3, 5, TTBound::Exact, 0, 0);
+        tt.store(4, 5, TTBound::Exact, 0, 0);
+
+        tt.store(5, 6, TTBound::Exact, 0, 0);
+        assert!(tt.retrieve(1, 0).is_none());
+        tt.store(6, 6, TTBound::Exact, 0, 0);
+        assert!(tt.retrieve(2, 0).is_none());
+
+        for key in 3..=6 {
+            assert!(tt.retrieve(key, 0).is_some(), "{key}");
+        }
+    }
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("epsilon-tt-{}-{name}", std::process::id()))
+    }
+
+    /// Every packed slot and the generation
+    fn contents(tt: &TranspositionTable) -> (Vec<u64>, u8) {
+        let slots = tt.table.iter().flat_map(|cluster| cluster.entries.iter());
+        (slots.map(|slot| slot.load(Ordering::Relaxed)).collect(), tt.generation())
+    }
+
+    #[test]
+    fn save_and_load() {
+        let tt = TranspositionTable::new(1);
+        for key in 0..1000u64 {
+            tt.store(key.wrapping_mul(0x9E37_79B9_7F4A_7C15), (key % 20) as u8 + 1, TTBound::Lower, key as i16, 0);
+        }
+        tt.new_search();
+        tt.store(42, 30, TTBound::Exact, MATE - 5, 2);
+
+        let path = temp_path("round-trip");
+        tt.save(&path).unwrap();
+        let loaded = TranspositionTable::new(1);
+        loaded.load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(contents(&loaded) == contents(&tt));
+        assert_eq!(loaded.generation(), 1);
+        assert_eq!(loaded.retrieve(42, 2).unwrap().score, MATE - 5);
+    }
+
+    #[test]
+    fn load_rejects_bad_files() {
+        let saved = TranspositionTable::new(1);
+        saved.store(7, 5, TTBound::Exact, 100, 0);
+        let path = temp_path("bad");
+        saved.save(&path).unwrap();
+        let good = std::fs::read(&path).unwrap();
+
+        let tt = TranspositionTable::new(1);
+        tt.store(8, 3, TTBound::Upper, -20, 0);
+        tt.new_search();
+        let before = contents(&tt);
+
+        let load = |bytes: &[u8], tt: &TranspositionTable| {
+            std::fs::write(&path, bytes).unwrap();
+            tt.load(&path)
+        };
+        let with = |offset: usize, bytes: &[u8]| {
+            let mut file = good.clone();
+            file[offset..offset + bytes.len()].copy_from_slice(bytes);
+            file
+        };
+
+        assert!(matches!(load(&with(0, b"EPSNNUE\0"), &tt), Err(TTFileError::BadMagic)));
+        assert!(matches!(load(&good[..4], &tt), Err(TTFileError::BadMagic)));
+        assert!(matches!(load(&with(8, &2u32.to_le_bytes()), &tt), Err(TTFileError::UnsupportedVersion(2))));
+        assert!(matches!(
+            load(&with(12, &(Zobrist::SEED ^ 1).to_le_bytes()), &tt),
+            Err(TTFileError::SeedMismatch(seed)) if seed == Zobrist::SEED ^ 1
+        ));
+        assert!(matches!(load(&good[..good.len() - 1], &tt), Err(TTFileError::Io(_))));
+        assert!(contents(&tt) == before);
+
+        let other_size = TranspositionTable::new(2);
+        assert!(matches!(
+            load(&good, &other_size),
+            Err(TTFileError::SizeMismatch { expected, found }) if expected == other_size.table.len() && found == tt.table.len() as u64
+        ));
+        assert!(contents(&other_size).0.iter().all(|&slot| slot == 0));
+
+        std::fs::remove_file(&path).unwrap();
+    }
 }
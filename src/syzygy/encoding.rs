@@ -0,0 +1,186 @@
+/*
+ * Lookup tables used to turn piece placements into a table index.
+ *
+ * Positions without pawns are mirrored so the leading piece lands in the
+ * a1-d1-d4 triangle, positions with pawns so the leading pawn is on files a-d.
+ */
+
+/// Rank minus file: 0 on the a1-h8 diagonal, negative below it
+pub const fn off_a1h8(square: usize) -> i32 {
+    (square / 8) as i32 - (square % 8) as i32
+}
+
+/// Squares below the a1-h8 diagonal (b1-h1-h7 triangle) to 0..28
+pub const MAP_B1H1H7: [usize; 64] = {
+    let mut table = [0; 64];
+    let mut code = 0;
+
+    let mut square = 0;
+    while square < 64 {
+        if off_a1h8(square) < 0 {
+            table[square] = code;
+            code += 1;
+        }
+        square += 1;
+    }
+
+    table
+};
+
+/// Squares in the a1-d1-d4 triangle to 0..10, with the diagonal squares last
+pub const MAP_A1D1D4: [usize; 64] = {
+    let mut table = [0; 64];
+    let mut code = 0;
+
+    let mut diagonal = [0; 4];
+    let mut diagonal_count = 0;
+
+    let mut square = 0;
+    while square <= 27 {
+        if off_a1h8(square) < 0 && square % 8 <= 3 {
+            table[square] = code;
+            code += 1;
+        } else if off_a1h8(square) == 0 && square % 8 <= 3 {
+            diagonal[diagonal_count] = square;
+            diagonal_count += 1;
+        }
+        square += 1;
+    }
+
+    let mut i = 0;
+    while i < diagonal_count {
+        table[diagonal[i]] = code;
+        code += 1;
+        i += 1;
+    }
+
+    table
+};
+
+const fn kings_touch(a: usize, b: usize) -> bool {
+    let file_distance = (a % 8) as i32 - (b % 8) as i32;
+    let rank_distance = (a / 8) as i32 - (b / 8) as i32;
+    file_distance.abs() <= 1 && rank_distance.abs() <= 1
+}
+
+/// The 462 legal placements of two kings with the first in the a1-d1-d4 triangle,
+/// indexed by [MAP_A1D1D4[first king]][second king]
+pub const MAP_KK: [[usize; 64]; 10] = {
+    let mut table = [[0; 64]; 10];
+    let mut code = 0;
+
+    // Both kings on the diagonal are encoded last
+    let mut both_on_diagonal = [(0, 0); 32];
+    let mut both_count = 0;
+
+    let mut idx = 0;
+    while idx < 10 {
+        let mut s1 = 0;
+        while s1 <= 27 {
+            // b1 is the only square really mapped to 0
+            if MAP_A1D1D4[s1] == idx && (idx != 0 || s1 == 1) {
+                let mut s2 = 0;
+                while s2 < 64 {
+                    if kings_touch(s1, s2) {
+                        // Illegal position
+                    } else if off_a1h8(s1) == 0 && off_a1h8(s2) > 0 {
+                        // First on the diagonal, second above it
+                    } else if off_a1h8(s1) == 0 && off_a1h8(s2) == 0 {
+                        both_on_diagonal[both_count] = (idx, s2);
+                        both_count += 1;
+                    } else {
+                        table[idx][s2] = code;
+                        code += 1;
+                    }
+                    s2 += 1;
+                }
+            }
+            s1 += 1;
+        }
+        idx += 1;
+    }
+
+    let mut i = 0;
+    while i < both_count {
+        let (idx, s2) = both_on_diagonal[i];
+        table[idx][s2] = code;
+        code += 1;
+        i += 1;
+    }
+
+    table
+};
+
+/// BINOMIAL[k][n]: ways to choose k elements out of n
+pub const BINOMIAL: [[u64; 64]; 6] = {
+    let mut table = [[0; 64]; 6];
+    table[0][0] = 1;
+
+    let mut n = 1;
+    while n < 64 {
+        let mut k = 0;
+        while k < 6 && k <= n {
+            let a = if k > 0 { table[k - 1][n - 1] } else { 0 };
+            let b = if k < n { table[k][n - 1] } else { 0 };
+            table[k][n] = a + b;
+            k += 1;
+        }
+        n += 1;
+    }
+
+    table
+};
+
+/// Squares a2-h7 to 0..48, highest for the squares nearest the edge and lowest rank.
+/// The pawn with the highest value is the leading pawn.
+pub const MAP_PAWNS: [usize; 64] = {
+    let mut table = [0; 64];
+    let mut available: usize = 47;
+
+    let mut file = 0;
+    while file < 4 {
+        let mut rank = 1;
+        while rank < 7 {
+            let square = rank * 8 + file;
+            table[square] = available;
+            table[square ^ 7] = available - 1;
+            available = available.saturating_sub(2);
+            rank += 1;
+        }
+        file += 1;
+    }
+
+    table
+};
+
+/// Index of the leading pawn group by [lead pawn count][leading pawn square],
+/// and the number of such indices per file
+const LEAD_PAWNS: ([[u64; 64]; 6], [[u64; 4]; 6]) = {
+    let mut lead_pawn_idx = [[0; 64]; 6];
+    let mut lead_pawns_size = [[0; 4]; 6];
+
+    let mut count = 1;
+    while count <= 5 {
+        let mut file = 0;
+        while file < 4 {
+            let mut idx = 0;
+
+            let mut rank = 1;
+            while rank < 7 {
+                let square = rank * 8 + file;
+                lead_pawn_idx[count][square] = idx;
+                idx += BINOMIAL[count - 1][MAP_PAWNS[square]];
+                rank += 1;
+            }
+
+            lead_pawns_size[count][file] = idx;
+            file += 1;
+        }
+        count += 1;
+    }
+
+    (lead_pawn_idx, lead_pawns_size)
+};
+
+pub const LEAD_PAWN_IDX: [[u64; 64]; 6] = LEAD_PAWNS.0;
+pub const LEAD_PAWNS_SIZE: [[u64; 4]; 6] = LEAD_PAWNS.1;
@@ -0,0 +1,55 @@
+use super::TB_PIECES;
+
+/// What a table's file name says about its pieces, e.g. "KRPvKR"
+pub struct Material {
+    pub signature: String,
+    pub piece_count: usize,
+    pub has_pawns: bool,
+    pub has_unique_pieces: bool, // Some side has exactly one piece of a non-king type
+    pub pawn_count: [usize; 2],  // [leading color, other color]
+    pub symmetric: bool,         // Both sides have the same pieces (KRvKR)
+}
+
+impl Material {
+    pub fn from_signature(signature: &str) -> Option<Self> {
+        let (white, black) = signature.split_once('v')?;
+
+        // [color][K, Q, R, B, N, P]
+        let mut counts = [[0; 6]; 2];
+        for (side, pieces) in [white, black].into_iter().enumerate() {
+            for c in pieces.chars() {
+                let piece = "KQRBNP".find(c)?;
+                counts[side][piece] += 1;
+            }
+
+            if counts[side][0] != 1 {
+                return None;
+            }
+        }
+
+        let piece_count = white.len() + black.len();
+        if piece_count > TB_PIECES {
+            return None;
+        }
+
+        let [white_pawns, black_pawns] = [counts[0][5], counts[1][5]];
+
+        // The leading color is the one with fewer pawns (if both sides have any)
+        let white_leads = black_pawns == 0 || (white_pawns != 0 && black_pawns >= white_pawns);
+
+        Some(Self {
+            signature: signature.to_string(),
+            piece_count,
+            has_pawns: white_pawns + black_pawns > 0,
+            has_unique_pieces: counts.iter().any(|side| side[1..].contains(&1)),
+            pawn_count: if white_leads { [white_pawns, black_pawns] } else { [black_pawns, white_pawns] },
+            symmetric: white == black,
+        })
+    }
+
+    /// The same material seen with the colors swapped, "KRPvKR" -> "KRvKRP"
+    pub fn mirrored_signature(&self) -> String {
+        let (white, black) = self.signature.split_once('v').unwrap();
+        format!("{black}v{white}")
+    }
+}
@@ -0,0 +1,14 @@
+//! Syzygy endgame tablebase probing
+
+use crate::board::{Board, Color, Move, MoveType, PieceType};
+use crate::movegen::MoveGen;
+
+mod encoding;
+mod material;
+mod table;
+mod probe;
+
+pub use probe::{Tablebases, Wdl};
+
+/// Largest tablebases supported by the index encoding
+pub const TB_PIECES: usize = 7;
@@ -0,0 +1,705 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, OnceLock};
+
+use super::material::Material;
+use super::table::{Lookup, Table, TableKind};
+use super::{Board, Color, Move, MoveGen, MoveType, PieceType, TB_PIECES};
+
+/// Game theoretical result for the side to move. Cursed wins and blessed
+/// losses are wins and losses that the 50-move rule turns into draws.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Wdl {
+    /// Lost
+    Loss = -2,
+    /// Lost, but drawn by the 50-move rule
+    BlessedLoss = -1,
+    /// Drawn
+    Draw = 0,
+    /// Won, but drawn by the 50-move rule
+    CursedWin = 1,
+    /// Won
+    Win = 2,
+}
+
+impl Wdl {
+    const fn from_i32(value: i32) -> Self {
+        match value {
+            ..=-2 => Self::Loss,
+            -1    => Self::BlessedLoss,
+            0     => Self::Draw,
+            1     => Self::CursedWin,
+            _     => Self::Win,
+        }
+    }
+
+    /// The same result from the opponent's point of view
+    const fn flip(self) -> Self {
+        Self::from_i32(-(self as i32))
+    }
+
+    /// DTZ of the move just before a zeroing move (capture or pawn move)
+    const fn dtz_before_zeroing(self) -> i32 {
+        match self {
+            Self::Win         => 1,
+            Self::CursedWin   => 101,
+            Self::Draw        => 0,
+            Self::BlessedLoss => -101,
+            Self::Loss        => -1,
+        }
+    }
+}
+
+/// Tables for one material configuration, loaded on first use
+struct Entry {
+    material: Material,
+    wdl_path: PathBuf,
+    dtz_path: Option<PathBuf>,
+    wdl: OnceLock<Option<Table>>,
+    dtz: OnceLock<Option<Table>>,
+}
+
+impl Entry {
+    fn wdl(&self) -> Option<&Table> {
+        self.wdl
+            .get_or_init(|| Table::load(&self.wdl_path, TableKind::Wdl, &self.material))
+            .as_ref()
+    }
+
+    fn dtz(&self) -> Option<&Table> {
+        self.dtz
+            .get_or_init(|| {
+                let path = self.dtz_path.as_ref()?;
+                Table::load(path, TableKind::Dtz, &self.material)
+            })
+            .as_ref()
+    }
+}
+
+/// Syzygy tablebases, probed through a `Board`
+pub struct Tablebases {
+    // Keyed by the board's white-first signature, `true` when that is the
+    // table's material with colors swapped
+    entries: HashMap<String, (Arc<Entry>, bool)>,
+    max_pieces: usize,
+}
+
+impl Tablebases {
+    /// Find the `.rtbw`/`.rtbz` files in a list of directories separated by
+    /// ':' (';' on Windows). Tables are only read when first probed.
+    pub fn open(paths: &str) -> Self {
+        let separator = if cfg!(windows) { ';' } else { ':' };
+
+        let mut files: HashMap<String, (Option<PathBuf>, Option<PathBuf>)> = HashMap::new();
+        for dir in paths.split(separator).filter(|dir| !dir.is_empty()) {
+            let Ok(read_dir) = std::fs::read_dir(dir) else { continue };
+
+            for path in read_dir.flatten().map(|entry| entry.path()) {
+                let (Some(stem), Some(extension)) = (
+                    path.file_stem().and_then(|s| s.to_str()),
+                    path.extension().and_then(|s| s.to_str()),
+                ) else {
+                    continue;
+                };
+
+                let slot = files.entry(stem.to_string()).or_default();
+                match extension {
+                    "rtbw" => slot.0 = Some(path.clone()),
+                    "rtbz" => slot.1 = Some(path.clone()),
+                    _ => {}
+                }
+            }
+        }
+
+        let mut entries = HashMap::new();
+        let mut max_pieces = 0;
+
+        for (signature, (wdl_path, dtz_path)) in files {
+            let (Some(wdl_path), Some(material)) = (wdl_path, Material::from_signature(&signature)) else {
+                continue;
+            };
+
+            max_pieces = max_pieces.max(material.piece_count);
+
+            let mirrored = material.mirrored_signature();
+            let entry = Arc::new(Entry {
+                material,
+                wdl_path,
+                dtz_path,
+                wdl: OnceLock::new(),
+                dtz: OnceLock::new(),
+            });
+
+            if mirrored != signature {
+                entries.insert(mirrored, (entry.clone(), true));
+            }
+            entries.insert(signature, (entry, false));
+        }
+
+        Self { entries, max_pieces }
+    }
+
+    /// Number of material configurations found (counting each table once)
+    pub fn len(&self) -> usize {
+        self.entries.values().filter(|(_, mirrored)| !mirrored).count()
+    }
+
+    /// Whether no table was found
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Pieces, kings included, of the largest table found
+    pub fn max_pieces(&self) -> usize {
+        self.max_pieces
+    }
+
+    /// Whether the position is small enough and has no castling rights
+    pub fn can_probe(&self, board: &Board) -> bool {
+        let castling_rights = board.get_castling_rights();
+        board.get_occupied().all.count_ones() as usize <= self.max_pieces.min(TB_PIECES)
+            && !castling_rights.white_king_side
+            && !castling_rights.white_queen_side
+            && !castling_rights.black_king_side
+            && !castling_rights.black_queen_side
+    }
+
+    fn probe_table(&self, board: &Board, kind: TableKind, wdl: Wdl) -> Option<Lookup> {
+        if board.get_occupied().all.count_ones() == 2 {
+            return Some(Lookup::Value(0)); // KvK
+        }
+
+        let (entry, mirrored) = self.entries.get(&board.material_signature(Color::White))?;
+        let table = match kind {
+            TableKind::Wdl => entry.wdl()?,
+            TableKind::Dtz => entry.dtz()?,
+        };
+
+        Some(table.probe(board, &entry.material, *mirrored, wdl))
+    }
+
+    fn legal_moves(board: &mut Board) -> Vec<Move> {
+        MoveGen::get_pseudolegal_moves(board)
+            .into_iter()
+            .filter(|mv| {
+                board.make_move(mv);
+                let illegal = board.was_illegal_move();
+                board.revert_state();
+                !illegal
+            })
+            .collect()
+    }
+
+    fn is_capture(board: &Board, mv: &Move) -> bool {
+        mv.move_type == MoveType::EnPassant || board.get_piece(mv.to).is_some()
+    }
+
+    fn is_zeroing(board: &Board, mv: &Move) -> bool {
+        Self::is_capture(board, mv)
+            || board.get_piece(mv.from).is_some_and(|piece| piece.piece_type == PieceType::Pawn)
+    }
+
+    /*
+     * Tables store "don't care" values for positions where the side to move
+     * has a winning capture (and draws may be stored as losses if there is a
+     * drawing capture), and know nothing about en passant. So captures are
+     * always searched, and the best of them and the stored value is the
+     * result. With `zeroing_moves`, pawn moves are searched as well, for DTZ.
+     *
+     * Returns the score and whether the best move is a zeroing one, in which
+     * case the DTZ table can't be trusted.
+     */
+    fn search(&self, board: &mut Board, zeroing_moves: bool) -> Option<(Wdl, bool)> {
+        let moves = Self::legal_moves(board);
+
+        let mut best = Wdl::Loss;
+        let mut searched = 0;
+
+        for mv in &moves {
+            let searchable = if zeroing_moves {
+                Self::is_zeroing(board, mv)
+            } else {
+                Self::is_capture(board, mv)
+            };
+            if !searchable {
+                continue;
+            }
+            searched += 1;
+
+            board.make_move(mv);
+            let result = self.search(board, false);
+            board.revert_state();
+
+            let value = result?.0.flip();
+            if value > best {
+                best = value;
+                if value >= Wdl::Win {
+                    return Some((value, true));
+                }
+            }
+        }
+
+        // If every move was searched the stored value isn't needed (and can be
+        // wrong, e.g. with en passant)
+        let no_more_moves = searched > 0 && searched == moves.len();
+
+        let value = if no_more_moves {
+            best
+        } else {
+            match self.probe_table(board, TableKind::Wdl, Wdl::Draw)? {
+                Lookup::Value(value) => Wdl::from_i32(value),
+                Lookup::ChangeStm => return None,
+            }
+        };
+
+        if best >= value {
+            Some((best, best > Wdl::Draw || no_more_moves))
+        } else {
+            Some((value, false))
+        }
+    }
+
+    /// Win/draw/loss for the side to move, `None` if the tables are missing
+    pub fn probe_wdl(&self, board: &mut Board) -> Option<Wdl> {
+        if !self.can_probe(board) {
+            return None;
+        }
+
+        self.search(board, false).map(|(wdl, _)| wdl)
+    }
+
+    /// Distance to zeroing the 50-move counter in plies, positive when winning,
+    /// `None` if the tables are missing:
+    ///
+    /// ```text
+    /// n < -100       : loss, but draw under the 50-move rule
+    /// -100 <= n < 0  : loss in n plies
+    /// 0              : draw
+    /// 0 < n <= 100   : win in n plies
+    /// 100 < n        : win, but draw under the 50-move rule
+    /// ```
+    pub fn probe_dtz(&self, board: &mut Board) -> Option<i32> {
+        if !self.can_probe(board) {
+            return None;
+        }
+
+        let (wdl, zeroing) = self.search(board, true)?;
+
+        // DTZ tables don't store draws
+        if wdl == Wdl::Draw {
+            return Some(0);
+        }
+
+        // The stored value is a "don't care" (or plain wrong for en passant)
+        if zeroing {
+            return Some(wdl.dtz_before_zeroing());
+        }
+
+        let sign = (wdl as i32).signum();
+
+        if let Lookup::Value(dtz) = self.probe_table(board, TableKind::Dtz, wdl)? {
+            let cursed = matches!(wdl, Wdl::CursedWin | Wdl::BlessedLoss);
+            return Some((dtz + if cursed { 100 } else { 0 }) * sign);
+        }
+
+        // The table stores the other side to move: search one ply and take the
+        // best move keeping the result
+        let mut min_dtz = i32::MAX;
+
+        for mv in Self::legal_moves(board) {
+            let zeroing = Self::is_zeroing(board, &mv);
+
+            board.make_move(&mv);
+            let result = if zeroing {
+                // The DTZ of the move before it, with the sign from after it
+                self.search(board, false).map(|(wdl, _)| -wdl.dtz_before_zeroing())
+            } else {
+                self.probe_dtz(board).map(|dtz| -dtz)
+            };
+            let mate = result == Some(1) && board.in_check() && Self::legal_moves(board).is_empty();
+            board.revert_state();
+
+            let mut dtz = result?;
+
+            if mate {
+                min_dtz = 1;
+            }
+
+            // Zeroing moves are already counted by dtz_before_zeroing
+            if !zeroing {
+                dtz += dtz.signum();
+            }
+
+            if dtz < min_dtz && dtz.signum() == sign {
+                min_dtz = dtz;
+            }
+        }
+
+        // No legal moves: mated
+        Some(if min_dtz == i32::MAX { -1 } else { min_dtz })
+    }
+
+    /// Best move at the root by DTZ: the fastest win, otherwise a draw, otherwise the longest loss.
+    /// Returns the move with its result and DTZ.
+    pub fn probe_root(&self, board: &mut Board) -> Option<(Move, Wdl, i32)> {
+        if !self.can_probe(board) {
+            return None;
+        }
+
+        let mut best: Option<(Move, Wdl, i32)> = None;
+
+        for mv in Self::legal_moves(board) {
+            let zeroing = Self::is_zeroing(board, &mv);
+
+            board.make_move(&mv);
+            let result = if zeroing {
+                self.search(board, false).map(|(wdl, _)| wdl.flip().dtz_before_zeroing())
+            } else {
+                self.probe_dtz(board).map(|dtz| -dtz + (-dtz).signum())
+            };
+            let mate = board.in_check() && Self::legal_moves(board).is_empty();
+            board.revert_state();
+
+            let dtz = if mate { 1 } else { result? };
+            let wdl = match dtz {
+                101.. => Wdl::CursedWin,
+                1..   => Wdl::Win,
+                0     => Wdl::Draw,
+                -100.. => Wdl::Loss,
+                _     => Wdl::BlessedLoss,
+            };
+
+            // Higher is better: wins by shortest DTZ, then draws, then losses by longest DTZ
+            let rank = |wdl: Wdl, dtz: i32| match wdl {
+                Wdl::Win         => (4, -dtz),
+                Wdl::CursedWin   => (3, -dtz),
+                Wdl::Draw        => (2, 0),
+                Wdl::BlessedLoss => (1, -dtz),
+                Wdl::Loss        => (0, -dtz),
+            };
+
+            if best.is_none_or(|(_, best_wdl, best_dtz)| rank(wdl, dtz) > rank(best_wdl, best_dtz)) {
+                best = Some((mv, wdl, dtz));
+            }
+        }
+
+        best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The published tables, fetched by tools/syzygy/download.sh
+    const TABLES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tools/syzygy");
+
+    fn tablebases() -> Tablebases {
+        let tablebases = Tablebases::open(TABLES);
+        assert!(!tablebases.is_empty(), "no tables in {TABLES}, run tools/syzygy/download.sh");
+        tablebases
+    }
+
+    /// DTZ tables may store moves instead of plies, in which case a value
+    /// comes back one ply longer than the exact distance
+    fn assert_dtz_close(dtz: i32, expected: i32, context: &str) {
+        assert_eq!(dtz.signum(), expected.signum(), "{context}: {dtz} for {expected}");
+        assert!((0..=1).contains(&(dtz.abs() - expected.abs())), "{context}: {dtz} for {expected}");
+    }
+
+    fn board(fen: &str) -> Board {
+        Board::from_fen(fen).unwrap()
+    }
+
+    /// The same position with colors swapped and the board flipped
+    fn mirror(fen: &str) -> String {
+        let fields: Vec<&str> = fen.split_whitespace().collect();
+
+        let placement = fields[0]
+            .split('/')
+            .rev()
+            .map(|rank| {
+                rank.chars()
+                    .map(|c| if c.is_ascii_uppercase() { c.to_ascii_lowercase() } else { c.to_ascii_uppercase() })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("/");
+        let color = if fields[1] == "w" { "b" } else { "w" };
+        let en_passant = match fields[3].as_bytes() {
+            [file, b'3'] => format!("{}6", *file as char),
+            [file, b'6'] => format!("{}3", *file as char),
+            _ => "-".to_string(),
+        };
+
+        format!("{placement} {color} - {en_passant} 0 1")
+    }
+
+    fn assert_wdl(tablebases: &Tablebases, fen: &str, wdl: Wdl) {
+        for fen in [fen.to_string(), mirror(fen)] {
+            assert_eq!(tablebases.probe_wdl(&mut board(&fen)), Some(wdl), "{fen}");
+        }
+    }
+
+    fn assert_dtz(tablebases: &Tablebases, fen: &str, dtz: i32) {
+        for fen in [fen.to_string(), mirror(fen)] {
+            assert_dtz_close(tablebases.probe_dtz(&mut board(&fen)).unwrap(), dtz, &fen);
+        }
+    }
+
+    fn is_mate(board: &mut Board) -> bool {
+        board.in_check() && Tablebases::legal_moves(board).is_empty()
+    }
+
+    /// Play the moves `probe_root` picks until the game ends, checking that
+    /// the winning side mates without ever reaching the 50-move rule
+    fn play_out(tablebases: &Tablebases, fen: &str, wdl: Wdl) {
+        let mut board = board(fen);
+        let winner = if wdl == Wdl::Win { *board.color_to_move() } else { board.color_to_move().inverse() };
+        let mut since_zeroing = 0;
+
+        for _ in 0..200 {
+            let Some((mv, root_wdl, dtz)) = tablebases.probe_root(&mut board) else {
+                assert!(is_mate(&mut board), "{fen}: no move in {}", board.to_fen());
+                assert_eq!(*board.color_to_move(), winner.inverse(), "{fen}: wrong side mated");
+                return;
+            };
+
+            let expected = if *board.color_to_move() == winner { Wdl::Win } else { Wdl::Loss };
+            assert_eq!(root_wdl, expected, "{fen}: {}", board.to_fen());
+            assert_eq!(tablebases.probe_wdl(&mut board), Some(expected), "{fen}: {}", board.to_fen());
+            assert_eq!(tablebases.probe_dtz(&mut board), Some(dtz), "{fen}: {}", board.to_fen());
+
+            assert!(dtz.abs() <= 100, "{fen}: {} has dtz {dtz}", board.to_fen());
+
+            since_zeroing = if Tablebases::is_zeroing(&board, &mv) { 0 } else { since_zeroing + 1 };
+            assert!(since_zeroing < 100, "{fen}: 50-move rule in {}", board.to_fen());
+            board.make_move(&mv);
+        }
+
+        panic!("{fen}: no mate in 200 plies");
+    }
+
+    /// Positions with the given pieces (FEN letters, white's first) placed
+    /// pseudo-randomly, both sides to move, kings apart and no pawn on the
+    /// first or last rank
+    fn sample_positions(pieces: &str, count: usize) -> Vec<String> {
+        let mut seed = 0x2545_F491_4F6C_DD1Du64;
+        let mut random = move || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            (seed % 64) as usize
+        };
+
+        let mut positions = Vec::new();
+        while positions.len() < count {
+            let squares: Vec<usize> = pieces.chars().map(|_| random()).collect();
+            let distinct = squares.iter().enumerate().all(|(i, square)| !squares[..i].contains(square));
+            let pawns_ok = pieces
+                .chars()
+                .zip(&squares)
+                .all(|(piece, square)| !piece.eq_ignore_ascii_case(&'p') || (8..56).contains(square));
+            if !distinct || !pawns_ok {
+                continue;
+            }
+
+            let mut grid = [None; 64];
+            for (piece, &square) in pieces.chars().zip(&squares) {
+                grid[square] = Some(piece);
+            }
+            let placement = (0..8)
+                .rev()
+                .map(|rank| {
+                    let mut row = String::new();
+                    let mut empty = 0;
+                    for file in 0..8 {
+                        match grid[rank * 8 + file] {
+                            Some(piece) => {
+                                if empty > 0 {
+                                    row.push_str(&empty.to_string());
+                                    empty = 0;
+                                }
+                                row.push(piece);
+                            }
+                            None => empty += 1,
+                        }
+                    }
+                    if empty > 0 {
+                        row.push_str(&empty.to_string());
+                    }
+                    row
+                })
+                .collect::<Vec<_>>()
+                .join("/");
+
+            // Illegal with the side not to move in check
+            for color in ["w", "b"] {
+                let fen = format!("{placement} {color} - - 0 1");
+                if Board::from_fen(&fen).is_ok() {
+                    positions.push(fen);
+                }
+            }
+        }
+
+        positions
+    }
+
+    /// Every result agrees with the best of the positions one move later, and
+    /// every DTZ up to the rounding of the tables
+    fn assert_consistent(tablebases: &Tablebases, fen: &str) {
+        let mut board = board(fen);
+        let wdl = tablebases.probe_wdl(&mut board).unwrap();
+        let dtz = tablebases.probe_dtz(&mut board).unwrap();
+
+        let mut best_wdl = if board.in_check() { Wdl::Loss } else { Wdl::Draw };
+        let mut best_dtz = None;
+        let moves = Tablebases::legal_moves(&mut board);
+        if !moves.is_empty() {
+            best_wdl = Wdl::Loss;
+        }
+
+        for mv in &moves {
+            let zeroing = Tablebases::is_zeroing(&board, mv);
+            board.make_move(mv);
+            let child_wdl = tablebases.probe_wdl(&mut board).unwrap().flip();
+            let child_dtz = if is_mate(&mut board) {
+                1
+            } else if zeroing {
+                child_wdl.dtz_before_zeroing()
+            } else {
+                let child_dtz = -tablebases.probe_dtz(&mut board).unwrap();
+                child_dtz + child_dtz.signum()
+            };
+            board.revert_state();
+
+            best_wdl = best_wdl.max(child_wdl);
+            if child_wdl == wdl {
+                // Shortest win, longest loss
+                best_dtz = Some(best_dtz.map_or(child_dtz, |best: i32| best.min(child_dtz)));
+            }
+        }
+
+        assert_eq!(wdl, best_wdl, "{fen}");
+        match wdl {
+            Wdl::Draw => assert_eq!(dtz, 0, "{fen}"),
+            _ if moves.is_empty() => assert_eq!(dtz, -1, "{fen}"),
+            _ => assert_dtz_close(dtz, best_dtz.unwrap(), fen),
+        }
+    }
+
+    #[test]
+    fn no_tables() {
+        for path in ["", concat!(env!("CARGO_MANIFEST_DIR"), "/src")] {
+            let tablebases = Tablebases::open(path);
+            assert!(tablebases.is_empty());
+            assert_eq!(tablebases.max_pieces(), 0);
+            assert!(!tablebases.can_probe(&board("8/8/8/3k4/8/8/8/KQ6 w - - 0 1")));
+            assert_eq!(tablebases.probe_wdl(&mut board("8/8/8/3k4/8/8/8/KQ6 w - - 0 1")), None);
+        }
+    }
+
+    #[test]
+    #[ignore = "needs the Syzygy tables from tools/syzygy/download.sh"]
+    fn finds_tables() {
+        let tablebases = tablebases();
+        assert_eq!(tablebases.len(), 10);
+        assert_eq!(tablebases.max_pieces(), 4);
+        assert!(tablebases.can_probe(&board("8/8/8/8/3k4/8/8/KQR5 w - - 0 1")));
+        assert!(!tablebases.can_probe(&board("8/8/8/8/3k4/8/8/KQRB4 w - - 0 1")));
+        assert!(!tablebases.can_probe(&board("4k3/8/8/8/8/8/8/R3K3 w Q - 0 1")));
+    }
+
+    #[test]
+    #[ignore = "needs the Syzygy tables from tools/syzygy/download.sh"]
+    fn missing_tables() {
+        let tablebases = tablebases();
+        // KQvKQ isn't there
+        let mut board = board("8/8/8/3q4/3k4/8/8/KQ6 w - - 0 1");
+        assert!(tablebases.can_probe(&board));
+        assert_eq!(tablebases.probe_wdl(&mut board), None);
+        assert_eq!(tablebases.probe_dtz(&mut board), None);
+        assert_eq!(tablebases.probe_root(&mut board), None);
+    }
+
+    #[test]
+    #[ignore = "needs the Syzygy tables from tools/syzygy/download.sh"]
+    fn known_results() {
+        let tablebases = tablebases();
+
+        // King in front of its pawn on the 6th rank wins, whoever moves
+        assert_wdl(&tablebases, "4k3/8/4K3/4P3/8/8/8/8 w - - 0 1", Wdl::Win);
+        assert_wdl(&tablebases, "4k3/8/4K3/4P3/8/8/8/8 b - - 0 1", Wdl::Loss);
+        // Pawn on the 7th: Kf6 wins with white to move, black to move is stalemate
+        assert_wdl(&tablebases, "4k3/4P3/4K3/8/8/8/8/8 w - - 0 1", Wdl::Win);
+        assert_wdl(&tablebases, "4k3/4P3/4K3/8/8/8/8/8 b - - 0 1", Wdl::Draw);
+        // Rook pawn with the defending king in the corner
+        assert_wdl(&tablebases, "k7/8/8/8/8/8/P7/K7 w - - 0 1", Wdl::Draw);
+        // Minor pieces can't mate
+        assert_wdl(&tablebases, "8/8/3k4/8/8/2B5/8/K7 w - - 0 1", Wdl::Draw);
+        assert_wdl(&tablebases, "8/8/3k4/8/8/2N5/8/K7 w - - 0 1", Wdl::Draw);
+        // Queen or rook against a bare king
+        assert_wdl(&tablebases, "8/8/3k4/8/8/8/8/KQ6 b - - 0 1", Wdl::Loss);
+        assert_wdl(&tablebases, "8/8/3k4/8/8/8/8/KR6 w - - 0 1", Wdl::Win);
+        // A hanging rook
+        assert_wdl(&tablebases, "8/8/8/8/8/2k5/3R4/K7 b - - 0 1", Wdl::Draw);
+        // The rook wins the pawn, unless it promotes with check
+        assert_wdl(&tablebases, "8/8/8/8/8/8/p7/R3K2k w - - 0 1", Wdl::Win);
+        // Stalemate
+        assert_wdl(&tablebases, "7k/5Q2/6K1/8/8/8/8/8 b - - 0 1", Wdl::Draw);
+        // Mated
+        assert_wdl(&tablebases, "R6k/8/6K1/8/8/8/8/8 b - - 0 1", Wdl::Loss);
+    }
+
+    #[test]
+    #[ignore = "needs the Syzygy tables from tools/syzygy/download.sh"]
+    fn known_dtz() {
+        let tablebases = tablebases();
+
+        assert_dtz(&tablebases, "7k/8/6K1/8/8/8/8/R7 w - - 0 1", 1);
+        assert_dtz(&tablebases, "R6k/8/6K1/8/8/8/8/8 b - - 0 1", -1);
+        assert_dtz(&tablebases, "7k/5Q2/6K1/8/8/8/8/8 b - - 0 1", 0);
+        // A capture or a pawn move zeroes the counter
+        assert_dtz(&tablebases, "8/8/8/8/8/8/p7/R3K2k w - - 0 1", 1);
+        // The pawn is blocked: a king move, a reply, then the push
+        assert_dtz(&tablebases, "4k3/8/4K3/4P3/8/8/8/8 w - - 0 1", 3);
+        // Only king moves: mate in two with the rook
+        assert_dtz(&tablebases, "7k/8/5K2/8/8/8/8/R7 w - - 0 1", 3);
+
+        for fen in ["7k/8/6K1/8/8/8/8/R7 w - - 0 1", &mirror("7k/8/6K1/8/8/8/8/R7 w - - 0 1")] {
+            let (mv, wdl, dtz) = tablebases.probe_root(&mut board(fen)).unwrap();
+            let mate = if fen.ends_with("w - - 0 1") { "a1a8" } else { "a8a1" };
+            assert_eq!((mv.to_uci().as_str(), wdl, dtz), (mate, Wdl::Win, 1), "{fen}");
+        }
+        assert_eq!(tablebases.probe_root(&mut board("R6k/8/6K1/8/8/8/8/8 b - - 0 1")), None);
+    }
+
+    #[test]
+    #[ignore = "needs the Syzygy tables from tools/syzygy/download.sh"]
+    fn play_outs() {
+        let tablebases = tablebases();
+
+        for (fen, wdl) in [
+            ("8/8/8/3k4/8/8/8/KQ6 w - - 0 1", Wdl::Win),
+            ("8/8/8/3k4/8/8/8/KR6 b - - 0 1", Wdl::Loss),
+            ("4k3/8/4K3/4P3/8/8/8/8 w - - 0 1", Wdl::Win),
+            ("8/8/8/8/8/1k6/1p6/1K1R4 b - - 0 1", Wdl::Loss),
+        ] {
+            play_out(&tablebases, fen, wdl);
+            play_out(&tablebases, &mirror(fen), wdl);
+        }
+    }
+
+    #[test]
+    #[ignore = "needs the Syzygy tables from tools/syzygy/download.sh"]
+    fn consistent() {
+        let tablebases = tablebases();
+
+        for pieces in ["KQk", "KPk", "KRkp", "KQkr", "KRkn"] {
+            for fen in sample_positions(pieces, 100) {
+                assert_consistent(&tablebases, &fen);
+                assert_consistent(&tablebases, &mirror(&fen));
+            }
+        }
+    }
+}
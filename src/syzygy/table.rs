@@ -0,0 +1,683 @@
+use std::path::Path;
+
+use super::encoding::{
+    off_a1h8,
+    BINOMIAL,
+    LEAD_PAWN_IDX, LEAD_PAWNS_SIZE,
+    MAP_A1D1D4, MAP_B1H1H7, MAP_KK, MAP_PAWNS
+};
+use super::material::Material;
+use super::probe::Wdl;
+use super::{Board, Color, PieceType, TB_PIECES};
+
+/*
+ * Syzygy table files
+ *
+ * A file is a 4 byte magic followed by a header describing, for each side
+ * to move (WDL only, DTZ tables are one-sided) and each leading pawn file
+ * (a-d, tables with pawns only), how pieces are grouped and encoded into an
+ * index, and the parameters of the compressed data. Values are compressed
+ * with Recursive Pairing and stored as canonical Huffman codes in fixed size
+ * blocks, with a sparse index to find the block holding a given index.
+ *
+ * All offsets below are into the whole file. Numbers are little-endian
+ * except the Huffman-coded data, which is read as big-endian.
+ */
+
+const WDL_MAGIC: [u8; 4] = [0x71, 0xE8, 0x23, 0x5D];
+const DTZ_MAGIC: [u8; 4] = [0xD7, 0x66, 0x0C, 0xA5];
+
+// Per-table flags
+const FLAG_STM: u8          = 1;
+const FLAG_MAPPED: u8       = 2;
+const FLAG_WIN_PLIES: u8    = 4;
+const FLAG_LOSS_PLIES: u8   = 8;
+const FLAG_WIDE: u8         = 16;
+const FLAG_SINGLE_VALUE: u8 = 128;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum TableKind {
+    Wdl,
+    Dtz,
+}
+
+/// Outcome of looking a position up in one table
+pub enum Lookup {
+    Value(i32),
+    ChangeStm, // DTZ table only stores the other side to move
+}
+
+#[derive(Clone, Default)]
+struct PairsData {
+    flags: u8,
+    block_size: usize,
+    span: usize,               // A sparse index entry every `span` values
+    num_blocks: usize,
+    min_sym_len: usize,        // Also the value itself for single value tables
+    lowest_sym: usize,         // Offset of the lowest symbol of each length
+    btree: usize,              // Offset of the symbol pairs, 3 bytes per symbol
+    block_length: usize,       // Offset of the number of values (- 1) in each block
+    block_length_size: usize,
+    sparse_index: usize,       // Offset of the (block, offset in block) entries
+    sparse_index_size: usize,
+    data: usize,               // Offset of the compressed blocks
+    base64: Vec<u64>,          // Lowest symbol of each length, left aligned
+    symlen: Vec<u8>,           // Number of values (- 1) each symbol expands to
+    pieces: [u8; TB_PIECES],   // Piece codes in encoding order
+    group_idx: [u64; TB_PIECES + 1],
+    group_len: [usize; TB_PIECES + 1],
+    map_idx: [u16; 4],         // DTZ value map per WDL result
+}
+
+pub struct Table {
+    data: Vec<u8>,
+    kind: TableKind,
+    pairs: Vec<Vec<PairsData>>, // [side][file]
+    map: usize,                 // Offset of the DTZ value maps
+}
+
+impl Table {
+    pub fn load(path: &Path, kind: TableKind, material: &Material) -> Option<Self> {
+        let data = std::fs::read(path).ok()?;
+
+        let magic = match kind {
+            TableKind::Wdl => WDL_MAGIC,
+            TableKind::Dtz => DTZ_MAGIC,
+        };
+        if data.len() % 64 != 16 || data[..4] != magic {
+            return None;
+        }
+
+        let mut table = Self { data, kind, pairs: Vec::new(), map: 0 };
+        table.parse(material)?;
+        Some(table)
+    }
+
+    #[inline(always)]
+    fn u16_le(&self, offset: usize) -> u16 {
+        u16::from_le_bytes([self.data[offset], self.data[offset + 1]])
+    }
+
+    #[inline(always)]
+    fn u32_le(&self, offset: usize) -> u32 {
+        u32::from_le_bytes(self.data[offset..offset + 4].try_into().unwrap())
+    }
+
+    #[inline(always)]
+    fn u32_be(&self, offset: usize) -> u32 {
+        u32::from_be_bytes(self.data[offset..offset + 4].try_into().unwrap())
+    }
+
+    #[inline(always)]
+    fn u64_be(&self, offset: usize) -> u64 {
+        u64::from_be_bytes(self.data[offset..offset + 8].try_into().unwrap())
+    }
+
+    /// Read the header, checking every offset stays inside the file
+    fn parse(&mut self, material: &Material) -> Option<()> {
+        let mut pos = 4;
+
+        // Bit 0: split (both sides stored), bit 1: has pawns
+        let header = *self.data.get(pos)?;
+        if (header & 2 != 0) != material.has_pawns {
+            return None;
+        }
+        pos += 1;
+
+        let sides = if self.kind == TableKind::Wdl && !material.symmetric { 2 } else { 1 };
+        let files = if material.has_pawns { 4 } else { 1 };
+        let pp = material.has_pawns && material.pawn_count[1] > 0; // Pawns on both sides
+
+        self.pairs = vec![vec![PairsData::default(); files]; sides];
+
+        for file in 0..files {
+            let first = *self.data.get(pos)?;
+            let second = if pp { *self.data.get(pos + 1)? } else { 0xFF };
+            let order = [
+                [first & 0xF, second & 0xF],
+                [first >> 4, second >> 4],
+            ];
+            pos += 1 + pp as usize;
+
+            for k in 0..material.piece_count {
+                let byte = *self.data.get(pos)?;
+                for side in 0..sides {
+                    self.pairs[side][file].pieces[k] = if side == 0 { byte & 0xF } else { byte >> 4 };
+                }
+                pos += 1;
+            }
+
+            for (side, order) in order.into_iter().enumerate().take(sides) {
+                Self::set_groups(&mut self.pairs[side][file], material, order, file)?;
+            }
+        }
+
+        pos += pos & 1;
+
+        for file in 0..files {
+            for side in 0..sides {
+                pos = self.set_sizes(side, file, pos)?;
+            }
+        }
+
+        if self.kind == TableKind::Dtz {
+            pos = self.set_dtz_map(files, pos)?;
+        }
+
+        for file in 0..files {
+            for side in 0..sides {
+                let d = &mut self.pairs[side][file];
+                d.sparse_index = pos;
+                pos += d.sparse_index_size * 6;
+            }
+        }
+
+        for file in 0..files {
+            for side in 0..sides {
+                let d = &mut self.pairs[side][file];
+                d.block_length = pos;
+                pos += d.block_length_size * 2;
+            }
+        }
+
+        for file in 0..files {
+            for side in 0..sides {
+                pos = (pos + 0x3F) & !0x3F;
+                let d = &mut self.pairs[side][file];
+                d.data = pos;
+                pos += d.num_blocks * d.block_size;
+            }
+        }
+
+        (pos <= self.data.len()).then_some(())
+    }
+
+    /// Split the pieces into groups encoded together, and compute each group's
+    /// multiplier in the final index according to the stored group order
+    fn set_groups(d: &mut PairsData, material: &Material, order: [u8; 2], file: usize) -> Option<()> {
+        let mut n = 0;
+        let mut first_len: i32 = if material.has_pawns {
+            0
+        } else if material.has_unique_pieces {
+            3
+        } else {
+            2
+        };
+
+        // The leading group holds the first 3 (or 2) pieces, or the leading pawns,
+        // after that identical pieces next to each other share a group
+        d.group_len[0] = 1;
+        for i in 1..material.piece_count {
+            first_len -= 1;
+            if first_len > 0 || d.pieces[i] == d.pieces[i - 1] {
+                d.group_len[n] += 1;
+            } else {
+                n += 1;
+                d.group_len[n] = 1;
+            }
+        }
+        n += 1;
+        d.group_len[n] = 0;
+
+        let pp = material.has_pawns && material.pawn_count[1] > 0;
+        let mut next = if pp { 2 } else { 1 };
+        let mut free_squares = 64 - d.group_len[0] - if pp { d.group_len[1] } else { 0 };
+        let mut idx: u64 = 1;
+
+        let mut k = 0;
+        while next < n || k == order[0] as usize || k == order[1] as usize {
+            if k == order[0] as usize {
+                // Leading pawns or pieces
+                d.group_idx[0] = idx;
+                idx = idx.checked_mul(if material.has_pawns {
+                    LEAD_PAWNS_SIZE[d.group_len[0]][file]
+                } else if material.has_unique_pieces {
+                    31332
+                } else {
+                    462
+                })?;
+            } else if k == order[1] as usize {
+                // Remaining pawns
+                d.group_idx[1] = idx;
+                idx = idx.checked_mul(BINOMIAL[d.group_len[1]][48 - d.group_len[0]])?;
+            } else {
+                // Remaining pieces
+                d.group_idx[next] = idx;
+                idx = idx.checked_mul(BINOMIAL[d.group_len[next]][free_squares])?;
+                free_squares -= d.group_len[next];
+                next += 1;
+            }
+            k += 1;
+        }
+
+        d.group_idx[n] = idx;
+        Some(())
+    }
+
+    /// Read the compression parameters and build the Huffman decoding tables
+    fn set_sizes(&mut self, side: usize, file: usize, mut pos: usize) -> Option<usize> {
+        let flags = *self.data.get(pos)?;
+        pos += 1;
+
+        if flags & FLAG_SINGLE_VALUE != 0 {
+            let d = &mut self.pairs[side][file];
+            d.flags = flags;
+            d.min_sym_len = *self.data.get(pos)? as usize;
+            return Some(pos + 1);
+        }
+
+        let header = self.data.get(pos..pos + 9)?;
+        let block_size = 1usize.checked_shl(header[0] as u32)?;
+        let span = 1usize.checked_shl(header[1] as u32)?;
+        let padding = header[2] as usize;
+        let num_blocks = u32::from_le_bytes(header[3..7].try_into().unwrap()) as usize;
+        let max_sym_len = header[7] as usize;
+        let min_sym_len = header[8] as usize;
+        pos += 9;
+
+        if min_sym_len == 0 || max_sym_len < min_sym_len || max_sym_len > 64 {
+            return None;
+        }
+
+        // The last group index is the table size
+        let d = &self.pairs[side][file];
+        let groups = d.group_len.iter().position(|&len| len == 0).unwrap();
+        let tb_size = d.group_idx[groups] as usize;
+
+        let lowest_sym = pos;
+        let lengths = max_sym_len - min_sym_len + 1;
+        self.data.get(pos..pos + 2 * lengths + 2)?;
+
+        // Longer codes have lower values, so base64[i] >= base64[i + 1]
+        let mut base64 = vec![0u64; lengths];
+        for i in (0..lengths - 1).rev() {
+            base64[i] = base64[i + 1]
+                .wrapping_add(self.u16_le(lowest_sym + 2 * i) as u64)
+                .wrapping_sub(self.u16_le(lowest_sym + 2 * (i + 1)) as u64)
+                / 2;
+        }
+
+        // Left align so any code of length l satisfies base64[l - 1] > code >= base64[l]
+        for (i, base) in base64.iter_mut().enumerate() {
+            *base = base.checked_shl((64 - i - min_sym_len) as u32).unwrap_or(0);
+        }
+        pos += 2 * lengths;
+
+        let symbols = self.u16_le(pos) as usize;
+        pos += 2;
+
+        let btree = pos;
+        self.data.get(btree..btree + 3 * symbols)?;
+
+        let mut symlen = vec![0u8; symbols];
+        let mut visited = vec![false; symbols];
+        for sym in 0..symbols {
+            if !visited[sym] {
+                symlen[sym] = self.set_symlen(btree, sym, &mut symlen, &mut visited)?;
+            }
+        }
+
+        let d = &mut self.pairs[side][file];
+        d.flags = flags;
+        d.block_size = block_size;
+        d.span = span;
+        d.sparse_index_size = tb_size.div_ceil(span);
+        d.num_blocks = num_blocks;
+        d.block_length_size = num_blocks + padding;
+        d.min_sym_len = min_sym_len;
+        d.lowest_sym = lowest_sym;
+        d.base64 = base64;
+        d.btree = btree;
+        d.symlen = symlen;
+
+        Some(pos + 3 * symbols + (symbols & 1))
+    }
+
+    /// Number of values (- 1) a symbol expands to, following its pair recursively
+    fn set_symlen(&self, btree: usize, sym: usize, symlen: &mut [u8], visited: &mut [bool]) -> Option<u8> {
+        visited[sym] = true; // The tree is acyclic
+
+        let right = self.btree_right(btree, sym);
+        if right == 0xFFF {
+            return Some(0);
+        }
+        let left = self.btree_left(btree, sym);
+
+        for child in [left, right] {
+            if child >= symlen.len() {
+                return None;
+            }
+            if !visited[child] {
+                symlen[child] = self.set_symlen(btree, child, symlen, visited)?;
+            }
+        }
+
+        Some(symlen[left].wrapping_add(symlen[right]).wrapping_add(1))
+    }
+
+    #[inline(always)]
+    fn btree_left(&self, btree: usize, sym: usize) -> usize {
+        let lr = btree + 3 * sym;
+        ((self.data[lr + 1] as usize & 0xF) << 8) | self.data[lr] as usize
+    }
+
+    #[inline(always)]
+    fn btree_right(&self, btree: usize, sym: usize) -> usize {
+        let lr = btree + 3 * sym;
+        ((self.data[lr + 2] as usize) << 4) | (self.data[lr + 1] as usize >> 4)
+    }
+
+    /// DTZ tables can remap stored values through a small per-WDL-result table
+    fn set_dtz_map(&mut self, files: usize, mut pos: usize) -> Option<usize> {
+        self.map = pos;
+
+        for file in 0..files {
+            let flags = self.pairs[0][file].flags;
+            if flags & FLAG_MAPPED == 0 {
+                continue;
+            }
+
+            for i in 0..4 {
+                if flags & FLAG_WIDE != 0 {
+                    pos += pos & 1;
+                    self.pairs[0][file].map_idx[i] = ((pos - self.map) / 2 + 1) as u16;
+                    pos += 2 * self.data.get(pos..pos + 2).map(|_| self.u16_le(pos))? as usize + 2;
+                } else {
+                    self.pairs[0][file].map_idx[i] = (pos - self.map + 1) as u16;
+                    pos += *self.data.get(pos)? as usize + 1;
+                }
+            }
+        }
+
+        Some(pos + (pos & 1))
+    }
+
+    /// Decode the value stored at `idx`
+    fn decompress_pairs(&self, d: &PairsData, idx: u64) -> usize {
+        if d.flags & FLAG_SINGLE_VALUE != 0 {
+            return d.min_sym_len;
+        }
+
+        // The sparse index stores the block and offset of the value at
+        // k * span + span / 2, walk from there to the block holding idx
+        let k = (idx / d.span as u64) as usize;
+        let mut block = self.u32_le(d.sparse_index + 6 * k) as usize;
+        let mut offset = self.u16_le(d.sparse_index + 6 * k + 4) as i64;
+        offset += (idx % d.span as u64) as i64 - (d.span / 2) as i64;
+
+        let block_length = |block: usize| self.u16_le(d.block_length + 2 * block) as i64;
+
+        while offset < 0 {
+            block -= 1;
+            offset += block_length(block) + 1;
+        }
+        while offset > block_length(block) {
+            offset -= block_length(block) + 1;
+            block += 1;
+        }
+
+        // Walk the Huffman codes of the block until the symbol covering offset
+        let mut ptr = d.data + block * d.block_size;
+        let mut buf64 = self.u64_be(ptr);
+        ptr += 8;
+        let mut buf64_size = 64;
+
+        let mut sym;
+        loop {
+            let mut len = 0;
+            while buf64 < d.base64[len] {
+                len += 1;
+            }
+
+            sym = ((buf64 - d.base64[len]) >> (64 - len - d.min_sym_len)) as usize;
+            sym += self.u16_le(d.lowest_sym + 2 * len) as usize;
+
+            if offset < d.symlen[sym] as i64 + 1 {
+                break;
+            }
+
+            offset -= d.symlen[sym] as i64 + 1;
+            len += d.min_sym_len;
+            buf64 <<= len;
+            buf64_size -= len;
+
+            if buf64_size <= 32 {
+                buf64_size += 32;
+                buf64 |= (self.u32_be(ptr) as u64) << (64 - buf64_size);
+                ptr += 4;
+            }
+        }
+
+        // Expand the symbol's pairs down to the single value at offset
+        while d.symlen[sym] != 0 {
+            let left = self.btree_left(d.btree, sym);
+            if offset < d.symlen[left] as i64 + 1 {
+                sym = left;
+            } else {
+                offset -= d.symlen[left] as i64 + 1;
+                sym = self.btree_right(d.btree, sym);
+            }
+        }
+
+        self.btree_left(d.btree, sym)
+    }
+
+    #[inline(always)]
+    fn pairs(&self, stm: usize, file: usize) -> &PairsData {
+        let side = &self.pairs[stm % self.pairs.len()];
+        &side[file.min(side.len() - 1)]
+    }
+
+    /// Convert a stored value into a WDL score (-2..=2) or a DTZ in plies
+    fn map_score(&self, file: usize, value: usize, wdl: Wdl) -> i32 {
+        if self.kind == TableKind::Wdl {
+            return value as i32 - 2;
+        }
+
+        let d = self.pairs(0, file);
+        let mut value = value;
+
+        if d.flags & FLAG_MAPPED != 0 {
+            // Indexed by Loss, BlessedLoss, Draw, CursedWin, Win
+            const WDL_MAP: [usize; 5] = [1, 3, 0, 2, 0];
+            let idx = d.map_idx[WDL_MAP[(wdl as i32 + 2) as usize]] as usize + value;
+
+            value = if d.flags & FLAG_WIDE != 0 {
+                self.u16_le(self.map + 2 * idx) as usize
+            } else {
+                self.data[self.map + idx] as usize
+            };
+        }
+
+        // Values are stored in moves unless the table says plies
+        let in_moves = match wdl {
+            Wdl::Win  => d.flags & FLAG_WIN_PLIES == 0,
+            Wdl::Loss => d.flags & FLAG_LOSS_PLIES == 0,
+            Wdl::CursedWin | Wdl::BlessedLoss => true,
+            Wdl::Draw => false,
+        };
+
+        let value = value as i32;
+        if in_moves { value * 2 + 1 } else { value + 1 }
+    }
+
+    /// Piece code used by the table format: 1..=6 for white, 9..=14 for black
+    #[inline(always)]
+    fn piece_code(piece_type: PieceType, color: Color) -> u8 {
+        piece_type as u8 + 1 + 8 * color.index() as u8
+    }
+
+    /// Look a position up. `mirrored` is set when the position has the table's
+    /// material with colors swapped (black is the stronger side).
+    pub fn probe(&self, board: &Board, material: &Material, mirrored: bool, wdl: Wdl) -> Lookup {
+        let mut squares = [0usize; TB_PIECES];
+        let mut pieces = [0u8; TB_PIECES];
+        let mut size = 0;
+
+        // Tables are stored with white as the stronger side, and symmetric
+        // tables only with white to move
+        let black_to_move = *board.color_to_move() == Color::Black;
+        let flip = mirrored || (material.symmetric && black_to_move);
+        let flip_color = if flip { 8 } else { 0 };
+        let flip_squares = if flip { 0o70 } else { 0 };
+        let stm = (flip ^ black_to_move) as usize;
+
+        // With pawns there is a table for each file of the leading pawn
+        let mut lead_pawns = 0;
+        let mut lead_pawns_count = 0;
+        let mut tb_file = 0;
+
+        if material.has_pawns {
+            let lead_color = if (self.pairs(0, 0).pieces[0] ^ flip_color) & 8 != 0 {
+                Color::Black
+            } else {
+                Color::White
+            };
+
+            lead_pawns = board.get_bitboard(&PieceType::Pawn, &lead_color);
+            let mut pawns = lead_pawns;
+            while pawns != 0 {
+                squares[size] = pawns.trailing_zeros() as usize ^ flip_squares;
+                size += 1;
+                pawns &= pawns - 1;
+            }
+            lead_pawns_count = size;
+
+            let leading = (0..lead_pawns_count).max_by_key(|&i| MAP_PAWNS[squares[i]]).unwrap();
+            squares.swap(0, leading);
+
+            tb_file = (squares[0] % 8).min(7 - squares[0] % 8);
+        }
+
+        // DTZ tables are one-sided, except symmetric pawnless ones which are the same either way
+        let one_sided = material.has_pawns || !material.symmetric;
+        if self.kind == TableKind::Dtz
+            && one_sided
+            && (self.pairs(0, tb_file).flags & FLAG_STM) as usize != stm
+        {
+            return Lookup::ChangeStm;
+        }
+
+        let mut others = board.get_occupied().all ^ lead_pawns;
+        while others != 0 {
+            let square = others.trailing_zeros() as u8;
+            others &= others - 1;
+
+            let piece = board.get_piece(square).unwrap();
+            squares[size] = square as usize ^ flip_squares;
+            pieces[size] = Self::piece_code(piece.piece_type, piece.color) ^ flip_color;
+            size += 1;
+        }
+
+        let d = self.pairs(stm, tb_file);
+
+        // Put the pieces in the table's order
+        for i in lead_pawns_count..size.saturating_sub(1) {
+            for j in i + 1..size {
+                if d.pieces[i] == pieces[j] {
+                    pieces.swap(i, j);
+                    squares.swap(i, j);
+                    break;
+                }
+            }
+        }
+
+        // Mirror horizontally so the leading piece is on files a-d
+        if squares[0] % 8 > 3 {
+            for square in &mut squares[..size] {
+                *square ^= 7;
+            }
+        }
+
+        let mut idx;
+        if material.has_pawns {
+            idx = LEAD_PAWN_IDX[lead_pawns_count][squares[0]];
+
+            squares[1..lead_pawns_count].sort_by_key(|&square| MAP_PAWNS[square]);
+            for i in 1..lead_pawns_count {
+                idx += BINOMIAL[i][MAP_PAWNS[squares[i]]];
+            }
+        } else {
+            // Mirror vertically so the leading piece is on ranks 1-4
+            if squares[0] / 8 > 3 {
+                for square in &mut squares[..size] {
+                    *square ^= 0o70;
+                }
+            }
+
+            // Mirror along a1-h8 so the first leading piece off the diagonal is below it
+            for i in 0..d.group_len[0] {
+                let off = off_a1h8(squares[i]);
+                if off == 0 {
+                    continue;
+                }
+
+                if off > 0 {
+                    for square in &mut squares[i..size] {
+                        *square = ((*square >> 3) | (*square << 3)) & 63;
+                    }
+                }
+                break;
+            }
+
+            idx = if material.has_unique_pieces {
+                Self::encode_unique(&squares)
+            } else {
+                MAP_KK[MAP_A1D1D4[squares[0]]][squares[1]] as u64
+            };
+        }
+
+        // Remaining groups: each is a set of squares among those left free
+        idx *= d.group_idx[0];
+
+        let mut group_start = d.group_len[0];
+        let mut remaining_pawns = material.has_pawns && material.pawn_count[1] > 0;
+
+        let mut next = 1;
+        while d.group_len[next] != 0 {
+            let group_end = group_start + d.group_len[next];
+            squares[group_start..group_end].sort_unstable();
+
+            let mut n = 0;
+            for i in 0..d.group_len[next] {
+                let square = squares[group_start + i];
+                let adjust = squares[..group_start].iter().filter(|&&s| square > s).count();
+                n += BINOMIAL[i + 1][square - adjust - if remaining_pawns { 8 } else { 0 }];
+            }
+
+            remaining_pawns = false;
+            idx += n * d.group_idx[next];
+            group_start = group_end;
+            next += 1;
+        }
+
+        Lookup::Value(self.map_score(tb_file, self.decompress_pairs(d, idx), wdl))
+    }
+
+    /// Index of the first three pieces when at least one of them is unique.
+    /// The first is in the a1-d1-d4 triangle and the pieces are ordered so
+    /// diagonal placements come after the rest.
+    fn encode_unique(squares: &[usize]) -> u64 {
+        let [s0, s1, s2] = [squares[0], squares[1], squares[2]];
+        let adjust1 = (s1 > s0) as usize;
+        let adjust2 = (s2 > s0) as usize + (s2 > s1) as usize;
+
+        let idx = if off_a1h8(s0) != 0 {
+            (MAP_A1D1D4[s0] * 63 + (s1 - adjust1)) * 62 + s2 - adjust2
+        } else if off_a1h8(s1) != 0 {
+            (6 * 63 + (s0 / 8) * 28 + MAP_B1H1H7[s1]) * 62 + s2 - adjust2
+        } else if off_a1h8(s2) != 0 {
+            6 * 63 * 62 + 4 * 28 * 62
+                + (s0 / 8) * 7 * 28
+                + (s1 / 8 - adjust1) * 28
+                + MAP_B1H1H7[s2]
+        } else {
+            6 * 63 * 62 + 4 * 28 * 62 + 4 * 7 * 28
+                + (s0 / 8) * 7 * 6
+                + (s1 / 8 - adjust1) * 6
+                + (s2 / 8 - adjust2)
+        };
+
+        idx as u64
+    }
+}
@@ -1,12 +1,17 @@
-pub enum UciCommand<'a> {
+pub enum UciCommand {
     Uci,
     IsReady,
     Position {
         // Assume that the position is startpos if None
-        fen: Option<Vec<&'a str>>,
+        fen: Option<String>,
         moves: Vec<String>
     },
+    SetOption {
+        name: String,
+        value: Option<String>
+    },
     Go,
+    Bench(Option<u8>), // Depth
     Stop,
     Quit,
     Debug(DebugCommand),
@@ -15,6 +20,7 @@ pub enum UciCommand<'a> {
 
 pub enum DebugCommand {
     Move(String),
+    Moves,
     Undo,
     Fen,
     Print,
@@ -23,11 +29,25 @@ pub enum DebugCommand {
     PrintMoves(String),
     Position(String),
     EnPassant,
-    // Eval,
+    Eval,
     CastlingRights,
     AllStats,
     Perft(i32),
     PerftSingleLine(i32),
+    PerftSuite(String, u32), // Path, max depth
+    PerftFast {
+        depth: i32,
+        threads: usize,
+        hash_mb: usize, // No table if 0
+    },
     Divide(i32),
+    TTSave(String),
+    TTLoad(String),
+    Pgn(String, bool), // Path, print FENs instead of the games
+    Suite {
+        path: String,
+        depth: Option<u8>,
+        movetime: Option<u64>, // Milliseconds
+    },
     Unknown(String),
 }
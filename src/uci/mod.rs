@@ -1,12 +1,20 @@
-use crate::{
+use epsilon::{
     board::{
         Board,
         BitboardExt,
-        Square, SquareExt,
-        Move
+        Square, SquareExt
     },
-    debug::Debug,
-    search::{Search, TranspositionTable}
+    book::Book,
+    epd::{Epd, Suite},
+    eval::{Network, Trace},
+    pgn::Game,
+    search::{Search, SearchLimits, SearchOptions, TranspositionTable},
+    syzygy::Tablebases
+};
+
+use crate::{
+    bench::{self, Bench},
+    debug::{Debug, PerftTable},
 };
 
 mod commands;
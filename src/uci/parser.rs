@@ -1,13 +1,22 @@
-use crate::movegen::MoveGen;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use epsilon::movegen::MoveGen;
 
 use super::commands::{UciCommand, DebugCommand};
 use super::uci_io::UciIO;
 use super::{
     Board,
+    bench, Bench,
+    Book,
     TranspositionTable,
-    Debug,
-    Search,
-    Move,
+    Debug, PerftTable,
+    Epd, Suite,
+    Game,
+    Network,
+    Trace,
+    Search, SearchLimits, SearchOptions,
+    Tablebases,
     Square, SquareExt,
     BitboardExt
 };
@@ -37,6 +46,8 @@ macro_rules! uci {
 pub struct UciParser;
 
 impl UciParser {
+    const EMBEDDED_NET: &'static str = "<embedded>";
+
     fn parse(s: &str) -> UciCommand {
         let mut tokens = s
             .split_whitespace()
@@ -51,6 +62,12 @@ impl UciParser {
 
             Some("stop") => uci!(Stop),
 
+            Some("bench") => match tokens.next().map(str::parse::<u64>) {
+                None => UciCommand::Bench(None),
+                Some(Ok(depth)) if depth > 0 => UciCommand::Bench(Some(depth.min(SearchLimits::MAX_DEPTH as u64) as u8)),
+                _ => uci!(unknown s_string),
+            },
+
             Some("quit") => uci!(Quit),
 
             Some("position") => match tokens.next() {
@@ -58,31 +75,21 @@ impl UciParser {
                     // Get moves
                     let mut moves = Vec::new();
                     if tokens.next() == Some("moves") {
-                        while let Some(t) = tokens.next() {
-                            moves.push(t.to_string());
-                        }
+                        moves.extend(tokens.by_ref().map(str::to_string));
                     }
                     uci!(pos startpos moves)
                 }
 
                 Some("fen") => {
-                    // Get fen
-                    let fen: Vec<&str> = tokens
+                    // Get fen (the move counters are optional, so read up to "moves")
+                    let fen = tokens
                         .by_ref()
-                        .take(6)
-                        .collect();
-
-                    if fen.len() < 4 {
-                        panic!();
-                    }
+                        .take_while(|&t| t != "moves")
+                        .collect::<Vec<_>>()
+                        .join(" ");
 
                     // Get moves
-                    let mut moves = Vec::new();
-                    if tokens.next() == Some("moves") {
-                        while let Some(t) = tokens.next() {
-                            moves.push(t.to_string());
-                        }
-                    }
+                    let moves = tokens.map(|t| t.to_string()).collect();
 
                     uci!(pos fen fen moves)
                 } // Some("fen")
@@ -90,6 +97,29 @@ impl UciParser {
                 _ => uci!(unknown s_string),
             } // Some("position")
 
+            Some("setoption") => {
+                if tokens.next() != Some("name") {
+                    return uci!(unknown s_string);
+                }
+
+                // Names and values may contain spaces
+                let mut name = Vec::new();
+                let mut value = Vec::new();
+                let mut in_value = false;
+                for t in tokens {
+                    match t {
+                        "value" if !in_value => in_value = true,
+                        _ if in_value => value.push(t),
+                        _ => name.push(t),
+                    }
+                }
+
+                UciCommand::SetOption {
+                    name: name.join(" "),
+                    value: if in_value { Some(value.join(" ")) } else { None }
+                }
+            }
+
             Some("go") => uci!(Go),
 
             Some("debug") => match tokens.next() {
@@ -123,26 +153,78 @@ impl UciParser {
                     None     => uci!(debug Unknown s_string),
                 }
 
+                Some("moves") => uci!(debug Moves),
+
                 Some("undo") => uci!(debug Undo),
 
                 Some("castling") => uci!(debug CastlingRights),
 
+                Some("eval") => uci!(debug Eval),
+
                 Some("perft") => match tokens.next() {
-                    Some("singleline") => match tokens.next() {
-                        Some(n) => uci!(debug PerftSingleLine n.parse().unwrap()),
-                        None    => uci!(debug Unknown s_string),
+                    Some("singleline") => match tokens.next().map(str::parse) {
+                        Some(Ok(n)) => uci!(debug PerftSingleLine n),
+                        _           => uci!(debug Unknown s_string),
+                    }
+                    Some("fast") => {
+                        let Some(Ok(depth)) = tokens.next().map(str::parse) else {
+                            return uci!(debug Unknown s_string);
+                        };
+
+                        let (mut threads, mut hash_mb) = (1, 0);
+                        while let (Some(name), Some(Ok(value))) = (tokens.next(), tokens.next().map(str::parse)) {
+                            match name {
+                                "threads" => threads = value,
+                                "hash"    => hash_mb = value,
+                                _ => return uci!(debug Unknown s_string),
+                            }
+                        }
+
+                        UciCommand::Debug(DebugCommand::PerftFast { depth, threads, hash_mb })
+                    }
+                    Some("suite") => match (tokens.next(), tokens.next().map(str::parse)) {
+                        (Some(path), None) => UciCommand::Debug(DebugCommand::PerftSuite(path.to_string(), 6)),
+                        (Some(path), Some(Ok(depth))) => UciCommand::Debug(DebugCommand::PerftSuite(path.to_string(), depth)),
+                        _ => uci!(debug Unknown s_string),
+                    }
+                    Some(n) => match n.parse() {
+                        Ok(n)  => uci!(debug Perft n),
+                        Err(_) => uci!(debug Unknown s_string),
                     }
-                    Some(n) => uci!(debug Perft n.parse().unwrap()),
                     None    => uci!(debug Unknown s_string),
                 },
 
-                Some("divide") => match tokens.next() {
-                    Some(n) => uci!(debug Divide n.parse().unwrap()),
-                    None    => uci!(debug Unknown s_string),
+                Some("divide") => match tokens.next().map(str::parse) {
+                    Some(Ok(n)) => uci!(debug Divide n),
+                    _           => uci!(debug Unknown s_string),
                 },
 
                 Some("allstats") => uci!(debug AllStats),
 
+                Some("tt") => match (tokens.next(), tokens.next()) {
+                    (Some("save"), Some(path)) => uci!(debug TTSave path.to_string()),
+                    (Some("load"), Some(path)) => uci!(debug TTLoad path.to_string()),
+                    _ => uci!(debug Unknown s_string),
+                },
+
+                Some("suite") => match (tokens.next(), tokens.next(), tokens.next().map(str::parse::<u64>)) {
+                    (Some(path), None, None) => UciCommand::Debug(DebugCommand::Suite {
+                        path: path.to_string(), depth: None, movetime: None
+                    }),
+                    (Some(path), Some("depth"), Some(Ok(depth))) if depth > 0 => UciCommand::Debug(DebugCommand::Suite {
+                        path: path.to_string(), depth: Some(depth.min(SearchLimits::MAX_DEPTH as u64) as u8), movetime: None
+                    }),
+                    (Some(path), Some("movetime"), Some(Ok(movetime))) => UciCommand::Debug(DebugCommand::Suite {
+                        path: path.to_string(), depth: None, movetime: Some(movetime)
+                    }),
+                    _ => uci!(debug Unknown s_string),
+                }
+
+                Some("pgn") => match tokens.next() {
+                    Some(path) => UciCommand::Debug(DebugCommand::Pgn(path.to_string(), tokens.next() == Some("fens"))),
+                    None       => uci!(debug Unknown s_string),
+                }
+
                 _ => uci!(debug Unknown s_string)
             }
 
@@ -154,7 +236,18 @@ impl UciParser {
         let mut io = UciIO::new();
 
         let mut board = Board::startpos();
-        let mut tt = TranspositionTable::new(16);
+        let tt = TranspositionTable::new(16);
+
+        // Options
+        let mut search_options = SearchOptions {
+            info: Some(Arc::new(|info| println!("{info}"))),
+            ..SearchOptions::default()
+        };
+        let mut use_nnue = false;
+        let mut eval_file = String::from(Self::EMBEDDED_NET);
+        let mut own_book = false;
+        let mut book_best_move = false;
+        let mut book: Option<Book> = None;
 
         while let Some(input) = io.input() {
             if input.is_empty() {
@@ -167,6 +260,13 @@ impl UciParser {
                 UciCommand::Uci => {
                     io.out("id name Epsilon");
                     io.out("id author ns8");
+                    io.out("option name Threads type spin default 1 min 1 max 256");
+                    io.out("option name UseNNUE type check default false");
+                    io.outfmt(format_args!("option name EvalFile type string default {}", Self::EMBEDDED_NET));
+                    io.out("option name OwnBook type check default false");
+                    io.out("option name BookFile type string default <empty>");
+                    io.out("option name BookBestMove type check default false");
+                    io.out("option name SyzygyPath type string default <empty>");
                     io.out("uciok");
                 }
 
@@ -179,20 +279,128 @@ impl UciParser {
                     moves
                 } => {
                     match fen {
-                        Some(v) => board = Board::from_fen(v),
-                        None    => board = Board::startpos(),
+                        Some(fen) => match Board::from_fen(&fen) {
+                            Ok(b) => board = b,
+                            Err(e) => {
+                                io.outfmt(format_args!("info string Invalid FEN: {e}"));
+                                continue;
+                            }
+                        },
+                        None => board = Board::startpos(),
                     }
 
+                    // Stop at the first move that isn't legal, rather than playing it anyway
                     for mv in moves {
-                        let mut mv = Move::from_uci(mv.as_str());
-                        board.find_move_type(&mut mv);
-                        board.make_move(&mv);
+                        match board.parse_uci(&mv) {
+                            Ok(legal) => board.make_move(&legal),
+                            Err(err) => {
+                                io.outfmt(format_args!("info string {err}"));
+                                break;
+                            }
+                        }
                     }
                 }
 
+                UciCommand::SetOption { name, value } => {
+                    match (name.to_lowercase().as_str(), value) {
+                        ("threads", Some(value)) => {
+                            match value.parse::<usize>() {
+                                Ok(n) if (1..=256).contains(&n) => search_options.threads = n,
+                                _ => io.outfmt(format_args!("info string Invalid Threads value: {value}")),
+                            }
+                            continue;
+                        }
+
+                        ("ownbook", Some(value)) => {
+                            own_book = value == "true";
+                            continue;
+                        }
+
+                        ("bookbestmove", Some(value)) => {
+                            book_best_move = value == "true";
+                            continue;
+                        }
+
+                        ("bookfile", value) => {
+                            book = match value.as_deref() {
+                                None | Some("") | Some("<empty>") => None,
+                                Some(path) => match Book::from_file(path) {
+                                    Ok(loaded) => {
+                                        io.outfmt(format_args!("info string Loaded book {path} ({} entries)", loaded.len()));
+                                        Some(loaded)
+                                    }
+                                    Err(err) => {
+                                        io.outfmt(format_args!("info string Failed to load {path}: {err}"));
+                                        None
+                                    }
+                                }
+                            };
+                            continue;
+                        }
+
+                        ("syzygypath", value) => {
+                            search_options.tablebases = match value.as_deref() {
+                                None | Some("") | Some("<empty>") => None,
+                                Some(path) => {
+                                    let tablebases = Tablebases::open(path);
+                                    io.outfmt(format_args!(
+                                        "info string Found {} tablebases (up to {} pieces)",
+                                        tablebases.len(),
+                                        tablebases.max_pieces()
+                                    ));
+                                    (!tablebases.is_empty()).then(|| Arc::new(tablebases))
+                                }
+                            };
+                            continue;
+                        }
+
+                        ("usennue", Some(value)) => use_nnue = value == "true",
+                        ("evalfile", Some(value)) => eval_file = value,
+
+                        _ => {
+                            io.outfmt(format_args!("info string Unknown option: {name}"));
+                            continue;
+                        }
+                    }
+
+                    search_options.network = if !use_nnue {
+                        None
+                    } else if eval_file == Self::EMBEDDED_NET {
+                        Some(Network::embedded())
+                    } else {
+                        match Network::from_file(&eval_file) {
+                            Ok(network) => {
+                                io.outfmt(format_args!("info string Loaded network {eval_file}"));
+                                Some(Arc::new(network))
+                            }
+                            Err(err) => {
+                                io.outfmt(format_args!("info string Failed to load {eval_file}: {err}"));
+                                Some(Network::embedded())
+                            }
+                        }
+                    };
+                }
+
                 UciCommand::Go => {
                     // println!("bestmove {}", Search::random(&mut board, &mut rng).to_uci());
-                    println!("bestmove {}", Search::alphabeta(&mut board, &mut tt, 5).to_uci());
+                    if own_book
+                        && let Some(book) = &book
+                        && let Some(mv) = book.probe(&mut board, book_best_move)
+                    {
+                        println!("bestmove {}", mv.to_uci());
+                        continue;
+                    }
+
+                    tt.new_search();
+                    let result = Search::lazy_smp(&board, &tt, SearchLimits::depth(5), &search_options);
+                    match result.best_move {
+                        Some(mv) => println!("bestmove {}", mv.to_uci()),
+                        None     => println!("bestmove 0000"),
+                    }
+                }
+
+                UciCommand::Bench(depth) => {
+                    Bench::run(depth.unwrap_or(bench::DEFAULT_DEPTH));
                 }
 
                 UciCommand::Stop => break,
@@ -219,37 +427,54 @@ impl UciParser {
                         None    => io.out("None"),
                     }
 
-                    DebugCommand::PrintAttacks(square) => {
-                        let square = Square::from_algebraic(&square);
-                        if let Some(piece) = board.get_piece(square) {
+                    DebugCommand::PrintAttacks(name) => match Square::try_from_algebraic(&name) {
+                        Some(square) => if let Some(piece) = board.get_piece(square) {
                             MoveGen::attacks(&board, &piece.piece_type, &piece.color, square).print();
                         }
+                        None => io.outfmt(format_args!("info string Invalid square {name}")),
                     }
 
-                    DebugCommand::PrintMoves(square) => {
-                        let square = Square::from_algebraic(&square);
-                        if let Some(piece) = board.get_piece(square) {
+                    DebugCommand::PrintMoves(name) => match Square::try_from_algebraic(&name) {
+                        Some(square) => if let Some(piece) = board.get_piece(square) {
                             MoveGen::moves(&board, &piece.piece_type, &piece.color, square).print();
                         }
+                        None => io.outfmt(format_args!("info string Invalid square {name}")),
                     }
 
-                    DebugCommand::Position(pos) => match pos.as_str() {
-                        "kiwipete" => board = Board::from_fen(
+                    DebugCommand::Position(pos) => if pos == "kiwipete" {
+                        board = Board::from_fen(
                             "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1"
-                                .split(' ')
-                                .collect()
-                        ),
-
-                        _ => {}
+                        ).unwrap();
                     }
 
+                    // SAN ("Nf3", "O-O") or UCI ("g1f3", "e1g1")
                     DebugCommand::Move(mv) => {
-                        let mut mv = Move::from_uci(mv.as_str());
-                        board.find_move_type(&mut mv);
-                        board.make_move(&mv);
-                    },
+                        let parsed = board.parse_san(&mv).or_else(|e| {
+                            board.legal_moves()
+                                .into_iter()
+                                .find(|legal| legal.to_uci() == mv)
+                                .ok_or(e)
+                        });
+
+                        match parsed {
+                            Ok(mv) => board.make_move(&mv),
+                            Err(e) => io.outfmt(format_args!("info string {e}")),
+                        }
+                    }
+
+                    DebugCommand::Moves => {
+                        let moves: Vec<String> = board.legal_moves()
+                            .iter()
+                            .map(|mv| board.move_to_san(mv))
+                            .collect();
+                        io.outfmt(format_args!("{}", moves.join(" ")));
+                    }
+
+                    DebugCommand::Undo => if !board.undo_move() {
+                        io.out("info string No move to undo");
+                    }
 
-                    DebugCommand::Undo => board.revert_state(),
+                    DebugCommand::Eval => io.outfmt(format_args!("{}", Trace::new(&board))),
 
                     DebugCommand::CastlingRights => {
                         io.outfmt(format_args!("{:#?}", board.get_castling_rights()));
@@ -282,10 +507,78 @@ impl UciParser {
                         ));
                     }
 
+                    DebugCommand::PerftFast { depth, threads, hash_mb } => {
+                        let table = (hash_mb > 0).then(|| PerftTable::new(hash_mb));
+
+                        let start = Instant::now();
+                        let nodes = Debug::perft_parallel(&board, depth, threads, table.as_ref());
+                        let elapsed = start.elapsed().as_secs_f64();
+
+                        io.outfmt(format_args!(
+                            "Time: {elapsed}s\nNodes: {nodes}\nNodes/s: {}",
+                            (nodes as f64 / elapsed) as u64
+                        ));
+                    }
+
+                    DebugCommand::PerftSuite(path, max_depth) => match Epd::from_file(&path) {
+                        Ok(positions) => {
+                            Debug::perft_suite(&positions, max_depth);
+                        }
+                        Err(err) => io.outfmt(format_args!("info string Failed to read {path}: {err}")),
+                    }
+
                     DebugCommand::Divide(depth) => {
                         io.outfmt(format_args!("\n{}", Debug::divide(&mut board, depth)));
                     }
 
+                    DebugCommand::TTSave(path) => match tt.save(&path) {
+                        Ok(()) => io.outfmt(format_args!("info string Saved transposition table to {path}")),
+                        Err(err) => io.outfmt(format_args!("info string Failed to save {path}: {err}")),
+                    }
+
+                    DebugCommand::TTLoad(path) => match tt.load(&path) {
+                        Ok(()) => io.outfmt(format_args!("info string Loaded transposition table from {path}")),
+                        Err(err) => io.outfmt(format_args!("info string Failed to load {path}: {err}")),
+                    }
+
+                    // Replay every game, printing it back or the FENs of its main line with the result
+                    DebugCommand::Pgn(path, fens) => match Game::from_file(&path) {
+                        Ok(games) => {
+                            for game in &games {
+                                let output = if fens {
+                                    game.mainline_fens().map(|fens| {
+                                        fens.iter()
+                                            .map(|fen| format!("{fen} [{}]", game.result.as_str()))
+                                            .collect::<Vec<_>>()
+                                            .join("\n")
+                                    })
+                                } else {
+                                    game.to_pgn()
+                                };
+
+                                match output {
+                                    Ok(text) => io.outfmt(format_args!("{text}")),
+                                    Err(err) => io.outfmt(format_args!("info string Invalid FEN tag: {err}")),
+                                }
+                            }
+                            io.outfmt(format_args!("info string Read {} games from {path}", games.len()));
+                        }
+                        Err(err) => io.outfmt(format_args!("info string Failed to read {path}: {err}")),
+                    }
+
+                    DebugCommand::Suite { path, depth, movetime } => match Epd::from_file(&path) {
+                        Ok(positions) => {
+                            let limits = match movetime {
+                                Some(ms) => SearchLimits::movetime(Duration::from_millis(ms)),
+                                None => SearchLimits::depth(depth.unwrap_or(5)),
+                            };
+                            let start = Instant::now();
+                            let (solved, tried) = Suite::run(&positions, &tt, limits, &search_options, |line| io.out(line));
+                            io.outfmt(format_args!("Solved {solved}/{tried} in {:.2}s", start.elapsed().as_secs_f64()));
+                        }
+                        Err(err) => io.outfmt(format_args!("info string Failed to read {path}: {err}")),
+                    }
+
                     DebugCommand::Unknown(cmd) => {
                         io.outfmt(format_args!("info string Unknown debug command: {cmd}"))
                     }
@@ -0,0 +1,287 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use rayon::prelude::*;
+
+type Bitboard = u64;
+type Square = u8;
+
+#[derive(Clone, Copy, Debug)]
+pub enum Slider {
+    Rook,
+    Bishop,
+}
+
+impl Slider {
+    pub const ALL: [Slider; 2] = [Slider::Rook, Slider::Bishop];
+
+    const fn directions(self) -> &'static [(i8, i8)] {
+        match self {
+            Self::Rook => &[(0, 1), (0, -1), (1, 0), (-1, 0)],
+            Self::Bishop => &[(1, 1), (1, -1), (-1, 1), (-1, -1)],
+        }
+    }
+
+    /// File name of the table, in `src/movegen/precomputed`
+    pub const fn file_name(self) -> &'static str {
+        match self {
+            Self::Rook => "rook.rs",
+            Self::Bishop => "bishop.rs",
+        }
+    }
+}
+
+pub struct Magic {
+    pub mask: Bitboard,
+    pub magic: Bitboard,
+    pub shift: u8,
+    pub attacks: Vec<Bitboard>,
+}
+
+impl Magic {
+    pub fn index(&self, occupied: Bitboard) -> usize {
+        ((occupied & self.mask).wrapping_mul(self.magic) >> self.shift) as usize
+    }
+}
+
+pub struct Options {
+    /// Magics found with one seed are always the same
+    pub seed: u64,
+
+    /// Look for magics with fewer index bits than the mask has
+    pub minimal: bool,
+
+    /// Candidates to try for each smaller table before giving up on it
+    pub attempts: usize,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self { seed: 0x45_5053_494C_4F4E, minimal: false, attempts: 1_000_000 }
+    }
+}
+
+// Check if a square is on board
+const fn on_board(rank: i8, file: i8) -> bool {
+    rank >= 0 && rank < 8 && file >= 0 && file < 8
+}
+
+/// Squares whose occupancy changes the attacks: the rays without their last square
+pub fn mask(slider: Slider, square: Square) -> Bitboard {
+    let mut mask = 0;
+
+    for &(dx, dy) in slider.directions() {
+        let mut r = square as i8 / 8 + dy;
+        let mut f = square as i8 % 8 + dx;
+
+        while on_board(r + dy, f + dx) {
+            mask |= 1u64 << (r * 8 + f);
+            r += dy;
+            f += dx;
+        }
+    }
+
+    mask
+}
+
+/// Attacks for given blockers, walking each ray
+pub fn gen_attacks_naive(slider: Slider, square: Square, blockers: Bitboard) -> Bitboard {
+    let mut attacks = 0;
+
+    for &(dx, dy) in slider.directions() {
+        let mut r = square as i8 / 8 + dy;
+        let mut f = square as i8 % 8 + dx;
+
+        while on_board(r, f) {
+            let bit = 1u64 << (r * 8 + f);
+            attacks |= bit;
+
+            if blockers & bit != 0 {
+                break;
+            }
+
+            r += dy;
+            f += dx;
+        }
+    }
+
+    attacks
+}
+
+/// Every subset of the mask bits (Carry-Rippler)
+pub fn blocker_permutations(mask: Bitboard) -> Vec<Bitboard> {
+    let mut blockers = Vec::with_capacity(1 << mask.count_ones());
+    let mut subset: Bitboard = 0;
+
+    loop {
+        blockers.push(subset);
+        subset = subset.wrapping_sub(mask) & mask;
+        if subset == 0 {
+            return blockers;
+        }
+    }
+}
+
+// xorshift64*, so that a seed gives the same magics everywhere
+struct Prng(u64);
+
+impl Prng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    // Magics with few set bits are found much faster
+    fn sparse(&mut self) -> u64 {
+        self.next() & self.next() & self.next()
+    }
+}
+
+// Try to find a magic indexing `bits` bits without destructive collisions
+fn try_find_magic(
+    mask: Bitboard,
+    blockers: &[Bitboard],
+    attacks: &[Bitboard],
+    bits: u32,
+    rng: &mut Prng,
+    attempts: usize,
+) -> Option<Magic> {
+    // The attempt that last wrote each slot, so the table is not cleared every attempt
+    let mut epoch = vec![0usize; 1 << bits];
+    let mut table = vec![0; 1 << bits];
+
+    for attempt in 1..=attempts {
+        let magic = rng.sparse();
+
+        // Quickly skip magics that can't spread the high mask bits over the index
+        if (mask.wrapping_mul(magic) >> 56).count_ones() < 6 {
+            continue;
+        }
+
+        let candidate = Magic { mask, magic, shift: (64 - bits) as u8, attacks: Vec::new() };
+        let mut success = true;
+
+        for (&b, &attack) in blockers.iter().zip(attacks) {
+            let index = candidate.index(b);
+
+            if epoch[index] != attempt {
+                epoch[index] = attempt;
+                table[index] = attack;
+            } else if table[index] != attack {
+                success = false;
+                break;
+            }
+        }
+
+        if success {
+            // Slots no occupancy maps to are left empty
+            let attacks = (0..table.len())
+                .map(|i| if epoch[i] == attempt { table[i] } else { 0 })
+                .collect();
+            return Some(Magic { attacks, ..candidate });
+        }
+    }
+
+    None
+}
+
+fn find_magic(slider: Slider, square: Square, options: &Options) -> Magic {
+    let mask = mask(slider, square);
+    let blockers = blocker_permutations(mask);
+    let attacks: Vec<Bitboard> = blockers.iter().map(|&b| gen_attacks_naive(slider, square, b)).collect();
+
+    let mut rng = Prng(options.seed ^ (slider as u64 + 1).wrapping_mul(0x9E37_79B9_7F4A_7C15) ^ square as u64);
+    if rng.0 == 0 {
+        rng.0 = 1;
+    }
+
+    // One bit per mask bit always works, given enough tries
+    let bits = mask.count_ones();
+    let mut best = try_find_magic(mask, &blockers, &attacks, bits, &mut rng, usize::MAX)
+        .expect("a magic exists for every square");
+
+    if options.minimal {
+        let mut bits = bits - 1;
+        while let Some(magic) = try_find_magic(mask, &blockers, &attacks, bits, &mut rng, options.attempts) {
+            best = magic;
+            bits -= 1;
+        }
+    }
+
+    best
+}
+
+/// Magics for all 64 squares, searched in parallel
+pub fn generate_all_magics(slider: Slider, options: &Options) -> Vec<Magic> {
+    (0..64)
+        .into_par_iter()
+        .map(|square| find_magic(slider, square, options))
+        .collect()
+}
+
+/// Check each magic against the naive generator, for every occupancy of the board
+/// that matters. Returns the first square that is wrong.
+pub fn verify(slider: Slider, magics: &[Magic]) -> Result<(), Square> {
+    for (square, magic) in (0..64).zip(magics) {
+        if magic.mask != mask(slider, square) {
+            return Err(square);
+        }
+
+        // Occupancy outside the mask must not change the index
+        let noise = !magic.mask & 0x8142_2418_1824_4281;
+        for blockers in blocker_permutations(magic.mask) {
+            let expected = gen_attacks_naive(slider, square, blockers);
+            if magic.attacks[magic.index(blockers)] != expected
+                || magic.attacks[magic.index(blockers | noise)] != expected
+            {
+                return Err(square);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Write the tables in the format of `src/movegen/precomputed`
+pub fn write_magics(magics: &[Magic], writer: &mut impl Write) -> io::Result<()> {
+    writeln!(writer, "use super::{{Bitboard, Magic}};\n")?;
+    writeln!(writer, "pub const MAGICS: [Magic; 64] = [")?;
+    for (i, magic) in magics.iter().enumerate() {
+        writeln!(
+            writer,
+            "    Magic {{ mask: 0x{:016X}, magic: 0x{:016X}, shift: {}, attacks: &ATTACK_TABLE_{} }},",
+            magic.mask, magic.magic, magic.shift, i
+        )?;
+    }
+    writeln!(writer, "];\n")?;
+
+    for (i, magic) in magics.iter().enumerate() {
+        write!(writer, "static ATTACK_TABLE_{}: [Bitboard; {}] = [", i, magic.attacks.len())?;
+        for attack in &magic.attacks {
+            write!(writer, "0x{attack:016X},")?;
+        }
+        writeln!(writer, "];")?;
+    }
+
+    Ok(())
+}
+
+/// Generate, verify and write the rook and bishop tables into `dir`
+pub fn generate(dir: &Path, options: &Options) -> io::Result<()> {
+    for slider in Slider::ALL {
+        let magics = generate_all_magics(slider, options);
+
+        if let Err(square) = verify(slider, &magics) {
+            return Err(io::Error::other(format!("{slider:?} magic for square {square} is wrong")));
+        }
+
+        let mut writer = BufWriter::new(File::create(dir.join(slider.file_name()))?);
+        write_magics(&magics, &mut writer)?;
+        writer.flush()?;
+    }
+
+    Ok(())
+}
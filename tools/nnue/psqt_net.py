@@ -0,0 +1,84 @@
+# Builds the embedded bootstrap network (src/eval/nnue/default.nnue) from the
+# handcrafted piece-square tables in src/eval/pst.rs.
+#
+# Every (relative color, piece type) pair gets one hidden neuron whose
+# accumulator holds the summed material + piece-square value (midgame and
+# endgame averaged) of those pieces, so the network reproduces the PST
+# evaluation until a trained net is loaded through the EvalFile option.
+
+import pathlib, re, struct
+
+INPUT_SIZE = 768
+HIDDEN_SIZE = 128
+PIECE_TYPES = 6
+
+QA = 255
+QB = 64
+SCALE = 400
+
+# Centipawns per accumulator unit, chosen so the output weight is exact:
+# 5 * QA * QB / SCALE = 204
+UNIT = 5
+OUTPUT_WEIGHT = UNIT * QA * QB // SCALE
+
+# Keeps the king neurons positive (king piece-square values can be negative)
+KING_BIAS = 64
+
+repo_dir = pathlib.Path(__file__).absolute().parent.parent.parent
+pst_path = repo_dir / "src" / "eval" / "pst.rs"
+output_path = repo_dir / "src" / "eval" / "nnue" / "default.nnue"
+
+def block(source: str, name: str) -> str:
+    start = source.index(f"const {name}")
+    start = source.index("= [", start) + 3
+    end = source.index("\n];", start)
+    return re.sub(r"//.*", "", source[start:end])
+
+def parse_tables(source: str, name: str) -> list[list[int]]:
+    numbers = [int(n) for n in re.findall(r"-?\d+", block(source, name))]
+    return [numbers[i * 64:(i + 1) * 64] for i in range(PIECE_TYPES)]
+
+source = pst_path.read_text()
+
+material = [
+    (int(mg), int(eg))
+    for mg, eg in re.findall(r"Score::new\((-?\d+), (-?\d+)\)", block(source, "MATERIAL"))
+]
+mg_tables = parse_tables(source, "MG_TABLES")
+eg_tables = parse_tables(source, "EG_TABLES")
+
+def value(piece_type: int, table_index: int) -> int:
+    mg = material[piece_type][0] + mg_tables[piece_type][table_index]
+    eg = material[piece_type][1] + eg_tables[piece_type][table_index]
+    return round((mg + eg) / 2 / UNIT)
+
+feature_weights = [[0] * HIDDEN_SIZE for _ in range(INPUT_SIZE)]
+for relative_color in range(2):
+    for piece_type in range(PIECE_TYPES):
+        neuron = relative_color * PIECE_TYPES + piece_type
+        for square in range(64):
+            # Own pieces read the tables like white pieces, enemy pieces like black ones
+            table_index = square ^ 56 if relative_color == 0 else square
+            feature = (relative_color * PIECE_TYPES + piece_type) * 64 + square
+            feature_weights[feature][neuron] = value(piece_type, table_index)
+
+feature_bias = [0] * HIDDEN_SIZE
+feature_bias[5] = KING_BIAS
+feature_bias[PIECE_TYPES + 5] = KING_BIAS
+
+# Only the side to move's accumulator is needed, it already sees both sides
+output_weights = [0] * (2 * HIDDEN_SIZE)
+for neuron in range(PIECE_TYPES):
+    output_weights[neuron] = OUTPUT_WEIGHT
+    output_weights[PIECE_TYPES + neuron] = -OUTPUT_WEIGHT
+
+with open(output_path, "wb") as f:
+    f.write(b"EPSNNUE\0")
+    f.write(struct.pack("<II", 1, HIDDEN_SIZE))
+    for weights in feature_weights:
+        f.write(struct.pack(f"<{HIDDEN_SIZE}h", *weights))
+    f.write(struct.pack(f"<{HIDDEN_SIZE}h", *feature_bias))
+    f.write(struct.pack(f"<{2 * HIDDEN_SIZE}h", *output_weights))
+    f.write(struct.pack("<i", 0))
+
+print(f"Wrote {output_path}")
@@ -0,0 +1,13 @@
+#!/bin/sh
+# Fetch the published Syzygy tables the probe tests run against:
+#   cargo test syzygy -- --ignored
+set -e
+
+cd "$(dirname "$0")"
+URL=https://tablebase.lichess.ovh/tables/standard/3-4-5
+
+for table in KQvK KRvK KBvK KNvK KPvK KQvKR KRvKR KRvKB KRvKN KRvKP; do
+    for ext in rtbw rtbz; do
+        [ -f "$table.$ext" ] || curl -fsSO "$URL/$table.$ext"
+    done
+done